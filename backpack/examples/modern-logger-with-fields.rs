@@ -2,34 +2,23 @@
 
 use log_rs::{
     banner::{BannerConfig, print as print_banner},
-    logging::{LogFormat, ModernBackend, ModernLogger, Printer, Verbosity, log::*, set_logger},
+    logging::{
+        L, LogFormat, LogProfile, LoggerSettings,
+        log::{debug, dim, done, err, info, intro, ok, outro, step, trace, warn},
+        set_logger,
+    },
 };
 use std::thread;
 use std::time::Duration;
 
+#[allow(clippy::too_many_lines)]
 fn main() {
-    // Parse command line arguments
-    let args: Vec<String> = std::env::args().collect();
-
-    let verbosity = if args.contains(&"-q".to_string()) {
-        Verbosity::Quiet
-    } else if args.contains(&"-vv".to_string()) {
-        Verbosity::Trace
-    } else if args.contains(&"-v".to_string()) {
-        Verbosity::Verbose
-    } else {
-        Verbosity::Normal
-    };
-
-    let format = if args.contains(&"--json".to_string()) {
-        LogFormat::Json
-    } else {
-        LogFormat::Text
-    };
-
-    // Initialize the logger with ModernLogger formatter
-    let logger = Printer::new(ModernLogger, ModernBackend::new(), format, verbosity);
-    set_logger(logger);
+    // `-q`, `-v`, `-vv` and `--json` pick the verbosity and format
+    let profile = LogProfile::new("modern", LoggerSettings::default()).with_args(std::env::args());
+    let LoggerSettings {
+        format, verbosity, ..
+    } = profile.settings;
+    set_logger(profile.build());
 
     // Print application banner (skip in JSON mode)
     if format == LogFormat::Text {
@@ -38,12 +27,13 @@ fn main() {
             version: env!("CARGO_PKG_VERSION"),
             tagline: Some("✨ Showcasing beautiful terminal output"),
             addr: Some("0.0.0.0:3000"),
+            url: None,
         };
         print_banner(&banner);
 
         println!("\n🎨 Running with ModernLogger");
-        println!("📊 Verbosity: {:?}", verbosity);
-        println!("📝 Format: {:?}\n", format);
+        println!("📊 Verbosity: {verbosity:?}");
+        println!("📝 Format: {format:?}\n");
     }
 
     // Demonstrate modern startup sequence
@@ -157,14 +147,15 @@ fn main() {
     info("Server ready to accept requests");
 
     if format == LogFormat::Text {
-        println!("\n{}", "─".repeat(60));
+        println!();
+        L.rule(None);
         println!("💡 Try these commands:");
         println!("  cargo run --example modern-logger           # normal output");
         println!("  cargo run --example modern-logger -- -v     # verbose output");
         println!("  cargo run --example modern-logger -- -vv    # trace output");
         println!("  cargo run --example modern-logger -- --json # JSON output");
         println!("  cargo run --example modern-logger -- -q     # quiet output");
-        println!("{}", "─".repeat(60));
+        L.rule(None);
     }
 }
 
@@ -1,6 +1,6 @@
 //! Modern Logger Example
 //!
-//! Demonstrates the ModernLogger with beautiful unicode symbols and modern
+//! Demonstrates the `ModernLogger` with beautiful unicode symbols and modern
 //! CLI aesthetics. Inspired by tools like cliclack and ink, this formatter
 //! creates polished, professional-looking terminal output.
 //!
@@ -12,35 +12,22 @@
 use log_rs::{
     banner::{BannerConfig, print as print_banner},
     logging::{
-        LogFormat, ModernBackend, ModernLogger, Printer, Progress, Verbosity, log::*, set_logger,
+        L, LogFormat, LogProfile, LoggerSettings, Progress,
+        log::{debug, dim, done, info, intro, ok, outro, step, trace, warn},
+        set_logger,
     },
 };
 use std::thread;
 use std::time::Duration;
 
+#[allow(clippy::too_many_lines)]
 fn main() {
-    // Parse command line arguments
-    let args: Vec<String> = std::env::args().collect();
-
-    let verbosity = if args.contains(&"-q".to_string()) {
-        Verbosity::Quiet
-    } else if args.contains(&"-vv".to_string()) {
-        Verbosity::Trace
-    } else if args.contains(&"-v".to_string()) {
-        Verbosity::Verbose
-    } else {
-        Verbosity::Normal
-    };
-
-    let format = if args.contains(&"--json".to_string()) {
-        LogFormat::Json
-    } else {
-        LogFormat::Text
-    };
-
-    // Initialize the logger with ModernLogger formatter
-    let logger = Printer::new(ModernLogger, ModernBackend::new(), format, verbosity);
-    set_logger(logger);
+    // `-q`, `-v`, `-vv` and `--json` pick the verbosity and format
+    let profile = LogProfile::new("modern", LoggerSettings::default()).with_args(std::env::args());
+    let LoggerSettings {
+        format, verbosity, ..
+    } = profile.settings;
+    set_logger(profile.build());
 
     // Print application banner (skip in JSON mode)
     if format == LogFormat::Text {
@@ -49,12 +36,13 @@ fn main() {
             version: env!("CARGO_PKG_VERSION"),
             tagline: Some("✨ Showcasing beautiful terminal output"),
             addr: Some("0.0.0.0:3000"),
+            url: None,
         };
         print_banner(&banner);
 
         println!("\n🎨 Running with ModernLogger");
-        println!("📊 Verbosity: {:?}", verbosity);
-        println!("📝 Format: {:?}\n", format);
+        println!("📊 Verbosity: {verbosity:?}");
+        println!("📝 Format: {format:?}\n");
     }
 
     // Demonstrate modern startup sequence
@@ -129,13 +117,8 @@ fn main() {
     warn("Skipped 3 slow tests in CI mode");
 
     step("Uploading to CDN");
-    {
-        let mut progress = Progress::with_total("Uploading files", 203);
-        for _ in 1..=203 {
-            simulate_work(2);
-            progress.tick();
-        }
-        progress.finish("Upload complete");
+    for _file in Progress::wrap_iter("Uploading files", 0..203) {
+        simulate_work(2);
     }
     dim("Uploaded 203 files to cdn.example.com");
     ok("Cache invalidated successfully");
@@ -203,8 +186,7 @@ fn main() {
             progress.tick();
         }
 
-        err("SMTP server connection failed");
-        warn("Retrying in 5 seconds...");
+        progress.retry("SMTP server connection failed");
         simulate_work(300);
         ok("Reconnected to SMTP server");
 
@@ -287,14 +269,15 @@ fn main() {
     info("Server ready to accept requests");
 
     if format == LogFormat::Text {
-        println!("\n{}", "─".repeat(60));
+        println!();
+        L.rule(None);
         println!("💡 Try these commands:");
         println!("  cargo run --example modern-logger           # normal output");
         println!("  cargo run --example modern-logger -- -v     # verbose output");
         println!("  cargo run --example modern-logger -- -vv    # trace output");
         println!("  cargo run --example modern-logger -- --json # JSON output");
         println!("  cargo run --example modern-logger -- -q     # quiet output");
-        println!("{}", "─".repeat(60));
+        L.rule(None);
     }
 }
 
@@ -1,6 +1,6 @@
 //! Simple Logger Example
 //!
-//! Demonstrates the SimpleLogger with basic ASCII symbols and no-frills output.
+//! Demonstrates the `SimpleLogger` with basic ASCII symbols and no-frills output.
 //! Perfect for environments where unicode support is limited or you prefer
 //! a more traditional CLI aesthetic.
 //!
@@ -12,35 +12,27 @@
 use log_rs::{
     banner::{BannerConfig, print as print_banner},
     logging::{
-        LogFormat, Printer, Progress, SimpleBackend, SimpleLogger, Verbosity, log::*, set_logger,
+        LogProfile, LoggerSettings, Progress, Theme,
+        log::{debug, dim, done, err, info, intro, ok, outro, step, trace, warn},
+        set_logger,
     },
 };
 use std::thread;
 use std::time::Duration;
 
+#[allow(clippy::too_many_lines)]
 fn main() {
-    // Parse command line arguments for verbosity
-    let args: Vec<String> = std::env::args().collect();
-
-    let verbosity: Verbosity = if args.contains(&"-q".to_string()) {
-        Verbosity::Quiet
-    } else if args.contains(&"-vv".to_string()) {
-        Verbosity::Trace
-    } else if args.contains(&"-v".to_string()) {
-        Verbosity::Verbose
-    } else {
-        Verbosity::Normal
-    };
-
-    let format = if args.contains(&"--json".to_string()) {
-        LogFormat::Json
-    } else {
-        LogFormat::Text
-    };
-
-    // Initialize the logger with SimpleLogger formatter
-    let logger = Printer::new(SimpleLogger, SimpleBackend, format, verbosity);
-    set_logger(logger);
+    // `-q`, `-v`, `-vv` and `--json` pick the verbosity and format
+    let profile = LogProfile::new(
+        "simple",
+        LoggerSettings {
+            theme: Theme::Simple,
+            ..LoggerSettings::default()
+        },
+    )
+    .with_args(std::env::args());
+    let verbosity = profile.settings.verbosity;
+    set_logger(profile.build());
 
     // Print application banner
     let banner = BannerConfig {
@@ -48,10 +40,11 @@ fn main() {
         version: env!("CARGO_PKG_VERSION"),
         tagline: Some("Demonstrating SimpleLogger with ASCII output"),
         addr: Some("127.0.0.1:8080"),
+        url: None,
     };
     print_banner(&banner);
 
-    println!("\nRunning with verbosity: {:?}\n", verbosity);
+    println!("\nRunning with verbosity: {verbosity:?}\n");
 
     // Demonstrate different log levels
     info("Starting application...");
@@ -17,6 +17,8 @@
 //!   - Specific IPs display as `IP:PORT` for precision
 //! - **ANSI Colors**: Tasteful green highlighting for addresses
 //! - **Flexible Configuration**: Optional tagline and address display
+//! - **Clickable Links**: An optional homepage URL becomes an OSC-8 hyperlink
+//!   on terminals that support it
 //! - **Zero Allocations**: Efficient formatting with minimal overhead
 //!
 //! ## Quick Start
@@ -29,6 +31,7 @@
 //!     version: "1.0.0",
 //!     tagline: Some("Fast and reliable REST API"),
 //!     addr: Some("0.0.0.0:8080"),
+//!     url: None,
 //! };
 //!
 //! print(&config);
@@ -54,18 +57,77 @@
 //!
 //! All in under 10 lines of output.
 
-use std::net::SocketAddr;
+use std::{
+    io::{IsTerminal, stdout},
+    net::SocketAddr,
+    sync::atomic::{AtomicU8, Ordering},
+};
 
 pub struct BannerConfig<'a> {
     pub name: &'a str,
     pub version: &'a str,
     pub tagline: Option<&'a str>,
     pub addr: Option<&'a str>,
+    /// Docs or homepage; shown under the tagline, linking the app name.
+    pub url: Option<&'a str>,
 }
 
 const GREEN: &str = "\x1b[32m";
 const RESET: &str = "\x1b[0m";
 
+/// 0 = probe the terminal, 1 = force off, 2 = force on.
+static HYPERLINKS: AtomicU8 = AtomicU8::new(0);
+
+/// Force OSC-8 hyperlinks on or off (`None` probes the terminal again).
+pub fn set_hyperlinks(enabled: Option<bool>) {
+    let mode = match enabled {
+        None => 0,
+        Some(false) => 1,
+        Some(true) => 2,
+    };
+    HYPERLINKS.store(mode, Ordering::Relaxed);
+}
+
+/// Whether `print` should emit OSC-8 hyperlinks.
+///
+/// Unless forced with `set_hyperlinks`, requires stdout to be a terminal
+/// that is known to render them.
+#[must_use]
+pub fn hyperlinks_enabled() -> bool {
+    match HYPERLINKS.load(Ordering::Relaxed) {
+        1 => false,
+        2 => true,
+        _ => stdout().is_terminal() && terminal_supports_hyperlinks(),
+    }
+}
+
+/// Best-effort check of the terminal emulator, from its environment.
+fn terminal_supports_hyperlinks() -> bool {
+    let var = |name| std::env::var(name).unwrap_or_default();
+
+    if var("TERM") == "dumb" {
+        return false;
+    }
+
+    matches!(
+        var("TERM_PROGRAM").as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "ghostty" | "Hyper"
+    ) || ["WT_SESSION", "KITTY_WINDOW_ID", "KONSOLE_VERSION"]
+        .iter()
+        .any(|name| std::env::var_os(name).is_some())
+        || var("VTE_VERSION").parse::<u32>().is_ok_and(|v| v >= 5000)
+}
+
+/// `text` as an OSC-8 hyperlink to `url`, or plain `text` when disabled.
+#[must_use]
+pub fn hyperlink(text: &str, url: &str, enabled: bool) -> String {
+    if enabled {
+        format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+    } else {
+        text.to_string()
+    }
+}
+
 #[must_use]
 pub fn print_address(addr: SocketAddr) -> String {
     let ip = addr.ip().to_string();
@@ -82,29 +144,48 @@ pub fn print_address(addr: SocketAddr) -> String {
 }
 
 pub fn print(config: &BannerConfig<'_>) {
+    println!("{}", render(config, hyperlinks_enabled()));
+}
+
+/// The banner text `print` writes, with or without OSC-8 hyperlinks.
+#[must_use]
+pub fn render(config: &BannerConfig<'_>, hyperlinks: bool) -> String {
     let tagline = config.tagline.unwrap_or("app.rs framework");
+    let url = config.url.filter(|u| !u.is_empty());
+    let name = url.map_or_else(
+        || config.name.to_string(),
+        |url| hyperlink(config.name, url, hyperlinks),
+    );
+
+    // The link itself is invisible in plain text, so spell the URL out.
+    let links_line = match url {
+        Some(_) if hyperlinks => format!("\n ⌂ {name}"),
+        Some(url) => format!("\n ⌂ {name}: {url}"),
+        None => String::new(),
+    };
     let addr_line = config
         .addr
         .filter(|s| !s.is_empty())
         .and_then(|addr_str| addr_str.parse::<SocketAddr>().ok())
-        .map(|addr| format!(" ⇨ {} listening on {}", config.name, print_address(addr)))
+        .map(|addr| format!(" ⇨ {name} listening on {}", print_address(addr)))
         .unwrap_or_default();
 
-    println!(
+    format!(
         r"
    ____    __
   / __/___/ /  ___
  / _// __/ _ \/ _ \
 /___/\__/_//_/\___/ v{version}
 
-{tagline}
+{tagline}{links_line}
 
 {addr_line}
 ",
         version = config.version,
         tagline = tagline,
+        links_line = links_line,
         addr_line = addr_line,
-    );
+    )
 }
 
 #[cfg(test)]
@@ -92,6 +92,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: Some("A test application"),
                 addr: Some("127.0.0.1:8080"),
+                url: None,
             };
 
             assert_eq!(config.name, "TestApp");
@@ -107,6 +108,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             assert_eq!(config.tagline, None);
@@ -123,6 +125,7 @@ mod banner_tests {
                 version: &version,
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             assert_eq!(config.name, "TestApp");
@@ -143,6 +146,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             // The default tagline should be "app.rs framework"
@@ -157,6 +161,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: Some("Custom tagline"),
                 addr: None,
+                url: None,
             };
 
             let tagline = config.tagline.unwrap_or("app.rs framework");
@@ -170,6 +175,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             let addr_line = config
@@ -189,6 +195,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: Some(""),
+                url: None,
             };
 
             let addr_line = config
@@ -208,6 +215,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: Some("127.0.0.1:8080"),
+                url: None,
             };
 
             let addr_line = config
@@ -230,6 +238,7 @@ mod banner_tests {
                 version: "2.0.0",
                 tagline: None,
                 addr: Some("0.0.0.0:3000"),
+                url: None,
             };
 
             let addr_line = config
@@ -251,6 +260,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: Some("invalid:address"),
+                url: None,
             };
 
             let addr_line = config
@@ -271,6 +281,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: Some("[::1]:8080"),
+                url: None,
             };
 
             let addr_line = config
@@ -297,6 +308,7 @@ mod banner_tests {
                 version: "1.2.3",
                 tagline: Some("The best app ever"),
                 addr: Some("127.0.0.1:8080"),
+                url: None,
             };
 
             // Test that config can be used
@@ -313,6 +325,7 @@ mod banner_tests {
                 version: "0.1.0",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             assert_eq!(config.name, "MinimalApp");
@@ -328,6 +341,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: Some("0.0.0.0:8080"),
+                url: None,
             };
 
             assert!(config.addr.is_some());
@@ -341,6 +355,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: Some("Just information"),
                 addr: None,
+                url: None,
             };
 
             assert!(config.tagline.is_some());
@@ -359,6 +374,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             assert_eq!(config.name, "");
@@ -371,6 +387,7 @@ mod banner_tests {
                 version: "",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             assert_eq!(config.version, "");
@@ -384,6 +401,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             assert_eq!(config.name.len(), 1000);
@@ -396,6 +414,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: Some("App with 🚀 emoji and <special> chars"),
                 addr: None,
+                url: None,
             };
 
             assert!(config.tagline.unwrap().contains("🚀"));
@@ -409,6 +428,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: Some("Line 1\nLine 2\nLine 3"),
                 addr: None,
+                url: None,
             };
 
             assert!(config.tagline.unwrap().contains('\n'));
@@ -421,6 +441,7 @@ mod banner_tests {
                 version: "1.0.0",
                 tagline: None,
                 addr: Some("   "),
+                url: None,
             };
 
             // Whitespace-only should not be filtered as empty
@@ -442,6 +463,7 @@ mod banner_tests {
                 version: "1.0.0-alpha.1+build.123",
                 tagline: None,
                 addr: None,
+                url: None,
             };
 
             assert_eq!(config.version, "1.0.0-alpha.1+build.123");
@@ -495,4 +517,65 @@ mod banner_tests {
             assert!("127.0.0.1:65535".parse::<SocketAddr>().is_ok());
         }
     }
+
+    // Test OSC-8 hyperlinks
+    mod hyperlink_tests {
+        use super::*;
+
+        const OSC8_OPEN: &str = "\x1b]8;;https://example.com/docs\x1b\\";
+        const OSC8_CLOSE: &str = "\x1b]8;;\x1b\\";
+
+        fn linked_config() -> BannerConfig<'static> {
+            BannerConfig {
+                name: "MyAPI",
+                version: "1.0.0",
+                tagline: None,
+                addr: Some("127.0.0.1:8080"),
+                url: Some("https://example.com/docs"),
+            }
+        }
+
+        #[test]
+        fn test_hyperlink_wraps_text_when_enabled() {
+            assert_eq!(
+                hyperlink("MyAPI", "https://example.com/docs", true),
+                format!("{OSC8_OPEN}MyAPI{OSC8_CLOSE}")
+            );
+        }
+
+        #[test]
+        fn test_hyperlink_is_plain_when_disabled() {
+            assert_eq!(
+                hyperlink("MyAPI", "https://example.com/docs", false),
+                "MyAPI"
+            );
+        }
+
+        #[test]
+        fn test_render_links_name_when_enabled() {
+            let banner = render(&linked_config(), true);
+
+            assert!(banner.contains(&format!(" ⌂ {OSC8_OPEN}MyAPI{OSC8_CLOSE}\n")));
+            assert!(banner.contains(&format!(" ⇨ {OSC8_OPEN}MyAPI{OSC8_CLOSE} listening on")));
+        }
+
+        #[test]
+        fn test_render_spells_out_url_when_disabled() {
+            let banner = render(&linked_config(), false);
+
+            assert!(!banner.contains("\x1b]8"));
+            assert!(banner.contains(" ⌂ MyAPI: https://example.com/docs\n"));
+            assert!(banner.contains(" ⇨ MyAPI listening on"));
+        }
+
+        #[test]
+        fn test_render_without_url_has_no_links_line() {
+            let config = BannerConfig {
+                url: None,
+                ..linked_config()
+            };
+
+            assert!(!render(&config, true).contains('⌂'));
+        }
+    }
 }
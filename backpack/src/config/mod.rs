@@ -1,7 +1,7 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct Config {
@@ -19,19 +19,48 @@ impl Config {
         PathBuf::from(".").join("log.toml")
     }
 
+    /// Load `log.toml`, falling back to the defaults.
+    ///
+    /// A file that exists but can't be read or parsed is reported on stderr
+    /// rather than silently ignored; use `try_load` to handle it yourself.
     #[must_use]
     pub fn load() -> Self {
-        let path = Self::path();
-        if path.exists() {
-            fs::read_to_string(&path)
-                .ok()
-                .and_then(|s| toml::from_str(&s).ok())
-                .unwrap_or_default()
-        } else {
+        Self::try_load().unwrap_or_else(|e| {
+            eprintln!("warning: ignoring {}: {e:#}", Self::path().display());
             Self::default()
-        }
+        })
+    }
+
+    /// Load `log.toml`. A missing file gives the defaults; a malformed one is
+    /// an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed.
+    pub fn try_load() -> Result<Self> {
+        Self::try_load_from(&Self::path())
     }
 
+    /// Like `try_load`, for a config file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but can't be read or parsed.
+    pub fn try_load_from(path: &Path) -> Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+
+        toml::from_str(&content).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// Write the config to `log.toml`, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config directory can't be created or the file can't be written.
     pub fn save(&self) -> Result<()> {
         let path = Self::path();
         if let Some(parent) = path.parent() {
@@ -55,6 +84,11 @@ impl Config {
         }
     }
 
+    /// Set `key` from its string form, as `get` returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `key` is not a known setting.
     pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
         match key {
             "quiet" => self.quiet = value == "true" || value == "1",
@@ -106,6 +140,10 @@ pub fn isnocolor() -> bool {
     unsafe { NOCOLOR }
 }
 
+#[cfg(test)]
+#[path = "tests.rs"]
+mod tests;
+
 /// Check if this is the first run
 #[must_use]
 pub fn isfirstrun() -> bool {
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod try_load_tests {
+    use crate::config::Config;
+    use std::{fs, path::PathBuf};
+
+    /// A fresh scratch directory for one test.
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("log-rs-config-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn absent_file_loads_the_defaults() {
+        let path = scratch("absent").join("log.toml");
+
+        let config = Config::try_load_from(&path).unwrap();
+
+        assert!(!config.quiet && !config.verbose && !config.nocolor);
+        assert_eq!(config.editor, None);
+    }
+
+    #[test]
+    fn valid_file_is_parsed() {
+        let path = scratch("valid").join("log.toml");
+        fs::write(
+            &path,
+            "quiet = true\nverbose = false\nnocolor = true\neditor = \"vim\"\n",
+        )
+        .unwrap();
+
+        let config = Config::try_load_from(&path).unwrap();
+
+        assert!(config.quiet && config.nocolor);
+        assert_eq!(config.editor.as_deref(), Some("vim"));
+    }
+
+    #[test]
+    fn malformed_file_is_an_error() {
+        let path = scratch("malformed").join("log.toml");
+        fs::write(&path, "quiet = \"maybe\n").unwrap();
+
+        let err = Config::try_load_from(&path)
+            .err()
+            .expect("malformed config should fail");
+
+        assert!(err.downcast_ref::<toml::de::Error>().is_some(), "{err:#}");
+        assert!(err.to_string().contains("log.toml"), "{err:#}");
+    }
+}
@@ -0,0 +1,229 @@
+use crate::logging::{LogLevel, ProgressUpdate, RenderBackend};
+use std::{
+    io::Write,
+    sync::{Arc, Condvar, Mutex},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+/// A backend that buffers rendered lines and writes them to `W` in batches.
+///
+/// Lines are flushed when `batch_size` of them are pending, when the optional
+/// flush timer fires, on `flush()`, and on `shutdown()` (also run on drop).
+pub struct BatchBackend<W: Write + Send + 'static> {
+    shared: Arc<Shared<W>>,
+    batch_size: usize,
+    timer: Mutex<Option<JoinHandle<()>>>,
+}
+
+struct Shared<W> {
+    batch: Mutex<Batch<W>>,
+    stop: Mutex<bool>,
+    wake: Condvar,
+}
+
+struct Batch<W> {
+    writer: W,
+    buf: String,
+    pending: usize,
+    flushes: usize,
+}
+
+impl<W: Write> Shared<W> {
+    fn flush(&self) -> std::io::Result<()> {
+        let mut batch = self.batch.lock().unwrap();
+        batch.write_out()
+    }
+}
+
+impl<W: Write> Batch<W> {
+    fn write_out(&mut self) -> std::io::Result<()> {
+        if self.pending == 0 {
+            return Ok(());
+        }
+
+        self.writer.write_all(self.buf.as_bytes())?;
+        self.writer.flush()?;
+        self.buf.clear();
+        self.pending = 0;
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+impl<W: Write + Send + 'static> BatchBackend<W> {
+    /// Default number of pending lines that triggers a write.
+    pub const DEFAULT_BATCH_SIZE: usize = 256;
+
+    #[must_use]
+    pub fn new(writer: W) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                batch: Mutex::new(Batch {
+                    writer,
+                    buf: String::new(),
+                    pending: 0,
+                    flushes: 0,
+                }),
+                stop: Mutex::new(false),
+                wake: Condvar::new(),
+            }),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            timer: Mutex::new(None),
+        }
+    }
+
+    /// Write once this many lines are pending (minimum 1).
+    #[must_use]
+    pub fn with_batch_size(mut self, lines: usize) -> Self {
+        self.batch_size = lines.max(1);
+        self
+    }
+
+    /// Also flush every `interval` from a background thread.
+    ///
+    /// Calling this again replaces the previous interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timer or batch lock is poisoned.
+    pub fn set_flush_interval(&self, interval: Duration) {
+        let mut timer = self.timer.lock().unwrap();
+        self.stop_timer(&mut timer);
+
+        *self.shared.stop.lock().unwrap() = false;
+        let shared = Arc::clone(&self.shared);
+        *timer = Some(std::thread::spawn(move || {
+            let mut stopped = shared.stop.lock().unwrap();
+            while !*stopped {
+                stopped = shared.wake.wait_timeout(stopped, interval).unwrap().0;
+                if !*stopped {
+                    let _ = shared.flush();
+                }
+            }
+        }));
+    }
+
+    /// Write out all pending lines now.
+    ///
+    /// # Errors
+    ///
+    /// Returns the writer's error if the pending lines can't be written.
+    pub fn flush(&self) -> std::io::Result<()> {
+        self.shared.flush()
+    }
+
+    /// Stop the flush timer and drain the batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns the writer's error if the final drain fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timer or batch lock is poisoned.
+    pub fn shutdown(&self) -> std::io::Result<()> {
+        let mut timer = self.timer.lock().unwrap();
+        self.stop_timer(&mut timer);
+        drop(timer);
+        self.flush()
+    }
+
+    /// How many batched writes have reached the writer so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `batch` lock is poisoned.
+    pub fn flush_count(&self) -> usize {
+        self.shared.batch.lock().unwrap().flushes
+    }
+
+    fn stop_timer(&self, timer: &mut Option<JoinHandle<()>>) {
+        if let Some(handle) = timer.take() {
+            *self.shared.stop.lock().unwrap() = true;
+            self.shared.wake.notify_all();
+            let _ = handle.join();
+        }
+    }
+
+    fn push(&self, line: &str) -> anyhow::Result<()> {
+        let mut batch = self.shared.batch.lock().unwrap();
+        batch.buf.push_str(line);
+        batch.buf.push('\n');
+        batch.pending += 1;
+
+        if batch.pending >= self.batch_size {
+            batch.write_out()?;
+        }
+        drop(batch);
+
+        Ok(())
+    }
+}
+
+impl<W: Write + Send + 'static> Drop for BatchBackend<W> {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+impl<W: Write + Send + 'static> RenderBackend for BatchBackend<W> {
+    fn render_error(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_info(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_remark(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_step(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_success(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_warning(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_intro(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_outro(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_debug(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_trace(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(msg)
+    }
+
+    fn render_json(&self, _level: LogLevel, line: &str) -> anyhow::Result<()> {
+        self.push(line)
+    }
+
+    fn flush(&self) -> anyhow::Result<()> {
+        Ok(self.shared.flush()?)
+    }
+
+    fn render_progress(&self, update: &ProgressUpdate<'_>) -> anyhow::Result<()> {
+        self.push(&update.line())?;
+
+        if update.finished {
+            let indent = "  ".repeat(update.depth);
+            self.push(&format!("{indent}{} — {}", update.label, update.status()))?;
+        }
+
+        Ok(())
+    }
+}
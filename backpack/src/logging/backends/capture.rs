@@ -0,0 +1,206 @@
+use crate::logging::{Fields, FormatLogger, LogLevel, Printer, ProgressUpdate, RenderBackend};
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+/// A backend that records every rendered line in memory instead of printing.
+///
+/// Clones share the same buffer, so a test can keep one handle and give the
+/// other to a `Printer`.
+#[derive(Debug, Clone, Default)]
+pub struct CaptureBackend {
+    lines: Arc<Mutex<Vec<Captured>>>,
+}
+
+/// One event drained from a [`CaptureBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedEvent {
+    pub level: LogLevel,
+    /// The rendered line, or the `message` of a JSON event.
+    pub message: String,
+    /// Fields of a JSON event; text lines carry theirs inline.
+    pub fields: Fields,
+}
+
+#[derive(Debug, Clone)]
+struct Captured {
+    level: LogLevel,
+    line: String,
+    json: bool,
+}
+
+impl Captured {
+    fn into_event(self) -> CapturedEvent {
+        let mut message = self.line;
+        let mut fields = Fields::new();
+
+        if self.json
+            && let Ok(Value::Object(mut event)) = serde_json::from_str(&message)
+        {
+            if let Some(Value::Object(map)) = event.remove("fields") {
+                for (key, value) in map {
+                    match value {
+                        Value::String(v) => fields.insert(key, v),
+                        other => fields.insert_json(key, &other),
+                    };
+                }
+            }
+            if let Some(Value::String(m)) = event.remove("message") {
+                message = m;
+            }
+        }
+
+        CapturedEvent {
+            level: self.level,
+            message,
+            fields,
+        }
+    }
+}
+
+impl CaptureBackend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every captured line with the level it was rendered at.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `lines` lock is poisoned.
+    #[must_use]
+    pub fn lines(&self) -> Vec<(LogLevel, String)> {
+        self.lines
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|c| (c.level, c.line.clone()))
+            .collect()
+    }
+
+    /// Captured text only, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `lines` lock is poisoned.
+    #[must_use]
+    pub fn messages(&self) -> Vec<String> {
+        self.lines
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|c| c.line.clone())
+            .collect()
+    }
+
+    /// Drain and return everything captured so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `lines` lock is poisoned.
+    #[must_use]
+    pub fn take(&self) -> Vec<(LogLevel, String)> {
+        std::mem::take(&mut *self.lines.lock().unwrap())
+            .into_iter()
+            .map(|c| (c.level, c.line))
+            .collect()
+    }
+
+    /// Empty the buffer, returning the events at `min_level` or above.
+    ///
+    /// `None` returns everything. Lower events are discarded, not kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `lines` lock is poisoned.
+    #[must_use]
+    pub fn drain(&self, min_level: Option<LogLevel>) -> Vec<CapturedEvent> {
+        std::mem::take(&mut *self.lines.lock().unwrap())
+            .into_iter()
+            .filter(|c| min_level.is_none_or(|min| c.level >= min))
+            .map(Captured::into_event)
+            .collect()
+    }
+
+    fn push(&self, level: LogLevel, msg: &str) {
+        self.record(level, msg, false);
+    }
+
+    fn record(&self, level: LogLevel, line: &str, json: bool) {
+        self.lines.lock().unwrap().push(Captured {
+            level,
+            line: line.to_string(),
+            json,
+        });
+    }
+}
+
+impl<L: FormatLogger> Printer<L, CaptureBackend> {
+    /// Shorthand for `printer.backend.drain(min_level)`.
+    #[must_use]
+    pub fn drain_captured(&self, min_level: Option<LogLevel>) -> Vec<CapturedEvent> {
+        self.backend.drain(min_level)
+    }
+}
+
+impl RenderBackend for CaptureBackend {
+    fn render_error(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Error, msg);
+        Ok(())
+    }
+
+    fn render_info(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Info, msg);
+        Ok(())
+    }
+
+    fn render_remark(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Info, msg);
+        Ok(())
+    }
+
+    fn render_step(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Info, msg);
+        Ok(())
+    }
+
+    fn render_success(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Info, msg);
+        Ok(())
+    }
+
+    fn render_warning(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Warn, msg);
+        Ok(())
+    }
+
+    fn render_intro(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Info, msg);
+        Ok(())
+    }
+
+    fn render_outro(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Info, msg);
+        Ok(())
+    }
+
+    fn render_debug(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Debug, msg);
+        Ok(())
+    }
+
+    fn render_trace(&self, msg: &str) -> anyhow::Result<()> {
+        self.push(LogLevel::Trace, msg);
+        Ok(())
+    }
+
+    fn render_json(&self, level: LogLevel, line: &str) -> anyhow::Result<()> {
+        self.record(level, line, true);
+        Ok(())
+    }
+
+    fn render_progress(&self, update: &ProgressUpdate<'_>) -> anyhow::Result<()> {
+        self.push(LogLevel::Progress, &update.line());
+        Ok(())
+    }
+}
@@ -1,33 +1,311 @@
+use crate::logging::{
+    LogLevel, approx_f64, format_heading, format_rule, format_table, glyph, group_digits,
+    write_line,
+};
+use std::time::Duration;
+
+mod batch;
+mod capture;
+#[cfg(feature = "modern")]
 mod modern;
+mod prompt;
 mod simple;
 
+pub use batch::*;
+pub use capture::*;
+#[cfg(feature = "modern")]
 pub use modern::*;
+pub use prompt::*;
 pub use simple::*;
 
+/// A single progress render request.
+///
+/// Build one with `ProgressUpdate::new`; how it's drawn (digit grouping, the
+/// gauge) is configured on the printer, which fills in the render options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ProgressUpdate<'a> {
+    /// Human label for the task.
+    pub label: &'a str,
+    /// Current position.
+    pub current: u64,
+    /// Optional total (None = unknown).
+    pub total: Option<u64>,
+    /// True when this is the final update.
+    pub finished: bool,
+    /// True when the task failed; only set together with `finished`.
+    pub aborted: bool,
+    /// Number of tasks open around this progress (0 = top level).
+    pub depth: usize,
+    /// Render counts with thousands separators (`1,250/50,000`); see
+    /// `Printer::set_group_digits`.
+    pub(crate) group_digits: bool,
+    /// Estimated time remaining, shown as `~Xs left` while running.
+    pub eta: Option<Duration>,
+    /// Time since the progress started by the logger's clock, for
+    /// throughput in JSON events.
+    pub elapsed: Option<Duration>,
+    /// Draw a `[████░░░░]` gauge this many cells wide instead of the counts;
+    /// see `Printer::set_progress_bar`.
+    ///
+    /// Ignored when the total is unknown or zero.
+    pub(crate) bar_width: Option<usize>,
+}
+
+impl<'a> ProgressUpdate<'a> {
+    /// A running, top-level update for `label` at `current` of `total`.
+    ///
+    /// The remaining public fields can be set afterwards.
+    #[must_use]
+    pub const fn new(label: &'a str, current: u64, total: Option<u64>) -> Self {
+        Self {
+            label,
+            current,
+            total,
+            finished: false,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: None,
+            elapsed: None,
+            bar_width: None,
+        }
+    }
+
+    /// `label (current/total, pct%)`, indented by depth, plus `~Xs left`
+    /// when an ETA is known and the task is still running.
+    ///
+    /// With a `bar_width` and a known total the counts become a gauge:
+    /// `label [████░░░░] 50%`.
+    #[must_use]
+    pub fn line(&self) -> String {
+        let count = |n: u64| {
+            let n = n.to_string();
+            if self.group_digits {
+                group_digits(&n).into_owned()
+            } else {
+                n
+            }
+        };
+
+        let indent = "  ".repeat(self.depth);
+        let position = if let (Some(width), Some(percent)) = (self.bar_width, self.percent()) {
+            format!("{} {percent}%", self.gauge(width))
+        } else {
+            let mut position = self.total.map_or_else(
+                || count(self.current),
+                |t| format!("{}/{}", count(self.current), count(t)),
+            );
+            if let Some(percent) = self.percent() {
+                position = format!("{position}, {percent}%");
+            }
+            format!("({position})")
+        };
+        match self.eta {
+            Some(eta) if !self.finished => {
+                format!(
+                    "{indent}{} {position} ~{}s left",
+                    self.label,
+                    eta.as_secs_f64().ceil()
+                )
+            }
+            _ => format!("{indent}{} {position}", self.label),
+        }
+    }
+
+    /// `[████░░░░]` with `width` cells, filled in proportion to completion.
+    fn gauge(&self, width: usize) -> String {
+        let total = self.total.unwrap_or(0).max(1);
+        let done = u128::from(self.current.min(total));
+        let filled = usize::try_from(done * width as u128 / u128::from(total)).unwrap_or(width);
+        let (full, empty) = (glyph("█", "#"), glyph("░", "-"));
+        format!("[{}{}]", full.repeat(filled), empty.repeat(width - filled))
+    }
+
+    /// Completion in whole percent, capped at 100.
+    ///
+    /// `None` when the total is unknown or zero; the raw counts still show
+    /// an overshoot like `10/5`.
+    #[must_use]
+    pub fn percent(&self) -> Option<u64> {
+        let total = self.total.filter(|&t| t > 0)?;
+        let percent = u128::from(self.current.min(total)) * 100 / u128::from(total);
+        u64::try_from(percent).ok()
+    }
+
+    /// Average items per second over `elapsed`.
+    ///
+    /// `None` without an elapsed time or before any time has passed.
+    #[must_use]
+    pub fn rate(&self) -> Option<f64> {
+        let secs = self.elapsed?.as_secs_f64();
+        (secs > 0.0).then(|| approx_f64(self.current) / secs)
+    }
+
+    /// Seconds left at the average `rate`, once the total is known.
+    #[must_use]
+    pub fn eta_seconds(&self) -> Option<f64> {
+        let remaining = self.total?.saturating_sub(self.current);
+        let rate = self.rate().filter(|&r| r > 0.0)?;
+        Some(approx_f64(remaining) / rate)
+    }
+
+    /// `"running"`, `"done"` or `"aborted"`.
+    #[must_use]
+    pub const fn status(&self) -> &'static str {
+        match (self.finished, self.aborted) {
+            (false, _) => "running",
+            (true, false) => "done",
+            (true, true) => "aborted",
+        }
+    }
+}
+
 /// A backend that knows how to *render* formatted strings.
 pub trait RenderBackend {
+    /// Render an error line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_error(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render an info line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_info(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render a low-key remark.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_remark(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render a step inside the current task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_step(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render a success line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_success(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render a warning line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_warning(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render the opening line of a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_intro(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render the closing line of a task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_outro(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render a debug line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_debug(&self, msg: &str) -> anyhow::Result<()>;
+    /// Render a trace line.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
     fn render_trace(&self, msg: &str) -> anyhow::Result<()>;
 
     /// Render a progress update.
     ///
-    /// - `label`: human label for the task
-    /// - `current`: current position
-    /// - `total`: optional total (None = unknown)
-    /// - `finished`: true when this is the final update
-    fn render_progress(
-        &self,
-        label: &str,
-        current: u64,
-        total: Option<u64>,
-        finished: bool,
-    ) -> anyhow::Result<()>;
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
+    fn render_progress(&self, update: &ProgressUpdate<'_>) -> anyhow::Result<()>;
+
+    /// Write one serialized JSON event.
+    ///
+    /// The default prints it on the stream the current `StreamPolicy` picks
+    /// for `level`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
+    fn render_json(&self, level: LogLevel, line: &str) -> anyhow::Result<()> {
+        write_line(level, line);
+        Ok(())
+    }
+
+    /// Write out anything the backend is holding back. Backends that write
+    /// straight through have nothing to do.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the held-back output can't be written.
+    fn flush(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Render a `width`-column divider, optionally labeled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
+    fn render_rule(&self, label: Option<&str>, width: usize) -> anyhow::Result<()> {
+        write_line(LogLevel::Info, &format_rule(label, width));
+        Ok(())
+    }
+
+    /// Render a section title, set off by blank lines.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
+    fn render_heading(&self, text: &str) -> anyhow::Result<()> {
+        write_line(LogLevel::Info, &format_heading(text));
+        Ok(())
+    }
+
+    /// Ask a yes/no question on stdout and read the answer from stdin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the question can't be written or the answer read.
+    fn confirm(&self, question: &str, default: bool) -> anyhow::Result<bool> {
+        read_confirm(
+            &mut std::io::stdin().lock(),
+            &mut std::io::stdout(),
+            question,
+            default,
+        )
+    }
+
+    /// Ask for a line of text on stdout and read it from stdin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prompt can't be written or the line read.
+    fn input(&self, prompt: &str) -> anyhow::Result<String> {
+        read_input(&mut std::io::stdin().lock(), &mut std::io::stdout(), prompt)
+    }
+
+    /// Render an aligned table; ragged rows are padded with empty cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line can't be written.
+    fn render_table(&self, headers: &[&str], rows: &[Vec<String>]) -> anyhow::Result<()> {
+        for line in format_table(headers, rows) {
+            write_line(LogLevel::Info, &line);
+        }
+        Ok(())
+    }
 }
@@ -1,10 +1,32 @@
-use crate::logging::RenderBackend;
+use crate::logging::{LogLevel, ProgressUpdate, RenderBackend, write_line};
 use cliclack::ProgressBar;
-use std::sync::Mutex;
+use std::{
+    io::IsTerminal,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+/// The running cliclack progress indicator.
+struct ActiveBar {
+    bar: ProgressBar,
+    /// Started without a total, so drawn as a spinner.
+    spinner: bool,
+}
 
 /// A backend that renders using cliclack's rich CLI primitives.
 pub struct ModernBackend {
-    bar: std::sync::Mutex<Option<cliclack::ProgressBar>>,
+    bar: Mutex<Option<ActiveBar>>,
+    bars_started: AtomicUsize,
+    fallback: bool,
+}
+
+/// Clones start without an active progress bar; bars are never shared.
+impl Clone for ModernBackend {
+    fn clone(&self) -> Self {
+        Self::new().with_fallback(self.fallback)
+    }
 }
 
 impl Default for ModernBackend {
@@ -18,95 +40,161 @@ impl ModernBackend {
     pub const fn new() -> Self {
         Self {
             bar: Mutex::new(None),
+            bars_started: AtomicUsize::new(0),
+            fallback: true,
+        }
+    }
+
+    /// Fall back to a plain `write_line` when cliclack can't render (default on).
+    ///
+    /// With the fallback off, cliclack errors propagate to the printer.
+    #[must_use]
+    pub const fn with_fallback(mut self, fallback: bool) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// How many progress bars or spinners this backend has started.
+    ///
+    /// Updates to a running bar reuse it; a new one starts after the previous
+    /// progress finished, or when a spinner learns its total.
+    #[must_use]
+    pub fn bars_started(&self) -> usize {
+        self.bars_started.load(Ordering::Relaxed)
+    }
+
+    /// Run a cliclack `render` call, printing `msg` plainly at `level` if it
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// With the fallback off, returns `render`'s error.
+    pub fn render_or_fallback(
+        &self,
+        level: LogLevel,
+        msg: &str,
+        render: impl FnOnce(&str) -> std::io::Result<()>,
+    ) -> anyhow::Result<()> {
+        match render(msg) {
+            Ok(()) => Ok(()),
+            Err(_) if self.fallback => {
+                write_line(level, msg);
+                Ok(())
+            }
+            Err(e) => Err(e.into()),
         }
     }
 }
 
 impl RenderBackend for ModernBackend {
     fn render_error(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::error(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Error, msg, |m| cliclack::log::error(m))
     }
 
     fn render_info(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::info(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Info, msg, |m| cliclack::log::info(m))
     }
 
     fn render_remark(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::remark(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Info, msg, |m| cliclack::log::remark(m))
     }
 
     fn render_step(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::step(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Info, msg, |m| cliclack::log::step(m))
     }
 
     fn render_success(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::success(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Info, msg, |m| cliclack::log::success(m))
     }
 
     fn render_warning(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::warning(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Warn, msg, |m| cliclack::log::warning(m))
     }
 
     fn render_intro(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::intro(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Info, msg, |m| cliclack::intro(m))
     }
 
     fn render_outro(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::outro(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Info, msg, |m| cliclack::outro(m))
     }
 
     fn render_debug(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::remark(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Debug, msg, |m| cliclack::log::remark(m))
     }
 
     fn render_trace(&self, msg: &str) -> anyhow::Result<()> {
-        cliclack::log::remark(msg)?;
-        Ok(())
+        self.render_or_fallback(LogLevel::Trace, msg, |m| cliclack::log::remark(m))
     }
 
-    fn render_progress(
-        &self,
-        label: &str,
-        current: u64,
-        total: Option<u64>,
-        finished: bool,
-    ) -> anyhow::Result<()> {
+    fn confirm(&self, question: &str, default: bool) -> anyhow::Result<bool> {
+        Ok(cliclack::confirm(question)
+            .initial_value(default)
+            .interact()?)
+    }
+
+    fn input(&self, prompt: &str) -> anyhow::Result<String> {
+        Ok(cliclack::input(prompt).interact()?)
+    }
+
+    fn render_progress(&self, update: &ProgressUpdate<'_>) -> anyhow::Result<()> {
+        let ProgressUpdate {
+            label,
+            current,
+            total,
+            finished,
+            aborted,
+            ..
+        } = *update;
         let mut guard = self.bar.lock().unwrap();
 
-        // Create the bar if needed
-        if guard.is_none() {
-            let bar = ProgressBar::new(total.unwrap_or(0));
-            bar.start(label);
-            *guard = Some(bar);
+        // A spinner started before the total was known gives way to a bar
+        if total.is_some()
+            && guard.as_ref().is_some_and(|a| a.spinner)
+            && let Some(spinner) = guard.take()
+        {
+            spinner.bar.cancel(label);
         }
 
-        // Update the bar
-        if let Some(bar) = guard.as_ref() {
-            // Update total if provided
-            if let Some(t) = total {
-                bar.set_length(t);
+        // Start a bar on the first update, or a spinner if there's no total
+        let active = guard.get_or_insert_with(|| {
+            let bar = total.map_or_else(cliclack::spinner, ProgressBar::new);
+            bar.start(label);
+            self.bars_started.fetch_add(1, Ordering::Relaxed);
+            ActiveBar {
+                bar,
+                spinner: total.is_none(),
             }
+        });
+
+        if let Some(t) = total {
+            active.bar.set_length(t);
+            active.bar.set_position(current);
+        }
+        active.bar.set_message(label);
 
-            // Update position
-            bar.set_position(current);
+        if !(finished || aborted) {
+            return Ok(());
+        }
 
-            // Update label/message
-            bar.set_message(label);
+        let Some(ActiveBar { bar, .. }) = guard.take() else {
+            return Ok(());
+        };
+        drop(guard);
 
-            // Finish if needed
-            if finished {
-                bar.stop(label);
-                *guard = None;
-            }
+        if aborted {
+            bar.error(label);
+        } else {
+            bar.stop(label);
+        }
+
+        // cliclack draws on stderr and nothing off a terminal, so report the
+        // outcome plainly there
+        if self.fallback && !std::io::stderr().is_terminal() {
+            write_line(
+                LogLevel::Progress,
+                &format!("{} {}", update.line(), update.status()),
+            );
         }
 
         Ok(())
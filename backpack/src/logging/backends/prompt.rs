@@ -0,0 +1,49 @@
+use std::io::{BufRead, Write};
+
+/// Interpret a y/n answer; anything unrecognized (including empty) is `default`.
+#[must_use]
+pub fn parse_confirm(answer: &str, default: bool) -> bool {
+    match answer.trim().to_ascii_lowercase().as_str() {
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default,
+    }
+}
+
+/// Ask `question` on `output` and read a y/n answer from `input`.
+///
+/// # Errors
+///
+/// Returns an error if writing `question` or reading `input` fails.
+pub fn read_confirm(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+    default: bool,
+) -> anyhow::Result<bool> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    write!(output, "{question} {hint} ")?;
+    output.flush()?;
+
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    Ok(parse_confirm(&answer, default))
+}
+
+/// Show `prompt` on `output` and read one line from `input`, without the newline.
+///
+/// # Errors
+///
+/// Returns an error if writing `prompt` or reading `input` fails.
+pub fn read_input(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    prompt: &str,
+) -> anyhow::Result<String> {
+    write!(output, "{prompt} ")?;
+    output.flush()?;
+
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
@@ -1,73 +1,68 @@
-use crate::logging::RenderBackend;
+use crate::logging::{LogLevel, ProgressUpdate, RenderBackend, write_line};
 
-/// A simple backend that renders to stdout/stderr.
+/// A simple backend that renders to stdout/stderr per the `StreamPolicy`.
+#[derive(Clone, Copy)]
 pub struct SimpleBackend;
 
 impl RenderBackend for SimpleBackend {
     fn render_error(&self, msg: &str) -> anyhow::Result<()> {
-        eprintln!("{msg}");
+        write_line(LogLevel::Error, msg);
         Ok(())
     }
 
     fn render_info(&self, msg: &str) -> anyhow::Result<()> {
-        println!("{msg}");
+        write_line(LogLevel::Info, msg);
         Ok(())
     }
 
     fn render_remark(&self, msg: &str) -> anyhow::Result<()> {
-        println!("{msg}");
+        write_line(LogLevel::Info, msg);
         Ok(())
     }
 
     fn render_step(&self, msg: &str) -> anyhow::Result<()> {
-        println!("{msg}");
+        write_line(LogLevel::Info, msg);
         Ok(())
     }
 
     fn render_success(&self, msg: &str) -> anyhow::Result<()> {
-        println!("{msg}");
+        write_line(LogLevel::Info, msg);
         Ok(())
     }
 
     fn render_warning(&self, msg: &str) -> anyhow::Result<()> {
-        println!("{msg}");
+        write_line(LogLevel::Warn, msg);
         Ok(())
     }
 
     fn render_intro(&self, msg: &str) -> anyhow::Result<()> {
-        println!("{msg}");
+        write_line(LogLevel::Info, msg);
         Ok(())
     }
 
     fn render_outro(&self, msg: &str) -> anyhow::Result<()> {
-        println!("{msg}");
+        write_line(LogLevel::Info, msg);
         Ok(())
     }
 
     fn render_debug(&self, msg: &str) -> anyhow::Result<()> {
-        eprintln!("{msg}");
+        write_line(LogLevel::Debug, msg);
         Ok(())
     }
 
     fn render_trace(&self, msg: &str) -> anyhow::Result<()> {
-        eprintln!("{msg}");
+        write_line(LogLevel::Trace, msg);
         Ok(())
     }
 
-    fn render_progress(
-        &self,
-        label: &str,
-        current: u64,
-        total: Option<u64>,
-        finished: bool,
-    ) -> anyhow::Result<()> {
-        match total {
-            Some(t) => println!("{label} ({current}/{t})"),
-            None => println!("{label} ({current})"),
-        }
+    fn render_progress(&self, update: &ProgressUpdate<'_>) -> anyhow::Result<()> {
+        write_line(LogLevel::Progress, &update.line());
 
-        if finished {
-            println!("{label} — done");
+        let label = format!("{}{}", "  ".repeat(update.depth), update.label);
+        if update.aborted {
+            write_line(LogLevel::Error, &format!("{label} — aborted"));
+        } else if update.finished {
+            write_line(LogLevel::Progress, &format!("{label} — done"));
         }
 
         Ok(())
@@ -2,12 +2,40 @@
 // LogEvent: builder for structured fields, emits on Drop
 // -----------------------------------------------------------------------------
 
-use crate::logging::LogLevel;
-use std::collections::BTreeMap;
+use crate::logging::{Fields, LogLevel};
+use std::panic::Location;
 
 /// A logger that can emit structured events.
 pub trait EmitsEvents {
     fn emit_event(&self, level: LogLevel, msg: &str, fields: &Fields);
+
+    /// Emit an event together with the call site that built it.
+    ///
+    /// Loggers that don't report locations can rely on the default, which
+    /// drops it.
+    fn emit_event_at(
+        &self,
+        level: LogLevel,
+        msg: &str,
+        fields: &Fields,
+        _location: &'static Location<'static>,
+    ) {
+        self.emit_event(level, msg, fields);
+    }
+
+    /// Emit an event tagged with the component (`target`) it came from.
+    ///
+    /// The default folds the target into the message as a `[target]` prefix.
+    fn emit_event_targeted(
+        &self,
+        level: LogLevel,
+        target: &str,
+        msg: &str,
+        fields: &Fields,
+        location: &'static Location<'static>,
+    ) {
+        self.emit_event_at(level, &format!("[{target}] {msg}"), fields, location);
+    }
 }
 
 impl<L> Drop for LogEvent<'_, L>
@@ -19,15 +47,10 @@ where
             return;
         }
 
-        let fields = std::mem::take(&mut self.fields);
-        self.logger.emit_event(self.level, &self.message, &fields);
-        self.emitted = true;
+        self.send();
     }
 }
 
-/// Structured fields attached to a log event.
-pub type Fields = BTreeMap<String, String>;
-
 pub struct LogEvent<'a, L>
 where
     L: EmitsEvents + ?Sized,
@@ -36,6 +59,8 @@ where
     level: LogLevel,
     message: String,
     fields: Fields,
+    target: Option<String>,
+    location: &'static Location<'static>,
     emitted: bool,
 }
 
@@ -43,21 +68,47 @@ impl<'a, L> LogEvent<'a, L>
 where
     L: EmitsEvents + ?Sized,
 {
+    #[track_caller]
     pub fn new(logger: &'a L, level: LogLevel, msg: &str) -> Self {
         Self {
             logger,
             level,
             message: msg.to_string(),
             fields: Fields::new(),
+            target: None,
+            location: Location::caller(),
             emitted: false,
         }
     }
 
+    /// Attach `key = value`, stored as `value`'s string form.
+    // Not `#[must_use]`: the event is emitted when the returned value drops.
+    // `value` is taken by value so literals and temporaries read naturally.
+    #[allow(
+        clippy::must_use_candidate,
+        clippy::return_self_not_must_use,
+        clippy::needless_pass_by_value
+    )]
     pub fn field(mut self, key: impl Into<String>, value: impl ToString) -> Self {
         self.fields.insert(key.into(), value.to_string());
         self
     }
 
+    /// Attach a raw JSON value, nested as-is in JSON output and printed
+    /// compactly in text mode.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn field_json(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.fields.insert_json(key.into(), &value.into());
+        self
+    }
+
+    /// Attach every `(key, value)` pair of `iter`, like repeated `field` calls.
+    // Not `#[must_use]`, for the same reason as `field`.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
     pub fn fields<I, K, V>(mut self, iter: I) -> Self
     where
         I: IntoIterator<Item = (K, V)>,
@@ -70,11 +121,47 @@ where
         self
     }
 
+    /// Tag the event with the component it came from, e.g. `"db"`.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Keep the event only if `cond` holds; otherwise it is discarded, fields
+    /// and all, instead of being emitted on drop.
+    ///
+    /// `printer.warn("slow").field("ms", t).when(t > 100);`
+    // Not `#[must_use]`: like `field`, the result is usually dropped to emit.
+    #[allow(clippy::must_use_candidate, clippy::return_self_not_must_use)]
+    pub fn when(mut self, cond: bool) -> Self {
+        if !cond {
+            self.emitted = true;
+            self.fields = Fields::new();
+        }
+        self
+    }
+
     pub fn emit(mut self) {
         if !self.emitted {
-            self.logger
-                .emit_event(self.level, &self.message, &self.fields);
-            self.emitted = true;
+            self.send();
+        }
+    }
+
+    fn send(&mut self) {
+        let fields = std::mem::take(&mut self.fields);
+        match &self.target {
+            Some(target) => self.logger.emit_event_targeted(
+                self.level,
+                target,
+                &self.message,
+                &fields,
+                self.location,
+            ),
+            None => self
+                .logger
+                .emit_event_at(self.level, &self.message, &fields, self.location),
         }
+        self.emitted = true;
     }
 }
@@ -1,4 +1,6 @@
-#[derive(Copy, Clone, Debug)]
+use std::{cmp::Ordering, str::FromStr};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum LogLevel {
     Info,
     Warn,
@@ -9,6 +11,16 @@ pub enum LogLevel {
 }
 
 impl LogLevel {
+    /// Every level, from least to most severe.
+    pub const ALL: [Self; 6] = [
+        Self::Trace,
+        Self::Debug,
+        Self::Info,
+        Self::Progress,
+        Self::Warn,
+        Self::Error,
+    ];
+
     #[must_use]
     pub const fn as_str(&self) -> &'static str {
         match self {
@@ -20,4 +32,77 @@ impl LogLevel {
             Self::Progress => "progress",
         }
     }
+
+    /// Numeric severity backing the ordering:
+    /// Trace < Debug < Info < Progress < Warn < Error.
+    ///
+    /// Progress sits just above Info: it is routine status, but a
+    /// "≥ Info" filter should still let it through.
+    #[must_use]
+    pub const fn severity(&self) -> u8 {
+        match self {
+            Self::Trace => 0,
+            Self::Debug => 1,
+            Self::Info => 2,
+            Self::Progress => 3,
+            Self::Warn => 4,
+            Self::Error => 5,
+        }
+    }
+
+    /// Iterate over every level, from least to most severe.
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+}
+
+impl PartialOrd for LogLevel {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LogLevel {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.severity().cmp(&other.severity())
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        Self::all()
+            .find(|level| level.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| anyhow::anyhow!("unknown log level `{s}`"))
+    }
+}
+
+/// `Progress` has no tracing counterpart and maps to `INFO`.
+#[cfg(feature = "tracing")]
+impl From<LogLevel> for tracing::Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Trace => Self::TRACE,
+            LogLevel::Debug => Self::DEBUG,
+            LogLevel::Info | LogLevel::Progress => Self::INFO,
+            LogLevel::Warn => Self::WARN,
+            LogLevel::Error => Self::ERROR,
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl From<tracing::Level> for LogLevel {
+    fn from(level: tracing::Level) -> Self {
+        match level {
+            tracing::Level::TRACE => Self::Trace,
+            tracing::Level::DEBUG => Self::Debug,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::WARN => Self::Warn,
+            tracing::Level::ERROR => Self::Error,
+        }
+    }
 }
@@ -0,0 +1,168 @@
+use serde::{Serialize, Serializer, ser::SerializeMap};
+
+/// Structured fields attached to a log event, kept in insertion order.
+///
+/// Text and CSV output list fields in the order they were added; JSON output
+/// goes through `serde_json`'s map and comes out sorted by key. Inserting an
+/// existing key replaces its value in place.
+///
+/// Values are strings. Keys set with `insert_json` hold serialized JSON that
+/// is embedded as a nested value in JSON output and printed as-is elsewhere.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fields {
+    entries: Vec<(String, Value)>,
+}
+
+/// One field value; both kinds keep their text form for non-JSON output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value {
+    Str(String),
+    Json(String),
+}
+
+impl Value {
+    const fn text(&self) -> &String {
+        match self {
+            Self::Str(s) | Self::Json(s) => s,
+        }
+    }
+
+    fn into_text(self) -> String {
+        match self {
+            Self::Str(s) | Self::Json(s) => s,
+        }
+    }
+}
+
+impl Fields {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Set `key` to `value`, returning the previous value if there was one.
+    pub fn insert(&mut self, key: String, value: String) -> Option<String> {
+        self.put(key, Value::Str(value))
+    }
+
+    /// Set `key` to a raw JSON value, kept nested in JSON output.
+    pub fn insert_json(&mut self, key: String, value: &serde_json::Value) -> Option<String> {
+        self.put(key, Value::Json(value.to_string()))
+    }
+
+    fn put(&mut self, key: String, value: Value) -> Option<String> {
+        if let Some((_, v)) = self.entries.iter_mut().find(|(k, _)| *k == key) {
+            return Some(std::mem::replace(v, value).into_text());
+        }
+
+        self.entries.push((key, value));
+        None
+    }
+
+    /// Whether `key` holds a raw JSON value.
+    #[must_use]
+    pub fn is_json(&self, key: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|(k, v)| k == key && matches!(v, Value::Json(_)))
+    }
+
+    /// Insert every entry of `other`, keeping its raw JSON values raw.
+    pub fn merge(&mut self, other: &Self) {
+        for (k, v) in &other.entries {
+            self.put(k.clone(), v.clone());
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.entries
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.text())
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let i = self.entries.iter().position(|(k, _)| k == key)?;
+        Some(self.entries.remove(i).1.into_text())
+    }
+    #[must_use]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.entries.iter().map(|(k, v)| (k, v.text()))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &String> {
+        self.entries.iter().map(|(_, v)| v.text())
+    }
+}
+
+impl Extend<(String, String)> for Fields {
+    fn extend<I: IntoIterator<Item = (String, String)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl FromIterator<(String, String)> for Fields {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        let mut fields = Self::new();
+        fields.extend(iter);
+        fields
+    }
+}
+
+impl<const N: usize> From<[(String, String); N]> for Fields {
+    fn from(pairs: [(String, String); N]) -> Self {
+        pairs.into_iter().collect()
+    }
+}
+
+impl IntoIterator for Fields {
+    type Item = (String, String);
+    type IntoIter = std::vec::IntoIter<(String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries
+            .into_iter()
+            .map(|(k, v)| (k, v.into_text()))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (k, v) in &self.entries {
+            match v {
+                Value::Json(raw) => match serde_json::from_str::<serde_json::Value>(raw) {
+                    Ok(value) => map.serialize_entry(k, &value)?,
+                    Err(_) => map.serialize_entry(k, raw)?,
+                },
+                Value::Str(s) => map.serialize_entry(k, s)?,
+            }
+        }
+        map.end()
+    }
+}
@@ -4,6 +4,8 @@
 
 mod events;
 mod levels;
+mod map;
 
 pub use events::*;
 pub use levels::*;
+pub use map::*;
@@ -0,0 +1,23 @@
+use crate::logging::with_thread_local;
+use std::cell::Cell;
+
+thread_local! {
+    /// Color setting of the printer currently formatting on this thread.
+    static ANSI_OVERRIDE: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Whether formatters should emit ANSI color codes.
+///
+/// A printer-level override (see `with_ansi`) wins; otherwise the global
+/// `nocolor` config decides.
+#[must_use]
+pub fn ansi_enabled() -> bool {
+    ANSI_OVERRIDE
+        .with(Cell::get)
+        .unwrap_or_else(|| !crate::config::isnocolor())
+}
+
+/// Run `f` with ANSI color forced on or off (`None` defers to the global).
+pub fn with_ansi<T>(ansi: Option<bool>, f: impl FnOnce() -> T) -> T {
+    with_thread_local(&ANSI_OVERRIDE, ansi, f)
+}
@@ -0,0 +1,94 @@
+use crate::logging::{ansi_enabled, with_thread_local};
+use std::{
+    cell::Cell,
+    sync::{PoisonError, RwLock},
+};
+
+/// ANSI SGR codes (the part between `\x1b[` and `m`) used by `SimpleLogger`
+/// for each kind of message. An empty code leaves that kind uncolored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorScheme {
+    pub ok: &'static str,
+    pub warn: &'static str,
+    pub err: &'static str,
+    pub info: &'static str,
+    pub dim: &'static str,
+    /// Structured `key=value` fields appended to text-mode events.
+    pub field: &'static str,
+    pub step: &'static str,
+    pub debug: &'static str,
+    pub trace: &'static str,
+}
+
+impl ColorScheme {
+    /// The stock colors: green ok, yellow warn, red err, uncolored info.
+    pub const STANDARD: Self = Self {
+        ok: "32",
+        warn: "33",
+        err: "31",
+        info: "",
+        dim: "90",
+        field: "2",
+        step: "36",
+        debug: "34",
+        trace: "90",
+    };
+
+    /// Bold, bright variants for low-contrast terminal themes.
+    #[must_use]
+    pub const fn high_contrast() -> Self {
+        Self {
+            ok: "1;92",
+            warn: "1;93",
+            err: "1;91",
+            info: "97",
+            dim: "37",
+            field: "37",
+            step: "1;96",
+            debug: "1;94",
+            trace: "1;95",
+        }
+    }
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+static COLOR_SCHEME: RwLock<ColorScheme> = RwLock::new(ColorScheme::STANDARD);
+
+thread_local! {
+    /// Scheme of the printer currently formatting on this thread.
+    static SCHEME_OVERRIDE: Cell<Option<ColorScheme>> = const { Cell::new(None) };
+}
+
+/// Set the process-wide default scheme.
+pub fn set_color_scheme(scheme: ColorScheme) {
+    *COLOR_SCHEME.write().unwrap_or_else(PoisonError::into_inner) = scheme;
+}
+
+/// The scheme formatters should use: a printer-level override if one is
+/// active (see `with_color_scheme`), else the process-wide default.
+#[must_use]
+pub fn color_scheme() -> ColorScheme {
+    SCHEME_OVERRIDE
+        .with(Cell::get)
+        .unwrap_or_else(|| *COLOR_SCHEME.read().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Wrap `text` in an ANSI color unless color is disabled or `code` is empty.
+#[must_use]
+pub fn paint(code: &str, text: &str) -> String {
+    if ansi_enabled() && !code.is_empty() {
+        format!("\x1b[{code}m{text}\x1b[0m")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Run `f` with `scheme` in place of the default (`None` keeps the default).
+pub fn with_color_scheme<T>(scheme: Option<ColorScheme>, f: impl FnOnce() -> T) -> T {
+    with_thread_local(&SCHEME_OVERRIDE, scheme, f)
+}
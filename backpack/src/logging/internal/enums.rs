@@ -1,22 +1,103 @@
-/// Cargo-style verbosity levels.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Cargo-style verbosity levels, ordered from least to most output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Verbosity {
-    Quiet,   // -q
-    Normal,  // default
+    Quiet, // -q
+    #[default]
+    Normal, // default
     Verbose, // -v
-    Trace,   // -vv
+    Trace, // -vv
 }
 
 /// Output format for the logger.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum LogFormat {
+    #[default]
     Text,
     Json,
+    /// RFC 4180 rows: a header on the first event, then one row per event.
+    Csv,
 }
 
-#[derive(Clone, Copy)]
+/// How JSON timestamps and text timestamp prefixes are produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TimestampMode {
+    /// The current time (the default).
+    #[default]
     Real,
+    /// No timestamp at all.
     Disabled,
+    /// A constant value, e.g. for snapshot tests.
     Fixed(&'static str),
+    /// The current time as epoch milliseconds, a JSON number rather than a
+    /// string.
+    UnixMillis,
+}
+
+/// How text-mode timestamp prefixes are rendered (local time).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// `2026-01-15T10:30:00+01:00`
+    Rfc3339,
+    /// `10:30:00`
+    Time,
+    /// A `chrono` strftime pattern, e.g. `"%H:%M:%S%.3f"`.
+    Custom(&'static str),
+}
+
+impl FromStr for Verbosity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "quiet" => Ok(Self::Quiet),
+            "normal" => Ok(Self::Normal),
+            "verbose" => Ok(Self::Verbose),
+            "trace" => Ok(Self::Trace),
+            _ => {
+                anyhow::bail!("unknown verbosity `{s}` (expected quiet, normal, verbose or trace)")
+            }
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => anyhow::bail!("unknown log format `{s}` (expected text, json or csv)"),
+        }
+    }
+}
+
+/// How text-mode progress updates are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressRender {
+    /// Redraw a single line in place with `\r`.
+    Inline,
+    /// One line per update (the default).
+    #[default]
+    Lines,
+    /// Only the final state; in-flight updates are dropped.
+    Final,
+}
+
+/// What quiet mode lets through besides errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuietProfile {
+    /// Errors plus every outro/done line with its timing (the default).
+    #[default]
+    Outros,
+    /// Errors only; outros, done lines and `summary()` are all hidden.
+    ErrorsOnly,
+    /// Errors and the final `summary()`, but no per-task outros.
+    ErrorsAndSummary,
 }
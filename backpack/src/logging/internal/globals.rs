@@ -1,5 +1,6 @@
-use crate::logging::{EmitsEvents, LogProxy, ScreenLogger};
+use crate::logging::{EarlyBuffer, EmitsEvents, LogProxy, NullLogger, ScreenLogger};
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 pub const PROJECT_NAME: &str = env!("CARGO_PKG_NAME");
 pub const PROJECT_DESC: &str = env!("CARGO_PKG_DESCRIPTION");
@@ -10,29 +11,84 @@ pub trait GlobalLoggerType: EmitsEvents + ScreenLogger + Send + Sync + std::any:
 /// The erased global logger type used throughout the crate.
 pub type GlobalLogger = dyn GlobalLoggerType;
 
+impl<L: GlobalLoggerType> From<L> for Box<GlobalLogger> {
+    fn from(logger: L) -> Self {
+        Box::new(logger)
+    }
+}
+
 static mut LOGGER: Option<&'static dyn GlobalLoggerType> = None;
 pub static INIT: OnceLock<()> = OnceLock::new();
+pub static NO_BANNER: AtomicBool = AtomicBool::new(false);
+
+/// Holds events logged after `buffer_early` and before `set_logger`.
+pub static EARLY: EarlyBuffer = EarlyBuffer::new();
 
 /// `LogProxy`
 pub static L: LogProxy = LogProxy;
 
-pub fn set_logger<L>(logger: L)
-where
-    L: GlobalLoggerType + 'static,
-{
-    let boxed = Box::new(logger);
-    let leaked: &'static dyn GlobalLoggerType = Box::leak(boxed);
+/// Install `logger` globally, first replaying anything `buffer_early` caught.
+///
+/// Takes a logger or an already boxed one, such as `LogProfile::build` returns.
+pub fn set_logger(logger: impl Into<Box<GlobalLogger>>) {
+    let leaked: &'static dyn GlobalLoggerType = Box::leak(logger.into());
 
     unsafe {
         LOGGER = Some(leaked);
     }
+
+    EARLY.replay(leaked);
+}
+
+/// Buffer log calls made before `set_logger` instead of panicking.
+///
+/// Until a logger is set, the global logger records up to
+/// `EarlyBuffer::DEFAULT_CAPACITY` events (oldest dropped first), and
+/// `set_logger` replays them in order. Does nothing once a logger is set.
+pub fn buffer_early() {
+    if unsafe { LOGGER }.is_none() {
+        unsafe {
+            LOGGER = Some(&EARLY);
+        }
+    }
 }
 
+/// Suppress the dev-mode welcome banner, regardless of `RUST_LOG`.
+///
+/// Must be called before `init()` (i.e. before constructing a `Printer`).
+pub fn disable_banner() {
+    NO_BANNER.store(true, Ordering::Relaxed);
+}
+
+/// The global logger set with `set_logger`.
+///
+/// # Panics
+///
+/// Panics if no logger has been set; `log_or_null` doesn't.
 #[must_use]
 pub fn logger() -> &'static dyn GlobalLoggerType {
     unsafe { LOGGER.expect("Logger not initialized") }
 }
 
+/// Like `logger`, but falls back to a `NullLogger` instead of panicking
+/// when no logger has been set.
+#[must_use]
+pub fn log_or_null() -> &'static dyn GlobalLoggerType {
+    static NULL: NullLogger = NullLogger;
+    unsafe { LOGGER }.unwrap_or(&NULL)
+}
+
+/// Process exit code for `main`: 1 if the global logger has logged an
+/// error, else 0 (also when no logger is set).
+///
+/// ```no_run
+/// std::process::exit(log_rs::logging::exit_code());
+/// ```
+#[must_use]
+pub fn exit_code() -> i32 {
+    i32::from(log_or_null().had_errors())
+}
+
 #[cfg(test)]
 pub fn reset_logger() {
     unsafe {
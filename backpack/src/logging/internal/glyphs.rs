@@ -0,0 +1,110 @@
+use crate::logging::with_thread_local;
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Whether formatters draw Unicode glyphs or plain ASCII, independent of color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlyphMode {
+    /// Unicode when the locale is UTF-8 (or unset), ASCII otherwise.
+    #[default]
+    Auto,
+    Unicode,
+    Ascii,
+}
+
+static GLYPH_MODE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_glyph_mode(mode: GlyphMode) {
+    GLYPH_MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn glyph_mode() -> GlyphMode {
+    match GLYPH_MODE.load(Ordering::Relaxed) {
+        1 => GlyphMode::Unicode,
+        2 => GlyphMode::Ascii,
+        _ => GlyphMode::Auto,
+    }
+}
+
+/// Whether a locale string (as in `LANG`) can display Unicode glyphs.
+///
+/// An unset locale counts as Unicode-capable to match most modern terminals.
+#[must_use]
+pub fn locale_supports_unicode(locale: Option<&str>) -> bool {
+    locale.is_none_or(|l| {
+        let l = l.to_ascii_lowercase();
+        l.contains("utf-8") || l.contains("utf8")
+    })
+}
+
+/// Resolve the current glyph mode, probing `LC_ALL`, `LC_CTYPE` then `LANG` for `Auto`.
+#[must_use]
+pub fn use_unicode() -> bool {
+    match glyph_mode() {
+        GlyphMode::Unicode => true,
+        GlyphMode::Ascii => false,
+        GlyphMode::Auto => {
+            let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+                .into_iter()
+                .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()));
+            locale_supports_unicode(locale.as_deref())
+        }
+    }
+}
+
+/// Pick the Unicode or ASCII variant of a glyph for the current mode.
+#[must_use]
+pub fn glyph(unicode: &'static str, ascii: &'static str) -> &'static str {
+    if use_unicode() { unicode } else { ascii }
+}
+
+/// Braille spinner frames a printer cycles through for successive steps;
+/// see `Printer::set_step_glyph_rotation`.
+pub const STEP_SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+thread_local! {
+    /// Emoji setting of the printer currently formatting on this thread.
+    static EMOJI: Cell<bool> = const { Cell::new(true) };
+
+    /// Step glyph chosen by the printer currently formatting on this thread.
+    static STEP_GLYPH: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+/// The glyph formatters put in front of a step: `⠿` (or `*` in ASCII mode)
+/// unless a printer picked a spinner frame.
+#[must_use]
+pub fn step_glyph() -> &'static str {
+    match STEP_GLYPH.with(Cell::get) {
+        Some(frame) if use_unicode() => frame,
+        _ => glyph("⠿", "*"),
+    }
+}
+
+/// Run `f` with `step_glyph` returning `frame` (`None` keeps the default).
+pub fn with_step_glyph<T>(frame: Option<&'static str>, f: impl FnOnce() -> T) -> T {
+    with_thread_local(&STEP_GLYPH, frame, f)
+}
+
+/// Whether formatters may draw emoji (on unless a printer turned it off).
+#[must_use]
+pub fn emoji_enabled() -> bool {
+    EMOJI.with(Cell::get)
+}
+
+/// Run `f` with emoji allowed or replaced by their plain fallbacks.
+pub fn with_emoji<T>(enabled: bool, f: impl FnOnce() -> T) -> T {
+    with_thread_local(&EMOJI, enabled, f)
+}
+
+/// Like `glyph`, but also falls back to `plain` when emoji are disabled.
+#[must_use]
+pub fn emoji(emoji: &'static str, plain: &'static str) -> &'static str {
+    if emoji_enabled() {
+        glyph(emoji, plain)
+    } else {
+        plain
+    }
+}
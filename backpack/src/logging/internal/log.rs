@@ -1,20 +1,93 @@
 use crate::logging::{GlobalLogger, LogEvent, LogLevel, globals, logger};
-use globals::{INIT, PROJECT_DESC, PROJECT_NAME};
+use globals::{INIT, NO_BANNER, PROJECT_DESC, PROJECT_NAME};
+use std::{fmt::Display, sync::atomic::Ordering};
 use terminal_banner::Banner;
+#[cfg(feature = "tracing")]
+use tracing::Subscriber;
+#[cfg(feature = "tracing")]
 use tracing_subscriber::{
-    Layer, Registry, filter::LevelFilter, fmt::writer::BoxMakeWriter, prelude::*,
+    EnvFilter, Layer, Registry, filter::ParseError, fmt::writer::BoxMakeWriter, prelude::*,
+    registry::LookupSpan,
 };
 
+/// Tracing directive used by `init()`: every event reaches the `fmt` layer.
+pub const DEFAULT_TRACING_FILTER: &str = "trace";
+
 /// Initialize the global tracing subscriber.
+///
+/// A `.env` file that fails to load is reported on stderr but doesn't stop
+/// the subscriber from being installed. Without the `tracing` feature only
+/// the environment and banner are set up.
+///
+/// # Errors
+///
+/// Returns an error if another global tracing subscriber is already installed.
 pub fn init() -> Result<(), Box<dyn std::error::Error>> {
+    init_with_filter(DEFAULT_TRACING_FILTER)
+}
+
+/// Like `init`, but the stderr `fmt` layer only passes events matching
+/// `directive` (`EnvFilter` syntax, e.g. `"myapp=debug,hyper=warn"`).
+///
+/// This is separate from the printers' `Verbosity`. Only the first call to
+/// `init`/`init_with_filter` takes effect; an invalid directive is an error
+/// and leaves the logger uninitialized. Without the `tracing` feature the
+/// directive is ignored.
+///
+/// # Errors
+///
+/// Returns an error if `directive` doesn't parse or another global tracing
+/// subscriber is already installed.
+pub fn init_with_filter(directive: &str) -> Result<(), Box<dyn std::error::Error>> {
     if INIT.get().is_some() {
         return Ok(());
     }
 
+    #[cfg(feature = "tracing")]
+    let layer = fmt_layer::<Registry>(directive, BoxMakeWriter::new(std::io::stderr))?;
+    #[cfg(not(feature = "tracing"))]
+    let _ = directive;
+
     INIT.set(()).ok();
-    env_rs::init()?;
+    load_env(env_rs::init);
+
+    //#[cfg(feature = "tokio-console")]
+    //let registry = registry.with(console_subscriber::spawn());
+
+    #[cfg(feature = "tracing")]
+    tracing::subscriber::set_global_default(Registry::default().with(layer))?;
+
+    if let Some(banner) = dev_banner() {
+        println!("{banner}");
+    }
 
-    let telemetry_fmt = tracing_subscriber::fmt::layer()
+    Ok(())
+}
+
+/// Run the environment loader, downgrading a failure to a stderr warning so
+/// logging still comes up. Returns whether it succeeded.
+pub(crate) fn load_env<E: Display>(load: impl FnOnce() -> Result<(), E>) -> bool {
+    match load() {
+        Ok(()) => true,
+        Err(e) => {
+            eprintln!("warning: failed to load environment, continuing without it: {e}");
+            false
+        }
+    }
+}
+
+/// The compact `fmt` layer `init` installs, filtered by `directive`.
+#[cfg(feature = "tracing")]
+pub(crate) fn fmt_layer<S>(
+    directive: &str,
+    writer: BoxMakeWriter,
+) -> Result<impl Layer<S>, ParseError>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let filter = EnvFilter::try_new(directive)?;
+
+    Ok(tracing_subscriber::fmt::layer()
         .with_ansi(true)
         .without_time()
         .compact()
@@ -22,80 +95,109 @@ pub fn init() -> Result<(), Box<dyn std::error::Error>> {
         .with_line_number(true)
         .with_thread_ids(false)
         .with_target(false)
-        .with_writer(BoxMakeWriter::new(std::io::stderr));
-
-    let registry = Registry::default().with(telemetry_fmt.with_filter(LevelFilter::TRACE));
-
-    //#[cfg(feature = "tokio-console")]
-    //let registry = registry.with(console_subscriber::spawn());
+        .with_writer(writer)
+        .with_filter(filter))
+}
 
-    tracing::subscriber::set_global_default(registry)?;
+/// Whether `init()` should print the dev-mode banner.
+///
+/// True only when `RUST_LOG` is exactly `debug` or `trace` (case-insensitive)
+/// and the banner hasn't been disabled.
+#[must_use]
+pub fn should_show_banner(rust_log: Option<&str>, no_banner: bool) -> bool {
+    !no_banner
+        && rust_log.is_some_and(|level| {
+            level.eq_ignore_ascii_case("debug") || level.eq_ignore_ascii_case("trace")
+        })
+}
 
-    if std::env::var("RUST_LOG").is_ok()
-        && ["debug", "trace"].contains(&std::env::var("RUST_LOG").unwrap().to_lowercase().as_str())
-    {
-        let banner = Banner::new()
+/// The dev-mode banner `init()` prints, or `None` when it shouldn't show.
+#[must_use]
+pub fn dev_banner() -> Option<String> {
+    should_show_banner(std::env::var("RUST_LOG").ok().as_deref(), banner_disabled()).then(|| {
+        Banner::new()
             .text(format!("Welcome to {PROJECT_NAME}!\n").into())
             .text(PROJECT_DESC.into())
-            .render();
-
-        println!("{banner}");
-    }
-
-    Ok(())
+            .render()
+    })
 }
 
+/// Whether the banner was switched off via `disable_banner()` or `LOG_RS_NO_BANNER`.
+///
+/// `LOG_RS_NO_BANNER` set to `0`, `false` or an empty string counts as unset.
 #[must_use]
+pub fn banner_disabled() -> bool {
+    NO_BANNER.load(Ordering::Relaxed)
+        || std::env::var("LOG_RS_NO_BANNER").is_ok_and(|v| {
+            let v = v.trim();
+            !(v.is_empty() || v == "0" || v.eq_ignore_ascii_case("false"))
+        })
+}
+
+// The event builders aren't `#[must_use]`: an event is emitted when it
+// drops, so `info("msg");` on its own is the intended use.
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn ok(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Info, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn warn(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Warn, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn err(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Error, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn info(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Info, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn dim(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Info, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn intro(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Info, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn outro(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Info, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn done() -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Info, "done")
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn step(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Info, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn debug(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Debug, msg)
 }
 
-#[must_use]
+#[allow(clippy::must_use_candidate)]
+#[track_caller]
 pub fn trace(msg: &str) -> LogEvent<'static, GlobalLogger> {
     LogEvent::new(logger(), LogLevel::Trace, msg)
 }
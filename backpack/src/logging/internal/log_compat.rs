@@ -0,0 +1,54 @@
+use crate::logging::{LogLevel, logger};
+use ::log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Forwards `log` crate records into the global `Printer`.
+struct LogCompat;
+
+static LOG_COMPAT: LogCompat = LogCompat;
+
+const fn map_level(level: Level) -> LogLevel {
+    match level {
+        Level::Error => LogLevel::Error,
+        Level::Warn => LogLevel::Warn,
+        Level::Info => LogLevel::Info,
+        Level::Debug => LogLevel::Debug,
+        Level::Trace => LogLevel::Trace,
+    }
+}
+
+impl Log for LogCompat {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        logger().enabled(map_level(metadata.level()))
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let msg = record.args().to_string();
+        let l = logger();
+        match record.level() {
+            Level::Error => l.err(&msg),
+            Level::Warn => l.warn(&msg),
+            Level::Info => l.info(&msg),
+            Level::Debug => l.debug(&msg),
+            Level::Trace => l.trace(&msg),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Route `log::info!` and friends into the global logger.
+///
+/// Call after `set_logger`.
+///
+/// # Errors
+///
+/// Returns an error if another `log` implementation is already installed.
+pub fn init_log_compat() -> anyhow::Result<()> {
+    ::log::set_logger(&LOG_COMPAT).map_err(|e| anyhow::anyhow!("{e}"))?;
+    ::log::set_max_level(LevelFilter::Trace);
+    Ok(())
+}
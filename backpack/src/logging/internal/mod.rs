@@ -1,12 +1,35 @@
+mod ansi;
+mod clock;
+mod colors;
 mod enums;
+mod glyphs;
 pub mod log;
+#[cfg(feature = "log-compat")]
+mod log_compat;
+mod profile;
 mod proxy;
+mod settings;
+pub(crate) mod spans;
+mod streams;
 mod utils;
+mod verbosity;
 
 pub mod globals;
 
+pub use ansi::*;
+pub use clock::*;
+pub use colors::*;
 pub use enums::*;
 pub use globals::*;
+pub use glyphs::*;
 pub use log::*;
+#[cfg(feature = "log-compat")]
+pub use log_compat::*;
+pub use profile::*;
 pub use proxy::*;
+pub use settings::*;
+pub use spans::Span;
+pub(crate) use spans::{step_span, task_span};
+pub use streams::*;
 pub use utils::*;
+pub use verbosity::*;
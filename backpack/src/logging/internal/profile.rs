@@ -0,0 +1,123 @@
+#[cfg(feature = "modern")]
+use crate::logging::ModernBackend;
+use crate::logging::{
+    GlobalLogger, LogFormat, LoggerSettings, ModernLogger, Printer, SimpleBackend, SimpleLogger,
+    Theme, Verbosity, set_logger,
+};
+
+/// A named, ready-made logging setup: formatter, backend, format and
+/// verbosity in one value.
+///
+/// ```no_run
+/// use log_rs::logging::{LogProfile, set_logger};
+///
+/// let profile = if std::env::var_os("CI").is_some() {
+///     LogProfile::ci()
+/// } else {
+///     LogProfile::dev()
+/// };
+/// set_logger(profile.with_args(std::env::args()).build());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogProfile {
+    pub name: &'static str,
+    pub settings: LoggerSettings,
+}
+
+impl LogProfile {
+    /// A profile named `name` built from `settings`.
+    #[must_use]
+    pub const fn new(name: &'static str, settings: LoggerSettings) -> Self {
+        Self { name, settings }
+    }
+
+    /// Modern theme, text output, verbose: for working on the app.
+    #[must_use]
+    pub const fn dev() -> Self {
+        Self::new(
+            "dev",
+            Self::settings(Theme::Modern, LogFormat::Text, Verbosity::Verbose),
+        )
+    }
+
+    /// Simple theme, JSON output, normal verbosity: for CI logs and tooling.
+    #[must_use]
+    pub const fn ci() -> Self {
+        Self::new(
+            "ci",
+            Self::settings(Theme::Simple, LogFormat::Json, Verbosity::Normal),
+        )
+    }
+
+    /// Simple theme, text output, quiet: errors and summaries only.
+    #[must_use]
+    pub const fn quiet() -> Self {
+        Self::new(
+            "quiet",
+            Self::settings(Theme::Simple, LogFormat::Text, Verbosity::Quiet),
+        )
+    }
+
+    /// Override verbosity and format from command-line flags: `-q`, `-v`,
+    /// `-vv` and `--json`. Other arguments are ignored.
+    #[must_use]
+    pub fn with_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for arg in args {
+            match arg.as_ref() {
+                "-q" => self.settings.verbosity = Verbosity::Quiet,
+                "-v" => self.settings.verbosity = Verbosity::Verbose,
+                "-vv" => self.settings.verbosity = Verbosity::Trace,
+                "--json" => self.settings.format = LogFormat::Json,
+                _ => {}
+            }
+        }
+        self
+    }
+
+    const fn settings(theme: Theme, format: LogFormat, verbosity: Verbosity) -> LoggerSettings {
+        LoggerSettings {
+            format,
+            verbosity,
+            nocolor: false,
+            theme,
+        }
+    }
+
+    /// Build the profile's printer, type-erased so every profile has the
+    /// same type.
+    ///
+    /// Like `Printer::new`, this sets the global verbosity flags. Without the
+    /// `modern` feature the modern theme renders through `SimpleBackend`.
+    #[must_use]
+    pub fn build(&self) -> Box<GlobalLogger> {
+        let settings = &self.settings;
+        match settings.theme {
+            #[cfg(feature = "modern")]
+            Theme::Modern => Box::new(Printer::from_settings(
+                ModernLogger,
+                ModernBackend::new(),
+                settings,
+            )),
+            #[cfg(not(feature = "modern"))]
+            Theme::Modern => Box::new(Printer::from_settings(
+                ModernLogger,
+                SimpleBackend,
+                settings,
+            )),
+            Theme::Simple => Box::new(Printer::from_settings(
+                SimpleLogger,
+                SimpleBackend,
+                settings,
+            )),
+        }
+    }
+
+    /// Build the printer and install it as the global logger.
+    pub fn install(&self) {
+        set_logger(self.build());
+    }
+}
@@ -1,73 +1,144 @@
-use crate::logging::{GlobalLogger, LogEvent, LogLevel, logger};
+use crate::logging::{GlobalLogger, LogEvent, LogLevel, ProgressUpdate, logger};
+use std::time::Instant;
 
 /// Proxy value so callers can write `L.ok("msg")` or `logger().ok("msg")`.
 pub struct LogProxy;
 
 impl LogProxy {
-    #[must_use]
+    // The event builders aren't `#[must_use]`: an event is emitted when it
+    // drops, so `info("msg");` on its own is the intended use.
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn ok(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Info, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn warn(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Warn, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn err(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Error, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn info(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Info, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn dim(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Info, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn intro(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Info, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn outro(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Info, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn done(&self) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Info, "done")
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn step(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Info, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn debug(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Debug, msg)
     }
 
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn trace(&self, msg: &str) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Trace, msg)
     }
 
     /// Dump the current task tree (verbose/trace only).
-    #[must_use]
+    #[allow(clippy::must_use_candidate)]
+    #[track_caller]
     pub fn dump_tree(&self) -> LogEvent<'static, GlobalLogger> {
         LogEvent::new(logger(), LogLevel::Debug, "dump_tree")
     }
 
+    /// Whether the global logger would show a message at `level`.
+    #[must_use]
+    pub fn enabled(&self, level: LogLevel) -> bool {
+        logger().enabled(level)
+    }
+
     /// Start a progress handle for a long-running task.
-    pub fn progress(&self, label: &str, current: u64, total: Option<u64>, finished: bool) {
+    pub fn progress(&self, update: &ProgressUpdate<'_>) {
         let logger = crate::logging::logger();
-        logger.progress(label, current, total, finished);
+        logger.progress(update);
+    }
+
+    /// Print an aligned table (or one JSON `table` event with a `rows` array).
+    pub fn table(&self, headers: &[&str], rows: &[Vec<String>]) {
+        logger().table(headers, rows);
+    }
+
+    /// Draw a horizontal divider, optionally labeled.
+    pub fn rule(&self, label: Option<&str>) {
+        logger().rule(label);
+    }
+
+    /// Print a section heading through the global logger.
+    pub fn heading(&self, text: &str) {
+        logger().heading(text);
+    }
+
+    /// Add a note to the global logger's end-of-run notes section.
+    pub fn note(&self, text: &str) {
+        logger().note(text);
+    }
+
+    /// Ask a yes/no question through the global logger.
+    ///
+    /// # Errors
+    ///
+    /// Returns the global logger's error if prompting fails.
+    pub fn confirm(&self, question: &str) -> anyhow::Result<bool> {
+        logger().confirm(question)
+    }
+
+    /// Ask for a line of text through the global logger.
+    ///
+    /// # Errors
+    ///
+    /// Returns the global logger's error if prompting fails.
+    pub fn input(&self, prompt: &str) -> anyhow::Result<String> {
+        logger().input(prompt)
+    }
+
+    /// Number of tasks currently open on the global logger.
+    #[must_use]
+    pub fn task_depth(&self) -> usize {
+        logger().task_depth()
+    }
+
+    /// The current time by the global logger's clock.
+    #[must_use]
+    pub fn now(&self) -> Instant {
+        logger().now()
     }
 }
@@ -0,0 +1,26 @@
+use crate::logging::{LogFormat, Verbosity};
+use serde::{Deserialize, Serialize};
+
+/// Visual style of the text output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    /// `ModernLogger` + `ModernBackend`.
+    #[default]
+    Modern,
+    /// `SimpleLogger` + `SimpleBackend`.
+    Simple,
+}
+
+/// Logging settings an application can embed in its own serde config.
+///
+/// Unlike `config::Config` (the crate's own `log.toml`), this only describes
+/// how a `Printer` is built. Missing keys fall back to their defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggerSettings {
+    pub format: LogFormat,
+    pub verbosity: Verbosity,
+    pub nocolor: bool,
+    pub theme: Theme,
+}
@@ -0,0 +1,61 @@
+//! Task/step spans and the event macros the `Printer` mirrors text output to.
+//!
+//! With the `tracing` feature these are real `tracing` spans and events.
+//! Without it, spans are inert markers and the macros only type-check their
+//! arguments, so task timing and rendering work the same either way.
+
+#[cfg(feature = "tracing")]
+pub use tracing::span::Span;
+
+/// Inert stand-in for `tracing::Span` when the `tracing` feature is off.
+#[cfg(not(feature = "tracing"))]
+#[derive(Debug, Clone, Default)]
+pub struct Span;
+
+#[cfg(not(feature = "tracing"))]
+impl Span {
+    #[must_use]
+    pub const fn none() -> Self {
+        Self
+    }
+}
+
+/// Span opened by each task intro.
+#[cfg(feature = "tracing")]
+pub fn task_span(label: &str) -> Span {
+    tracing::span!(tracing::Level::INFO, "task", message = %label)
+}
+
+/// Span recorded for each verbose step.
+#[cfg(feature = "tracing")]
+pub fn step_span(label: &str) -> Span {
+    tracing::span!(tracing::Level::INFO, "step", message = %label)
+}
+
+#[cfg(not(feature = "tracing"))]
+pub const fn task_span(_label: &str) -> Span {
+    Span
+}
+
+#[cfg(not(feature = "tracing"))]
+pub const fn step_span(_label: &str) -> Span {
+    Span
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! ignore_event {
+    ($($arg:tt)*) => {{
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use ignore_event as debug;
+#[cfg(not(feature = "tracing"))]
+pub(crate) use ignore_event as error;
+#[cfg(not(feature = "tracing"))]
+pub(crate) use ignore_event as info;
+#[cfg(not(feature = "tracing"))]
+pub(crate) use ignore_event as trace;
+#[cfg(not(feature = "tracing"))]
+pub(crate) use ignore_event as warn;
@@ -0,0 +1,215 @@
+use crate::logging::{LogLevel, with_thread_local};
+use std::cell::Cell;
+use std::io::{self, Write};
+use std::sync::{
+    Mutex, MutexGuard, PoisonError,
+    atomic::{AtomicU8, AtomicU64, Ordering},
+};
+
+/// Which standard stream a rendered line goes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// How levels are routed between stdout and stderr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StreamPolicy {
+    /// Errors, debug and trace go to stderr; everything else to stdout.
+    #[default]
+    InfoToStdout,
+    /// Everything goes to stderr, leaving stdout for program data.
+    AllToStderr,
+    /// Everything goes to stdout, errors included.
+    AllToStdout,
+}
+
+impl StreamPolicy {
+    #[must_use]
+    pub const fn stream_for(self, level: LogLevel) -> Stream {
+        match self {
+            Self::InfoToStdout => match level {
+                LogLevel::Error | LogLevel::Debug | LogLevel::Trace => Stream::Stderr,
+                LogLevel::Info | LogLevel::Warn | LogLevel::Progress => Stream::Stdout,
+            },
+            Self::AllToStderr => Stream::Stderr,
+            Self::AllToStdout => Stream::Stdout,
+        }
+    }
+}
+
+static STREAM_POLICY: AtomicU8 = AtomicU8::new(0);
+
+/// Set the process-wide default policy, for printers without their own
+/// (see `Printer::set_stream_policy`).
+pub fn set_stream_policy(policy: StreamPolicy) {
+    STREAM_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// The policy in effect on this thread: the writing printer's own, if it
+/// set one (see `with_stream_policy`), else the process-wide default.
+#[must_use]
+pub fn stream_policy() -> StreamPolicy {
+    POLICY_OVERRIDE
+        .with(Cell::get)
+        .unwrap_or_else(|| match STREAM_POLICY.load(Ordering::Relaxed) {
+            1 => StreamPolicy::AllToStderr,
+            2 => StreamPolicy::AllToStdout,
+            _ => StreamPolicy::InfoToStdout,
+        })
+}
+
+/// Run `f` with `policy` in place of the default (`None` keeps the default).
+pub fn with_stream_policy<T>(policy: Option<StreamPolicy>, f: impl FnOnce() -> T) -> T {
+    with_thread_local(&POLICY_OVERRIDE, policy, f)
+}
+
+thread_local! {
+    /// Policy of the printer currently writing on this thread.
+    static POLICY_OVERRIDE: Cell<Option<StreamPolicy>> = const { Cell::new(None) };
+
+    /// Where this thread is currently sending progress lines, if overridden.
+    static PROGRESS_STREAM: Cell<Option<Stream>> = const { Cell::new(None) };
+}
+
+/// Run `f` with `Progress` lines written to `stream`, whatever the policy
+/// says; see `Printer::set_progress_to`.
+pub fn with_progress_stream<T>(stream: Stream, f: impl FnOnce() -> T) -> T {
+    with_thread_local(&PROGRESS_STREAM, Some(stream), f)
+}
+
+/// The stream a line at `level` goes to on this thread.
+fn stream_for(level: LogLevel) -> Stream {
+    match (level, PROGRESS_STREAM.get()) {
+        (LogLevel::Progress, Some(stream)) => stream,
+        _ => stream_policy().stream_for(level),
+    }
+}
+
+static OUTPUT_LOCK: Mutex<()> = Mutex::new(());
+
+/// Newlines written through this module, so live regions can tell when
+/// other output has moved the cursor.
+static LINES_WRITTEN: AtomicU64 = AtomicU64::new(0);
+
+/// How many lines the `write_*` functions have written so far.
+///
+/// A live region (see `MultiProgress`) compares it across redraws to tell
+/// whether other output moved the cursor since it last drew.
+#[must_use]
+pub fn lines_written() -> u64 {
+    LINES_WRITTEN.load(Ordering::Relaxed)
+}
+
+/// Count the newlines in `msg`, plus `trailing` added by the writer.
+fn count_lines(msg: &str, trailing: u64) {
+    let lines = msg.bytes().filter(|&b| b == b'\n').count() as u64;
+    LINES_WRITTEN.fetch_add(lines + trailing, Ordering::Relaxed);
+}
+
+thread_local! {
+    /// Whether this thread already holds `OUTPUT_LOCK`.
+    static HOLDS_OUTPUT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Held output lock; see `output_lock`.
+#[must_use]
+pub struct OutputGuard {
+    guard: Option<MutexGuard<'static, ()>>,
+}
+
+impl Drop for OutputGuard {
+    fn drop(&mut self) {
+        if self.guard.take().is_some() {
+            HOLDS_OUTPUT.with(|held| held.set(false));
+        }
+    }
+}
+
+/// Serialize writes to stdout/stderr so concurrent events never interleave.
+///
+/// Only the final write is guarded; callers format their line first. Custom
+/// backends that print directly can hold it too. The lock is reentrant: a
+/// thread that already holds it gets a no-op guard, so a whole batch can be
+/// written under one lock (see `Printer::emit_many`).
+pub fn output_lock() -> OutputGuard {
+    if HOLDS_OUTPUT.with(Cell::get) {
+        return OutputGuard { guard: None };
+    }
+
+    let guard = OUTPUT_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    HOLDS_OUTPUT.with(|held| held.set(true));
+    OutputGuard { guard: Some(guard) }
+}
+
+/// Print `msg` on the stream the current policy picks for `level`.
+pub fn write_line(level: LogLevel, msg: &str) {
+    write_line_on(stream_for(level), msg);
+}
+
+/// Print `msg` on `stream`, whatever the policy says.
+pub fn write_line_on(stream: Stream, msg: &str) {
+    let _guard = output_lock();
+    count_lines(msg, 1);
+    match stream {
+        Stream::Stdout => println!("{msg}"),
+        Stream::Stderr => eprintln!("{msg}"),
+    }
+}
+
+/// Write `msg` plus `\n` to `out` in one call, flushing right after if asked.
+///
+/// Line-delimited consumers (e.g. JSON over a pipe) see each event as soon
+/// as it is written instead of whenever the stream's buffer fills.
+///
+/// # Errors
+///
+/// Returns `out`'s error if the write or flush fails.
+pub fn write_line_to<W: Write>(out: &mut W, msg: &str, flush: bool) -> io::Result<()> {
+    out.write_all(format!("{msg}\n").as_bytes())?;
+    if flush {
+        out.flush()?;
+    }
+    Ok(())
+}
+
+/// Like `write_line`, but flushes the stream after the line.
+pub fn write_line_flushed(level: LogLevel, msg: &str) {
+    let _guard = output_lock();
+    count_lines(msg, 1);
+    let _ = match stream_for(level) {
+        Stream::Stdout => write_line_to(&mut io::stdout().lock(), msg, true),
+        Stream::Stderr => write_line_to(&mut io::stderr().lock(), msg, true),
+    };
+}
+
+/// Flush the stream the current policy picks for `level`.
+pub fn flush_stream(level: LogLevel) {
+    let _guard = output_lock();
+    let _ = match stream_for(level) {
+        Stream::Stdout => io::stdout().flush(),
+        Stream::Stderr => io::stderr().flush(),
+    };
+}
+
+/// Print `msg` without a trailing newline and flush, for in-place redraws.
+pub fn write_inline(level: LogLevel, msg: &str) {
+    write_inline_on(stream_for(level), msg);
+}
+
+/// Like `write_inline`, on `stream` whatever the policy says.
+pub fn write_inline_on(stream: Stream, msg: &str) {
+    let _guard = output_lock();
+    count_lines(msg, 0);
+    match stream {
+        Stream::Stdout => {
+            print!("{msg}");
+            let _ = std::io::stdout().flush();
+        }
+        Stream::Stderr => {
+            eprint!("{msg}");
+            let _ = std::io::stderr().flush();
+        }
+    }
+}
@@ -1,3 +1,38 @@
+use crate::logging::{ansi_enabled, glyph};
+use std::{borrow::Cow, cell::Cell, fmt::Write, thread::LocalKey};
+use unicode_width::UnicodeWidthChar;
+
+/// Run `f` with the thread-local `key` set to `value`, restoring the previous
+/// value afterwards, even if `f` panics.
+///
+/// Backs the `with_*` render overrides such as `with_ansi`.
+pub fn with_thread_local<T: 'static, R>(
+    key: &'static LocalKey<Cell<T>>,
+    value: T,
+    f: impl FnOnce() -> R,
+) -> R {
+    struct Restore<T: 'static> {
+        key: &'static LocalKey<Cell<T>>,
+        previous: Option<T>,
+    }
+
+    impl<T> Drop for Restore<T> {
+        fn drop(&mut self) {
+            if let Some(previous) = self.previous.take() {
+                // The slot may already be gone if the thread is exiting.
+                let _ = self.key.try_with(|slot| slot.set(previous));
+            }
+        }
+    }
+
+    let previous = key.with(|slot| slot.replace(value));
+    let _restore = Restore {
+        key,
+        previous: Some(previous),
+    };
+    f()
+}
+
 #[must_use]
 pub fn format_duration(d: std::time::Duration) -> String {
     if d.as_secs() > 0 {
@@ -6,3 +41,243 @@ pub fn format_duration(d: std::time::Duration) -> String {
         format!("{}ms", d.as_millis())
     }
 }
+
+/// Counts as `f64` for rate math; saturating at `u32::MAX` is plenty for an ETA.
+#[must_use]
+pub fn approx_f64(n: u64) -> f64 {
+    f64::from(u32::try_from(n).unwrap_or(u32::MAX))
+}
+
+/// Byte length of the ANSI escape sequence at the start of `s`, if any.
+fn ansi_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return None;
+    }
+
+    match bytes.get(1) {
+        // CSI: ESC [ params... final byte in '@'..='~'
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map_or(Some(bytes.len()), |end| Some(end + 3)),
+        // OSC: ESC ] ... terminated by BEL or ESC \
+        Some(b']') => bytes[2..]
+            .iter()
+            .enumerate()
+            .find_map(|(i, b)| match b {
+                0x07 => Some(i + 3),
+                0x1b if bytes.get(i + 3) == Some(&b'\\') => Some(i + 4),
+                _ => None,
+            })
+            .or(Some(bytes.len())),
+        Some(_) => Some(1 + s[1..].chars().next().map_or(0, char::len_utf8)),
+        None => Some(1),
+    }
+}
+
+/// Width of `s` in terminal columns, ignoring ANSI escape sequences.
+#[must_use]
+pub fn display_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(n) = ansi_len(rest) {
+            rest = &rest[n..];
+            continue;
+        }
+
+        width += UnicodeWidthChar::width(c).unwrap_or(0);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    width
+}
+
+/// `s` with ANSI escape sequences removed: CSI (colors, cursor moves) and
+/// OSC (titles, hyperlinks).
+///
+/// A sequence cut off at the end of `s` is dropped along with its `ESC`.
+#[must_use]
+pub fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(n) = ansi_len(rest) {
+            rest = &rest[n..];
+            continue;
+        }
+
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    out
+}
+
+/// Escape control characters other than `\n` and `\t` as `\xNN`.
+///
+/// Keeps untrusted text from emitting terminal escapes such as `\x1b[2J`.
+#[must_use]
+pub fn escape_control(s: &str) -> Cow<'_, str> {
+    let is_unsafe = |c: char| c.is_control() && c != '\n' && c != '\t';
+    if !s.contains(is_unsafe) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len() + 8);
+    for c in s.chars() {
+        if is_unsafe(c) {
+            let _ = write!(out, "\\x{:02x}", u32::from(c));
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Truncate `s` to at most `max` display columns, ending with `…`.
+///
+/// ANSI escapes are kept but don't count toward the width; a reset is
+/// appended if any were copied so styling can't leak past the cut.
+#[must_use]
+pub fn truncate_to_width(s: &str, max: usize) -> Cow<'_, str> {
+    if display_width(s) <= max {
+        return Cow::Borrowed(s);
+    }
+
+    let budget = max.saturating_sub(1);
+    let mut out = String::with_capacity(s.len());
+    let mut used = 0;
+    let mut styled = false;
+    let mut rest = s;
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(n) = ansi_len(rest) {
+            out.push_str(&rest[..n]);
+            styled = true;
+            rest = &rest[n..];
+            continue;
+        }
+
+        let w = UnicodeWidthChar::width(c).unwrap_or(0);
+        if used + w > budget {
+            break;
+        }
+
+        used += w;
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if max > 0 {
+        out.push('…');
+    }
+    if styled {
+        out.push_str("\x1b[0m");
+    }
+
+    Cow::Owned(out)
+}
+
+/// Lay out `headers` and `rows` as aligned text lines.
+///
+/// Columns are as wide as their widest cell; short rows are padded with
+/// empty cells. A dashed rule separates the header from the body.
+#[must_use]
+pub fn format_table(headers: &[&str], rows: &[Vec<String>]) -> Vec<String> {
+    let header: Vec<&str> = headers.to_vec();
+    let body: Vec<Vec<&str>> = rows
+        .iter()
+        .map(|r| r.iter().map(String::as_str).collect())
+        .collect();
+
+    let columns = body.iter().map(Vec::len).fold(header.len(), usize::max);
+    let mut widths = vec![0; columns];
+    for row in std::iter::once(&header).chain(&body) {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(display_width(cell));
+        }
+    }
+
+    let line = |row: &[&str]| {
+        let cells: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let cell = row.get(i).copied().unwrap_or("");
+                format!("{cell}{}", " ".repeat(w - display_width(cell)))
+            })
+            .collect();
+        cells.join("  ").trim_end().to_string()
+    };
+
+    let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+
+    let mut lines = Vec::with_capacity(body.len() + 2);
+    lines.push(line(&header));
+    lines.push(rule.join("  "));
+    lines.extend(body.iter().map(|row| line(row)));
+    lines
+}
+
+/// A `width`-column divider, optionally with `label` centered in it.
+#[must_use]
+pub fn format_rule(label: Option<&str>, width: usize) -> String {
+    let fill = glyph("─", "-");
+
+    let Some(label) = label else {
+        return fill.repeat(width);
+    };
+
+    let side = width.saturating_sub(display_width(label) + 2);
+    let left = side / 2;
+    format!(
+        "{} {label} {}",
+        fill.repeat(left.max(1)),
+        fill.repeat((side - left).max(1))
+    )
+}
+
+/// `text` bold and underlined (plain without color), between blank lines.
+#[must_use]
+pub fn format_heading(text: &str) -> String {
+    if ansi_enabled() {
+        format!("\n\x1b[1;4m{text}\x1b[0m\n")
+    } else {
+        format!("\n{text}\n")
+    }
+}
+
+/// Insert `,` thousands separators into a plain number like `-1250.5`.
+///
+/// Anything that isn't a decimal number is returned unchanged.
+#[must_use]
+pub fn group_digits(s: &str) -> Cow<'_, str> {
+    let (sign, rest) = s.strip_prefix('-').map_or(("", s), |r| ("-", r));
+    let (int, frac) = rest
+        .split_once('.')
+        .map_or((rest, None), |(i, f)| (i, Some(f)));
+
+    let is_digits = |p: &str| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(int) || frac.is_some_and(|f| !is_digits(f)) || int.len() <= 3 {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len() + int.len() / 3);
+    out.push_str(sign);
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    if let Some(f) = frac {
+        out.push('.');
+        out.push_str(f);
+    }
+
+    Cow::Owned(out)
+}
@@ -0,0 +1,36 @@
+use crate::{Verbosity, config, logging::with_thread_local};
+use std::cell::Cell;
+
+thread_local! {
+    /// Verbosity of the printer currently writing on this thread.
+    static VERBOSITY_OVERRIDE: Cell<Option<Verbosity>> = const { Cell::new(None) };
+}
+
+/// Whether formatters should drop non-error output.
+///
+/// A printer-level override (see `with_verbosity_override`) wins; otherwise
+/// the global `quiet` config decides.
+#[must_use]
+pub fn quiet_active() -> bool {
+    VERBOSITY_OVERRIDE
+        .with(Cell::get)
+        .map_or_else(config::isquiet, |v| v == Verbosity::Quiet)
+}
+
+/// Whether formatters should print verbose-only output.
+///
+/// A printer-level override wins; otherwise the global `verbose` config
+/// decides.
+#[must_use]
+pub fn verbose_active() -> bool {
+    VERBOSITY_OVERRIDE
+        .with(Cell::get)
+        .map_or_else(config::isverbose, |v| {
+            matches!(v, Verbosity::Verbose | Verbosity::Trace)
+        })
+}
+
+/// Run `f` with formatters at `verbosity` (`None` defers to the global).
+pub fn with_verbosity_override<T>(verbosity: Option<Verbosity>, f: impl FnOnce() -> T) -> T {
+    with_thread_local(&VERBOSITY_OVERRIDE, verbosity, f)
+}
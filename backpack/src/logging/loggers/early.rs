@@ -0,0 +1,278 @@
+use crate::logging::{
+    EmitsEvents, Fields, GlobalLoggerType, LogLevel, ProgressUpdate, ScreenLogger,
+};
+use std::{
+    collections::VecDeque,
+    panic::Location,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+/// One call captured before a real logger was installed.
+#[derive(Debug, Clone)]
+enum EarlyEvent {
+    Ok(String),
+    Warn(String),
+    Err(String),
+    Info(String),
+    Dim(String),
+    Intro(String),
+    Outro(String),
+    Done,
+    Step(String),
+    Debug(String),
+    Trace(String),
+    Table(Vec<String>, Vec<Vec<String>>),
+    Rule(Option<String>),
+    Heading(String),
+    Note(String),
+    Event {
+        level: LogLevel,
+        target: Option<String>,
+        msg: String,
+        fields: Fields,
+        location: &'static Location<'static>,
+    },
+}
+
+/// Stand-in global logger that records calls until `set_logger` runs.
+///
+/// Installed by `buffer_early`. Holds at most `capacity` events, dropping the
+/// oldest once full; `set_logger` replays the rest through the new logger in
+/// order. Progress updates are transient and not kept; prompts return their
+/// non-interactive defaults.
+#[derive(Debug)]
+pub struct EarlyBuffer {
+    events: Mutex<VecDeque<EarlyEvent>>,
+    capacity: AtomicUsize,
+    dropped: AtomicUsize,
+}
+
+impl EarlyBuffer {
+    /// Default number of events kept before the oldest are dropped.
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            events: Mutex::new(VecDeque::new()),
+            capacity: AtomicUsize::new(Self::DEFAULT_CAPACITY),
+            dropped: AtomicUsize::new(0),
+        }
+    }
+
+    /// Keep at most `capacity` events (minimum 1).
+    pub fn set_capacity(&self, capacity: usize) {
+        self.capacity.store(capacity.max(1), Ordering::Relaxed);
+    }
+
+    /// Number of buffered events waiting to be replayed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `events` lock is poisoned.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.lock().unwrap().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push(&self, event: EarlyEvent) {
+        let capacity = self.capacity.load(Ordering::Relaxed);
+        let mut events = self.events.lock().unwrap();
+        while events.len() >= capacity {
+            events.pop_front();
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        events.push_back(event);
+        drop(events);
+    }
+
+    /// Send every buffered event to `logger`, oldest first, and clear the buffer.
+    ///
+    /// If events were dropped for space, a warning saying how many comes first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `events` lock is poisoned.
+    pub fn replay(&self, logger: &dyn GlobalLoggerType) {
+        let events = std::mem::take(&mut *self.events.lock().unwrap());
+        let dropped = self.dropped.swap(0, Ordering::Relaxed);
+        if dropped > 0 {
+            let (noun, verb) = if dropped == 1 {
+                ("event", "was")
+            } else {
+                ("events", "were")
+            };
+            logger.warn(&format!(
+                "{dropped} early log {noun} {verb} dropped (buffer full)"
+            ));
+        }
+
+        for event in events {
+            match event {
+                EarlyEvent::Ok(m) => logger.ok(&m),
+                EarlyEvent::Warn(m) => logger.warn(&m),
+                EarlyEvent::Err(m) => logger.err(&m),
+                EarlyEvent::Info(m) => logger.info(&m),
+                EarlyEvent::Dim(m) => logger.dim(&m),
+                EarlyEvent::Intro(m) => logger.intro(&m),
+                EarlyEvent::Outro(m) => logger.outro(&m),
+                EarlyEvent::Done => logger.done(),
+                EarlyEvent::Step(m) => logger.step(&m),
+                EarlyEvent::Debug(m) => logger.debug(&m),
+                EarlyEvent::Trace(m) => logger.trace(&m),
+                EarlyEvent::Table(headers, rows) => {
+                    let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+                    logger.table(&headers, &rows);
+                }
+                EarlyEvent::Rule(label) => logger.rule(label.as_deref()),
+                EarlyEvent::Heading(text) => logger.heading(&text),
+                EarlyEvent::Note(text) => logger.note(&text),
+                EarlyEvent::Event {
+                    level,
+                    target: Some(target),
+                    msg,
+                    fields,
+                    location,
+                } => logger.emit_event_targeted(level, &target, &msg, &fields, location),
+                EarlyEvent::Event {
+                    level,
+                    target: None,
+                    msg,
+                    fields,
+                    location,
+                } => logger.emit_event_at(level, &msg, &fields, location),
+            }
+        }
+    }
+}
+
+impl Default for EarlyBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScreenLogger for EarlyBuffer {
+    fn ok(&self, m: &str) {
+        self.push(EarlyEvent::Ok(m.to_string()));
+    }
+
+    fn warn(&self, m: &str) {
+        self.push(EarlyEvent::Warn(m.to_string()));
+    }
+
+    fn err(&self, m: &str) {
+        self.push(EarlyEvent::Err(m.to_string()));
+    }
+
+    fn info(&self, m: &str) {
+        self.push(EarlyEvent::Info(m.to_string()));
+    }
+
+    fn dim(&self, m: &str) {
+        self.push(EarlyEvent::Dim(m.to_string()));
+    }
+
+    fn intro(&self, m: &str) {
+        self.push(EarlyEvent::Intro(m.to_string()));
+    }
+
+    fn outro(&self, m: &str) {
+        self.push(EarlyEvent::Outro(m.to_string()));
+    }
+
+    fn done(&self) {
+        self.push(EarlyEvent::Done);
+    }
+
+    fn step(&self, m: &str) {
+        self.push(EarlyEvent::Step(m.to_string()));
+    }
+
+    fn debug(&self, m: &str) {
+        self.push(EarlyEvent::Debug(m.to_string()));
+    }
+
+    fn trace(&self, m: &str) {
+        self.push(EarlyEvent::Trace(m.to_string()));
+    }
+
+    fn dump_tree(&self) {}
+
+    fn progress(&self, _update: &ProgressUpdate<'_>) {}
+
+    fn table(&self, headers: &[&str], rows: &[Vec<String>]) {
+        let headers = headers.iter().map(ToString::to_string).collect();
+        self.push(EarlyEvent::Table(headers, rows.to_vec()));
+    }
+
+    fn rule(&self, label: Option<&str>) {
+        self.push(EarlyEvent::Rule(label.map(str::to_string)));
+    }
+
+    fn heading(&self, text: &str) {
+        self.push(EarlyEvent::Heading(text.to_string()));
+    }
+
+    fn note(&self, text: &str) {
+        self.push(EarlyEvent::Note(text.to_string()));
+    }
+
+    fn confirm(&self, _question: &str) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
+    fn input(&self, _prompt: &str) -> anyhow::Result<String> {
+        Ok(String::new())
+    }
+}
+
+impl EmitsEvents for EarlyBuffer {
+    #[track_caller]
+    fn emit_event(&self, level: LogLevel, msg: &str, fields: &Fields) {
+        self.emit_event_at(level, msg, fields, Location::caller());
+    }
+
+    fn emit_event_at(
+        &self,
+        level: LogLevel,
+        msg: &str,
+        fields: &Fields,
+        location: &'static Location<'static>,
+    ) {
+        self.push(EarlyEvent::Event {
+            level,
+            target: None,
+            msg: msg.to_string(),
+            fields: fields.clone(),
+            location,
+        });
+    }
+
+    fn emit_event_targeted(
+        &self,
+        level: LogLevel,
+        target: &str,
+        msg: &str,
+        fields: &Fields,
+        location: &'static Location<'static>,
+    ) {
+        self.push(EarlyEvent::Event {
+            level,
+            target: Some(target.to_string()),
+            msg: msg.to_string(),
+            fields: fields.clone(),
+            location,
+        });
+    }
+}
+
+impl GlobalLoggerType for EarlyBuffer {}
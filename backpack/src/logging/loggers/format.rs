@@ -1,13 +1,13 @@
-use crate::config;
+use crate::logging::{quiet_active, verbose_active};
 
 /// A logger that *only formats* messages into strings.
 pub trait FormatLogger {
     fn is_quiet(&self) -> bool {
-        config::isquiet()
+        quiet_active()
     }
 
     fn is_verbose(&self) -> bool {
-        config::isverbose()
+        verbose_active()
     }
 
     fn ok_raw(&self, m: &str) -> String;
@@ -22,6 +22,18 @@ pub trait FormatLogger {
     fn debug_raw(&self, m: &str) -> String;
     fn trace_raw(&self, m: &str) -> String;
 
+    /// Style the label of a text progress line; the backend appends the
+    /// counts or gauge. Defaults to the label unchanged.
+    fn progress_raw(
+        &self,
+        label: &str,
+        _current: u64,
+        _total: Option<u64>,
+        _finished: bool,
+    ) -> String {
+        label.to_string()
+    }
+
     fn ok(&self, m: &str) -> Option<String> {
         if self.is_quiet() {
             None
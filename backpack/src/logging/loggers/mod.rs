@@ -1,9 +1,13 @@
+mod early;
 mod format;
 mod modern;
+mod null;
 mod screen;
 mod simple;
 
+pub use early::*;
 pub use format::*;
 pub use modern::*;
+pub use null::*;
 pub use screen::*;
 pub use simple::*;
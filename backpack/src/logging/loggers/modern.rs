@@ -1,50 +1,51 @@
-use crate::logging::FormatLogger;
+use crate::logging::{FormatLogger, emoji, glyph, step_glyph};
 
 /// A modern, minimal logger inspired by cliclack.
+#[derive(Clone, Copy)]
 pub struct ModernLogger;
 
 impl FormatLogger for ModernLogger {
     fn ok_raw(&self, m: &str) -> String {
-        format!("✔ {m}")
+        format!("{} {m}", glyph("✔", "+"))
     }
 
     fn warn_raw(&self, m: &str) -> String {
-        format!("⚠ {m}")
+        format!("{} {m}", glyph("⚠", "!"))
     }
 
     fn err_raw(&self, m: &str) -> String {
-        format!("✗ {m}")
+        format!("{} {m}", glyph("✗", "x"))
     }
 
     fn info_raw(&self, m: &str) -> String {
-        format!("ℹ {m}")
+        format!("{} {m}", glyph("ℹ", "i"))
     }
 
     fn dim_raw(&self, m: &str) -> String {
-        format!("› {m}")
+        format!("{} {m}", glyph("›", ">"))
     }
 
     fn intro_raw(&self, m: &str) -> String {
-        format!("→ {m}")
+        format!("{} {m}", glyph("→", "->"))
     }
 
     fn outro_raw(&self, m: &str) -> String {
-        format!("✔ {m}")
+        format!("{} {m}", glyph("✔", "+"))
     }
 
     fn done_raw(&self) -> String {
-        "✔ Done!".to_string()
+        format!("{} Done!", glyph("✔", "+"))
     }
 
     fn step_raw(&self, m: &str) -> String {
-        format!("⠿ {m}")
+        format!("{} {m}", step_glyph())
     }
 
     fn debug_raw(&self, m: &str) -> String {
-        format!("🔍 {m}")
+        format!("{} {m}", emoji("🔍", "[debug]"))
     }
 
     fn trace_raw(&self, m: &str) -> String {
-        format!("📡 {m}")
+        format!("{} {m}", emoji("📡", "[trace]"))
     }
 }
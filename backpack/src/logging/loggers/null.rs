@@ -0,0 +1,48 @@
+use crate::logging::{
+    EmitsEvents, Fields, GlobalLoggerType, LogLevel, ProgressUpdate, ScreenLogger,
+};
+
+/// A logger that discards everything.
+///
+/// Handy as a default for libraries that accept a `&dyn ScreenLogger` when
+/// the host application hasn't configured one. Prompts return `false` and an
+/// empty string.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullLogger;
+
+impl ScreenLogger for NullLogger {
+    fn ok(&self, _m: &str) {}
+    fn warn(&self, _m: &str) {}
+    fn err(&self, _m: &str) {}
+    fn info(&self, _m: &str) {}
+    fn dim(&self, _m: &str) {}
+    fn intro(&self, _m: &str) {}
+    fn outro(&self, _m: &str) {}
+    fn done(&self) {}
+    fn step(&self, _m: &str) {}
+    fn debug(&self, _m: &str) {}
+    fn trace(&self, _m: &str) {}
+    fn dump_tree(&self) {}
+    fn progress(&self, _update: &ProgressUpdate<'_>) {}
+    fn table(&self, _headers: &[&str], _rows: &[Vec<String>]) {}
+    fn rule(&self, _label: Option<&str>) {}
+    fn heading(&self, _text: &str) {}
+
+    fn confirm(&self, _question: &str) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+
+    fn input(&self, _prompt: &str) -> anyhow::Result<String> {
+        Ok(String::new())
+    }
+
+    fn enabled(&self, _level: LogLevel) -> bool {
+        false
+    }
+}
+
+impl EmitsEvents for NullLogger {
+    fn emit_event(&self, _level: LogLevel, _msg: &str, _fields: &Fields) {}
+}
+
+impl GlobalLoggerType for NullLogger {}
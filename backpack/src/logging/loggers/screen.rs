@@ -1,3 +1,6 @@
+use crate::logging::{LogLevel, ProgressUpdate};
+use std::time::Instant;
+
 pub trait ScreenLogger {
     fn ok(&self, m: &str);
     fn warn(&self, m: &str);
@@ -11,5 +14,50 @@ pub trait ScreenLogger {
     fn debug(&self, m: &str);
     fn trace(&self, m: &str);
     fn dump_tree(&self);
-    fn progress(&self, label: &str, current: u64, total: Option<u64>, finished: bool);
+    fn progress(&self, update: &ProgressUpdate<'_>);
+    fn table(&self, headers: &[&str], rows: &[Vec<String>]);
+    fn rule(&self, label: Option<&str>);
+
+    /// Print a prominent section title, not tied to task timing.
+    fn heading(&self, text: &str);
+
+    /// Ask a yes/no question; non-interactive loggers return a default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the question can't be asked or answered.
+    fn confirm(&self, question: &str) -> anyhow::Result<bool>;
+
+    /// Ask for a line of text; non-interactive loggers return a default.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the prompt can't be shown or the line read.
+    fn input(&self, prompt: &str) -> anyhow::Result<String>;
+
+    /// Number of currently open tasks (intro without outro/done).
+    fn task_depth(&self) -> usize {
+        0
+    }
+
+    /// The current time by this logger's clock; see `Printer::set_clock`.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    /// Keep `text` for the end-of-run notes section; see `Printer::flush_notes`.
+    ///
+    /// Loggers that don't collect notes drop them.
+    fn note(&self, _text: &str) {}
+
+    /// Whether any error has been logged; see `logging::exit_code`.
+    fn had_errors(&self) -> bool {
+        false
+    }
+
+    /// Whether a message at `level` would be shown; lets callers skip
+    /// building expensive messages that would be dropped anyway.
+    fn enabled(&self, _level: LogLevel) -> bool {
+        true
+    }
 }
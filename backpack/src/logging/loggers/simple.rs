@@ -1,78 +1,51 @@
-use crate::{config, logging::FormatLogger};
+use crate::logging::{FormatLogger, color_scheme, glyph, paint, step_glyph};
 
 /// A simple ANSI-based logger.
+#[derive(Clone, Copy)]
 pub struct SimpleLogger;
 
 impl FormatLogger for SimpleLogger {
     fn ok_raw(&self, m: &str) -> String {
-        if config::isnocolor() {
-            format!("+ {m}")
-        } else {
-            format!("\x1b[32m✔\x1b[0m {m}")
-        }
+        format!("{} {m}", paint(color_scheme().ok, glyph("✔", "+")))
     }
 
     fn warn_raw(&self, m: &str) -> String {
-        if config::isnocolor() {
-            format!("! {m}")
-        } else {
-            format!("\x1b[33m⚠\x1b[0m {m}")
-        }
+        format!("{} {m}", paint(color_scheme().warn, glyph("⚠", "!")))
     }
 
     fn err_raw(&self, m: &str) -> String {
-        if config::isnocolor() {
-            format!("X {m}")
-        } else {
-            format!("\x1b[31m✗\x1b[0m {m}")
-        }
+        format!("{} {m}", paint(color_scheme().err, glyph("✗", "X")))
     }
 
     fn info_raw(&self, m: &str) -> String {
-        format!("  {m}")
+        format!("  {}", paint(color_scheme().info, m))
     }
 
     fn dim_raw(&self, m: &str) -> String {
-        if config::isnocolor() {
-            format!("  {m}")
-        } else {
-            format!("\x1b[90m  {m}\x1b[0m")
-        }
+        paint(color_scheme().dim, &format!("  {m}"))
     }
 
     fn intro_raw(&self, m: &str) -> String {
-        format!("→ {m}")
+        format!("{} {m}", glyph("→", "->"))
     }
 
     fn outro_raw(&self, m: &str) -> String {
-        format!("✓ {m}")
+        format!("{} {m}", glyph("✓", "+"))
     }
 
     fn done_raw(&self) -> String {
-        "✓ Done!".to_string()
+        format!("{} Done!", glyph("✓", "+"))
     }
 
     fn step_raw(&self, m: &str) -> String {
-        if config::isnocolor() {
-            format!("* {m}")
-        } else {
-            format!("\x1b[36m⠿\x1b[0m {m}")
-        }
+        format!("{} {m}", paint(color_scheme().step, step_glyph()))
     }
 
     fn debug_raw(&self, m: &str) -> String {
-        if config::isnocolor() {
-            format!("[debug] {m}")
-        } else {
-            format!("\x1b[34m[debug]\x1b[0m {m}")
-        }
+        format!("{} {m}", paint(color_scheme().debug, "[debug]"))
     }
 
     fn trace_raw(&self, m: &str) -> String {
-        if config::isnocolor() {
-            format!("[trace] {m}")
-        } else {
-            format!("\x1b[90m[trace]\x1b[0m {m}")
-        }
+        format!("{} {m}", paint(color_scheme().trace, "[trace]"))
     }
 }
@@ -26,6 +26,7 @@
 //! ## Quick Start
 //!
 //! ```rust
+//! # #[cfg(feature = "modern")] {
 //! use log_rs::logging::{
 //!     set_logger, log, Printer, ModernLogger, ModernBackend, Verbosity, LogFormat,
 //! };
@@ -46,6 +47,7 @@
 //! // ⠿ Uploading files
 //! // ✔ All files uploaded
 //! // ✔ Deployment complete (took 2.3s)
+//! # }
 //! ```
 //!
 //! ## Verbosity Levels Explained
@@ -71,6 +73,13 @@
 //! {"level":"warn","message":"⚠ Cache miss","timestamp":"2026-01-15T10:30:02Z"}
 //! ```
 //!
+//! **CSV Mode** (--format=csv):
+//! ```text
+//! level,message,user_id
+//! info,User logged in,42
+//! info,User logged out,42
+//! ```
+//!
 //! ## Architecture
 //!
 //! Two-layer design for clean separation of concerns:
@@ -0,0 +1,81 @@
+use crate::{
+    LogFormat, Verbosity,
+    logging::{FormatLogger, Printer, RenderBackend},
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A reusable template for building several independent printers.
+///
+/// `Printer::new` sets the global quiet/verbose flags and initializes tracing
+/// on every call. A builder does that only for the first printer it builds
+/// (the "primary"); later builds get their own format and verbosity without
+/// touching global state.
+pub struct PrinterBuilder<L: FormatLogger + Clone, B: RenderBackend + Clone> {
+    inner: L,
+    backend: B,
+    format: LogFormat,
+    verbosity: Verbosity,
+    primary_built: AtomicBool,
+    /// Global setup run for the primary build; `init` outside tests.
+    pub(crate) init: fn() -> Result<(), Box<dyn std::error::Error>>,
+}
+
+impl<L: FormatLogger + Clone, B: RenderBackend + Clone> Printer<L, B> {
+    /// Start a builder for text output at normal verbosity.
+    pub const fn builder(inner: L, backend: B) -> PrinterBuilder<L, B> {
+        PrinterBuilder {
+            inner,
+            backend,
+            format: LogFormat::Text,
+            verbosity: Verbosity::Normal,
+            primary_built: AtomicBool::new(false),
+            init: crate::logging::init,
+        }
+    }
+}
+
+impl<L: FormatLogger + Clone, B: RenderBackend + Clone> PrinterBuilder<L, B> {
+    #[must_use]
+    pub const fn format(mut self, format: LogFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    #[must_use]
+    pub const fn verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Change the format used by subsequent builds.
+    pub const fn set_format(&mut self, format: LogFormat) {
+        self.format = format;
+    }
+
+    /// Change the verbosity used by subsequent builds.
+    pub const fn set_verbosity(&mut self, verbosity: Verbosity) {
+        self.verbosity = verbosity;
+    }
+
+    /// Whether the primary printer has been built yet.
+    pub fn primary_built(&self) -> bool {
+        self.primary_built.load(Ordering::Relaxed)
+    }
+
+    /// Build a printer from the current template.
+    ///
+    /// Only the first call sets global flags and initializes tracing.
+    pub fn build(&self) -> Printer<L, B> {
+        if !self.primary_built.swap(true, Ordering::Relaxed) {
+            Printer::<L, B>::apply_global_verbosity(self.verbosity);
+            let _ = (self.init)();
+        }
+
+        Printer::from_parts(
+            self.inner.clone(),
+            self.backend.clone(),
+            self.format,
+            self.verbosity,
+        )
+    }
+}
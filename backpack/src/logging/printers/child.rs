@@ -0,0 +1,221 @@
+use crate::{
+    Verbosity,
+    logging::{
+        EmitsEvents, Fields, FormatLogger, LogLevel, Printer, ProgressUpdate, RenderBackend,
+        ScreenLogger,
+    },
+};
+use std::{panic::Location, sync::Arc, time::Instant};
+
+/// A tagged view of a parent `Printer`.
+///
+/// Children share the parent's backend, task stack and output format, so they
+/// never re-initialize tracing. Every message is prefixed with `[tag]`. An
+/// optional verbosity override can only narrow what the parent already shows.
+/// A child holds its own handle on the parent, so it can move to another thread.
+pub struct ChildLogger<L: FormatLogger, B: RenderBackend> {
+    parent: Arc<Printer<L, B>>,
+    tag: String,
+    verbosity: Option<Verbosity>,
+}
+
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Create a child logger that prefixes every message with `[tag]`.
+    pub fn child(self: &Arc<Self>, tag: &str) -> ChildLogger<L, B> {
+        ChildLogger {
+            parent: Arc::clone(self),
+            tag: tag.to_string(),
+            verbosity: None,
+        }
+    }
+}
+
+impl<L: FormatLogger, B: RenderBackend> ChildLogger<L, B> {
+    /// Override the verbosity for this child only.
+    #[must_use]
+    pub const fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = Some(verbosity);
+        self
+    }
+
+    #[must_use]
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    fn tagged(&self, m: &str) -> String {
+        format!("[{}] {m}", self.tag)
+    }
+
+    const fn is_quiet(&self) -> bool {
+        matches!(self.verbosity, Some(Verbosity::Quiet))
+    }
+
+    const fn is_verbose(&self) -> bool {
+        !matches!(self.verbosity, Some(Verbosity::Quiet | Verbosity::Normal))
+    }
+}
+
+impl<L: FormatLogger, B: RenderBackend> ScreenLogger for ChildLogger<L, B> {
+    fn ok(&self, m: &str) {
+        if !self.is_quiet() {
+            ScreenLogger::ok(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn warn(&self, m: &str) {
+        if !self.is_quiet() {
+            ScreenLogger::warn(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn err(&self, m: &str) {
+        ScreenLogger::err(&*self.parent, &self.tagged(m));
+    }
+
+    fn info(&self, m: &str) {
+        if !self.is_quiet() {
+            ScreenLogger::info(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn dim(&self, m: &str) {
+        if !self.is_quiet() {
+            ScreenLogger::dim(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn intro(&self, m: &str) {
+        if !self.is_quiet() {
+            ScreenLogger::intro(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn outro(&self, m: &str) {
+        ScreenLogger::outro(&*self.parent, &self.tagged(m));
+    }
+
+    fn done(&self) {
+        ScreenLogger::done(&*self.parent);
+    }
+
+    fn step(&self, m: &str) {
+        if !self.is_quiet() {
+            ScreenLogger::step(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn debug(&self, m: &str) {
+        if self.is_verbose() {
+            ScreenLogger::debug(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn trace(&self, m: &str) {
+        if self.is_verbose() {
+            ScreenLogger::trace(&*self.parent, &self.tagged(m));
+        }
+    }
+
+    fn dump_tree(&self) {
+        ScreenLogger::dump_tree(&*self.parent);
+    }
+
+    fn progress(&self, update: &ProgressUpdate<'_>) {
+        let label = self.tagged(update.label);
+        ScreenLogger::progress(
+            &*self.parent,
+            &ProgressUpdate {
+                label: &label,
+                ..*update
+            },
+        );
+    }
+
+    fn table(&self, headers: &[&str], rows: &[Vec<String>]) {
+        if !self.is_quiet() {
+            ScreenLogger::table(&*self.parent, headers, rows);
+        }
+    }
+
+    fn rule(&self, label: Option<&str>) {
+        if !self.is_quiet() {
+            ScreenLogger::rule(&*self.parent, label);
+        }
+    }
+
+    fn heading(&self, text: &str) {
+        if !self.is_quiet() {
+            ScreenLogger::heading(&*self.parent, text);
+        }
+    }
+
+    fn confirm(&self, question: &str) -> anyhow::Result<bool> {
+        ScreenLogger::confirm(&*self.parent, &self.tagged(question))
+    }
+
+    fn input(&self, prompt: &str) -> anyhow::Result<String> {
+        ScreenLogger::input(&*self.parent, &self.tagged(prompt))
+    }
+
+    fn task_depth(&self) -> usize {
+        self.parent.task_depth()
+    }
+
+    fn now(&self) -> Instant {
+        self.parent.now()
+    }
+
+    fn note(&self, text: &str) {
+        ScreenLogger::note(&*self.parent, &format!("[{}] {text}", self.tag));
+    }
+
+    fn had_errors(&self) -> bool {
+        self.parent.had_errors()
+    }
+
+    fn enabled(&self, level: LogLevel) -> bool {
+        let narrowed = match level {
+            LogLevel::Error => true,
+            LogLevel::Info | LogLevel::Warn | LogLevel::Progress => !self.is_quiet(),
+            LogLevel::Debug | LogLevel::Trace => self.is_verbose(),
+        };
+
+        narrowed && self.parent.enabled(level)
+    }
+}
+
+impl<L: FormatLogger, B: RenderBackend> EmitsEvents for ChildLogger<L, B> {
+    fn emit_event(&self, level: LogLevel, msg: &str, fields: &Fields) {
+        if ScreenLogger::enabled(self, level) {
+            self.parent.emit_event(level, &self.tagged(msg), fields);
+        }
+    }
+
+    fn emit_event_at(
+        &self,
+        level: LogLevel,
+        msg: &str,
+        fields: &Fields,
+        location: &'static Location<'static>,
+    ) {
+        if ScreenLogger::enabled(self, level) {
+            self.parent
+                .emit_event_at(level, &self.tagged(msg), fields, location);
+        }
+    }
+
+    fn emit_event_targeted(
+        &self,
+        level: LogLevel,
+        target: &str,
+        msg: &str,
+        fields: &Fields,
+        location: &'static Location<'static>,
+    ) {
+        if ScreenLogger::enabled(self, level) {
+            self.parent
+                .emit_event_targeted(level, target, &self.tagged(msg), fields, location);
+        }
+    }
+}
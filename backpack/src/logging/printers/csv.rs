@@ -0,0 +1,68 @@
+use crate::logging::{
+    Fields, FormatLogger, LogLevel, Printer, RenderBackend, Stream, output_lock, write_line_on,
+};
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+// Printer: CSV emission for spreadsheet-friendly batch output
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Emit one CSV row: `level,message,<field values...>`.
+    ///
+    /// The first event also writes the header row, built from its own field
+    /// keys. Later events are not re-aligned against that header, so mixing
+    /// different field sets produces ragged rows. All rows go to stdout so the
+    /// output stays a single well-formed document.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `csv_header` lock is poisoned.
+    pub fn emit_csv_fields(&self, level: LogLevel, message: &str, fields: Option<&Fields>) {
+        self.routed(|| {
+            self.mark_error(level);
+            let fields = fields.map(|f| self.redact_fields(f));
+            let fields = fields.as_deref();
+            let empty = Fields::new();
+            let fields = fields.unwrap_or(&empty);
+
+            // Hold the flag across the write so the header is always the first line.
+            // The output lock comes first, matching `emit_many`.
+            let output = output_lock();
+            let mut header_written = self.csv_header.lock().unwrap();
+
+            if !*header_written {
+                let header = ["level", "message"]
+                    .into_iter()
+                    .chain(fields.keys().map(String::as_str));
+                write_line_on(Stream::Stdout, &csv_row(header));
+                *header_written = true;
+            }
+
+            let label = self.level_label(level);
+            let row = [label.as_ref(), message]
+                .into_iter()
+                .chain(fields.values().map(String::as_str));
+            write_line_on(Stream::Stdout, &csv_row(row));
+            drop(header_written);
+            drop(output);
+            self.flush_after(level);
+        });
+    }
+
+    pub fn emit_csv(&self, level: LogLevel, message: &str) {
+        self.emit_csv_fields(level, message, None);
+    }
+}
+
+fn csv_row<'a>(cells: impl Iterator<Item = &'a str>) -> String {
+    cells.map(csv_escape).collect::<Vec<_>>().join(",")
+}
+
+/// Quote a cell per RFC 4180 when it contains a comma, quote, or line break.
+pub(crate) fn csv_escape(cell: &str) -> Cow<'_, str> {
+    if cell.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", cell.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(cell)
+    }
+}
@@ -0,0 +1,332 @@
+use crate::{
+    LogFormat, Verbosity,
+    banner::{hyperlink, hyperlinks_enabled},
+    config::Config,
+    logging::{
+        ColorScheme, FormatLogger, Printer, RenderBackend, TimestampFormat, TimestampMode,
+        ansi_enabled, escape_control, with_ansi, with_color_scheme, with_emoji,
+    },
+};
+use chrono::format::{Item, StrftimeItems};
+use std::{borrow::Cow, sync::atomic::Ordering};
+
+// -----------------------------------------------------------------------------
+// Printer: per-line prefixes for text-mode output
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Prefix text lines with `[thread-name]` (and add a `thread` key in JSON)
+    /// when running at Verbose or Trace verbosity.
+    pub fn set_show_thread(&self, show: bool) {
+        self.show_thread.store(show, Ordering::Relaxed);
+    }
+
+    /// Prefix text lines with the current time (`None` disables, the default).
+    ///
+    /// Honors the printer's `TimestampMode`: `Fixed` prints its value verbatim
+    /// and `Disabled` prints nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the prefix unchanged, if a
+    /// `TimestampFormat::Custom` pattern isn't valid `chrono` strftime syntax.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `text_timestamp` lock is poisoned.
+    pub fn set_text_timestamp(&self, format: Option<TimestampFormat>) -> anyhow::Result<()> {
+        if let Some(TimestampFormat::Custom(pattern)) = format
+            && StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+        {
+            anyhow::bail!("invalid timestamp pattern: {pattern:?}");
+        }
+
+        *self.text_timestamp.lock().unwrap() = format;
+        Ok(())
+    }
+
+    /// Force ANSI color on or off for this printer only.
+    ///
+    /// Until called, the global `nocolor` config decides.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `ansi` lock is poisoned.
+    pub fn set_ansi(&self, ansi: bool) {
+        *self.ansi.lock().unwrap() = Some(ansi);
+    }
+
+    /// Apply the display settings of a loaded `log.toml` to this printer.
+    ///
+    /// `nocolor = true` turns ANSI color off; otherwise the color setting is
+    /// left as is. Verbosity stays whatever the printer was built with.
+    pub fn apply_config(&self, config: &Config) {
+        if config.nocolor {
+            self.set_ansi(false);
+        }
+    }
+
+    /// Builder form of `apply_config`.
+    #[must_use]
+    pub fn with_config(self, config: &Config) -> Self {
+        self.apply_config(config);
+        self
+    }
+
+    /// Let formatters draw emoji (default on); off swaps in plain markers
+    /// such as `[debug]` and `[trace]`.
+    pub fn set_emoji(&self, enabled: bool) {
+        self.emoji.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Cycle step glyphs through a braille spinner (default off), one frame
+    /// per step within each task, so progress reads as motion.
+    ///
+    /// Only in text mode on a terminal (see `set_tty`) at Verbose or Trace;
+    /// elsewhere steps keep the static `⠿`.
+    pub fn set_step_glyph_rotation(&self, rotate: bool) {
+        self.step_glyph_rotation.store(rotate, Ordering::Relaxed);
+    }
+
+    /// Whether steps should get a spinner frame instead of the static glyph.
+    pub(crate) fn rotates_step_glyph(&self) -> bool {
+        self.step_glyph_rotation.load(Ordering::Relaxed)
+            && self.format == LogFormat::Text
+            && matches!(self.verbosity(), Verbosity::Verbose | Verbosity::Trace)
+            && self.is_tty()
+    }
+
+    /// Recolor `SimpleLogger` output for this printer only.
+    ///
+    /// Until called, the process-wide scheme (`set_color_scheme`) applies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `color_scheme` lock is poisoned.
+    pub fn set_color_scheme(&self, scheme: ColorScheme) {
+        *self.color_scheme.lock().unwrap() = Some(scheme);
+    }
+
+    /// Escape control characters in text-mode messages as `\xNN` (default on).
+    ///
+    /// Stops logged content from driving the terminal, e.g. clearing it with
+    /// `\x1b[2J`. Newlines and tabs pass through; JSON is escaped by serde.
+    pub fn set_sanitize(&self, sanitize: bool) {
+        self.sanitize.store(sanitize, Ordering::Relaxed);
+    }
+
+    /// `m` with control characters escaped, when sanitizing text output.
+    pub(crate) fn sanitized<'a>(&self, m: &'a str) -> Cow<'a, str> {
+        if self.format == LogFormat::Text && self.sanitize.load(Ordering::Relaxed) {
+            escape_control(m)
+        } else {
+            Cow::Borrowed(m)
+        }
+    }
+
+    /// Turn `path:line` locations in text-mode error messages into clickable
+    /// OSC-8 `file://` links (default off).
+    ///
+    /// Only takes effect where `banner::hyperlinks_enabled` says the terminal
+    /// renders them.
+    pub fn set_link_paths(&self, link: bool) {
+        self.link_paths.store(link, Ordering::Relaxed);
+    }
+
+    /// `m` with source locations hyperlinked, when path linking applies.
+    pub(crate) fn linked<'a>(&self, m: &'a str) -> Cow<'a, str> {
+        if self.format == LogFormat::Text
+            && self.link_paths.load(Ordering::Relaxed)
+            && hyperlinks_enabled()
+        {
+            link_source_locations(m)
+        } else {
+            Cow::Borrowed(m)
+        }
+    }
+
+    /// Run a formatter call under this printer's color and emoji settings.
+    pub(crate) fn styled<T>(&self, f: impl FnOnce(&L) -> T) -> T {
+        let ansi = *self.ansi.lock().unwrap();
+        let scheme = *self.color_scheme.lock().unwrap();
+        let emoji = self.emoji.load(Ordering::Relaxed);
+        with_ansi(ansi, || {
+            with_color_scheme(scheme, || with_emoji(emoji, || f(&self.inner)))
+        })
+    }
+
+    /// Whether this printer emits ANSI escapes, by its own setting or else
+    /// the process-wide one.
+    pub(crate) fn uses_ansi(&self) -> bool {
+        self.styled(|_| ansi_enabled())
+    }
+
+    /// Indent step/ok/info lines by `width` spaces per open task (default 2).
+    pub fn set_indent_width(&self, width: usize) {
+        self.indent_width.store(width, Ordering::Relaxed);
+    }
+
+    /// Set how timestamps are produced for JSON output and text prefixes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `timestamp` lock is poisoned.
+    pub fn set_timestamp_mode(&self, mode: TimestampMode) {
+        *self.timestamp.lock().unwrap() = mode;
+    }
+
+    /// Builder form of `set_timestamp_mode`.
+    ///
+    /// `TimestampMode::Disabled` or `Fixed(..)` make output deterministic,
+    /// e.g. for snapshot tests.
+    #[must_use]
+    pub fn with_timestamp_mode(self, mode: TimestampMode) -> Self {
+        self.set_timestamp_mode(mode);
+        self
+    }
+
+    /// Rendered text-mode timestamp, if enabled.
+    fn text_timestamp(&self) -> Option<String> {
+        let format = (*self.text_timestamp.lock().unwrap())?;
+        let mode = *self.timestamp.lock().unwrap();
+
+        match mode {
+            TimestampMode::Real => {
+                let now = chrono::Local::now();
+                Some(match format {
+                    TimestampFormat::Rfc3339 => now.to_rfc3339(),
+                    TimestampFormat::Time => now.format("%H:%M:%S").to_string(),
+                    TimestampFormat::Custom(pattern) => now.format(pattern).to_string(),
+                })
+            }
+            TimestampMode::Disabled => None,
+            TimestampMode::Fixed(value) => Some(value.to_string()),
+            TimestampMode::UnixMillis => Some(chrono::Utc::now().timestamp_millis().to_string()),
+        }
+    }
+
+    /// Label of the current thread, if thread display is active.
+    pub(crate) fn thread_label(&self) -> Option<String> {
+        let active = self.show_thread.load(Ordering::Relaxed)
+            && matches!(self.verbosity(), Verbosity::Verbose | Verbosity::Trace);
+
+        active.then(current_thread_label)
+    }
+
+    /// Indent `s` under the currently open tasks.
+    pub(crate) fn indent<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let depth = self.tasks.lock().unwrap().len();
+        let width = depth * self.indent_width.load(Ordering::Relaxed);
+        if width == 0 {
+            return Cow::Borrowed(s);
+        }
+
+        let pad = " ".repeat(width);
+        Cow::Owned(
+            s.split('\n')
+                .map(|line| format!("{pad}{line}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+
+    /// Apply line prefixes and the max line width to a rendered text line.
+    pub(crate) fn decorate<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let mut prefix = String::new();
+
+        if let Some(timestamp) = self.text_timestamp() {
+            prefix.push_str(&timestamp);
+            prefix.push(' ');
+        }
+
+        if let Some(run_id) = self.run_id() {
+            prefix.push('[');
+            prefix.push_str(&run_id);
+            prefix.push_str("] ");
+        }
+
+        if let Some(thread) = self.thread_label() {
+            prefix.push('[');
+            prefix.push_str(&thread);
+            prefix.push_str("] ");
+        }
+
+        if prefix.is_empty() {
+            return self.fit_width(s);
+        }
+
+        prefix.push_str(s);
+        Cow::Owned(self.fit_width(&prefix).into_owned())
+    }
+}
+
+/// The current thread's name, falling back to its numeric id.
+fn current_thread_label() -> String {
+    let thread = std::thread::current();
+
+    thread.name().map_or_else(
+        || {
+            let id = format!("{:?}", thread.id());
+            id.trim_start_matches("ThreadId(")
+                .trim_end_matches(')')
+                .to_string()
+        },
+        str::to_string,
+    )
+}
+
+/// Wrap each `path:line` or `path:line:col` in `m` in an OSC-8 link to the file.
+fn link_source_locations(m: &str) -> Cow<'_, str> {
+    let is_path_byte = |b: u8| b.is_ascii_alphanumeric() || b"_-./~:".contains(&b);
+    let bytes = m.as_bytes();
+    let mut out = String::new();
+    let (mut copied, mut i) = (0, 0);
+
+    while i < bytes.len() {
+        if !is_path_byte(bytes[i]) {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && is_path_byte(bytes[i]) {
+            i += 1;
+        }
+
+        // Sentence punctuation after a location isn't part of it.
+        let run = m[start..i].trim_end_matches(['.', ':']);
+        if let Some(path) = source_location_path(run) {
+            let url = std::path::absolute(path).map_or_else(
+                |_| format!("file://{path}"),
+                |abs| format!("file://{}", abs.display()),
+            );
+            out.push_str(&m[copied..start]);
+            out.push_str(&hyperlink(run, &url, true));
+            copied = start + run.len();
+        }
+    }
+
+    if copied == 0 {
+        return Cow::Borrowed(m);
+    }
+    out.push_str(&m[copied..]);
+    Cow::Owned(out)
+}
+
+/// The file part of `path:line[:col]`, if `run` looks like a source location.
+///
+/// The path needs a `/` or an alphabetic extension, so `127.0.0.1:80` and
+/// `localhost:8080` are left alone.
+fn source_location_path(run: &str) -> Option<&str> {
+    let mut parts = run.split(':');
+    let path = parts.next()?;
+    let numbers: Vec<&str> = parts.collect();
+
+    let is_number = |s: &&str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let looks_like_file = path.contains('/')
+        || path.rsplit_once('.').is_some_and(|(stem, ext)| {
+            !stem.is_empty() && ext.starts_with(|c: char| c.is_ascii_alphabetic())
+        });
+
+    (matches!(numbers.len(), 1 | 2) && numbers.iter().all(is_number) && looks_like_file)
+        .then_some(path)
+}
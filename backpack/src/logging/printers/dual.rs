@@ -0,0 +1,149 @@
+use crate::logging::{
+    Fields, FormatLogger, LogFormat, LogLevel, MultiProgress, Printer, ProgressRender,
+    ProgressUpdate, RenderBackend, Stream, with_progress_stream, write_inline, write_inline_on,
+};
+use std::sync::atomic::Ordering;
+
+// -----------------------------------------------------------------------------
+// Printer: progress events (structured) and progress lines (human)
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// In JSON mode, also draw progress live on stderr (default off).
+    ///
+    /// Machines read the JSON progress events on stdout while a person
+    /// watching the terminal sees the usual progress line, with a gauge if
+    /// `set_progress_bar` is on.
+    pub fn set_progress_dual(&self, dual: bool) {
+        self.progress_dual.store(dual, Ordering::Relaxed);
+    }
+
+    /// Emit `update` as a structured `progress` event.
+    pub(crate) fn emit_progress_json(&self, update: &ProgressUpdate<'_>) {
+        let mut fields = Fields::new();
+        fields.insert("current".into(), update.current.to_string());
+        if let Some(total) = update.total {
+            fields.insert("total".into(), total.to_string());
+        }
+        if let Some(rate) = update.rate() {
+            fields.insert("rate".into(), format!("{rate:.2}"));
+        }
+        if update.finished {
+            fields.insert("finished".into(), "true".into());
+            fields.insert("status".into(), update.status().into());
+        } else if let Some(eta) = update.eta_seconds() {
+            fields.insert("eta_seconds".into(), format!("{eta:.1}"));
+        }
+
+        self.emit_json_fields(LogLevel::Progress, update.label, Some(&fields));
+    }
+
+    /// Send text progress to `stream` (default stderr), whatever the stream
+    /// policy, so bars never end up in piped stdout data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `progress_stream` lock is poisoned.
+    pub fn set_progress_to(&self, stream: Stream) {
+        *self.progress_stream.lock().unwrap() = stream;
+    }
+
+    /// Render `update` through the backend as a text progress line, on the
+    /// stream chosen with `set_progress_to`.
+    pub(crate) fn render_progress_text(&self, update: &ProgressUpdate<'_>) {
+        let stream = *self.progress_stream.lock().unwrap();
+        with_progress_stream(stream, || match MultiProgress::live_row() {
+            Some((multi, index)) => {
+                let styled = self.progress_label(update);
+                let label = self.decorate(&styled);
+                let update = self.text_progress(update, &label, stream);
+                let line = if update.finished {
+                    format!("{} — {}", update.line(), update.status())
+                } else {
+                    update.line()
+                };
+                multi.draw_row(index, line);
+            }
+            None => self.render_progress_line(update, stream),
+        });
+    }
+
+    /// A coordinator for several concurrent `Progress` handles.
+    ///
+    /// Live (one redrawn row per handle) in text mode with ANSI on, when the
+    /// progress stream is a terminal (see `set_tty` and `set_progress_to`);
+    /// otherwise each handle keeps printing its own lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `progress_stream` lock is poisoned.
+    #[must_use]
+    pub fn multi_progress(&self) -> MultiProgress {
+        let stream = *self.progress_stream.lock().unwrap();
+        MultiProgress::new(
+            self.format == LogFormat::Text && self.stream_is_tty(stream) && self.uses_ansi(),
+        )
+    }
+
+    fn render_progress_line(&self, update: &ProgressUpdate<'_>, stream: Stream) {
+        let styled = self.progress_label(update);
+        let label = self.decorate(&styled);
+        let update = self.text_progress(update, &label, stream);
+        let render = *self.progress_render.lock().unwrap();
+
+        match (render, update.finished) {
+            (ProgressRender::Final, false) => return,
+            (ProgressRender::Inline, false) => {
+                write_inline(LogLevel::Progress, &format!("\r\x1b[2K{}", update.line()));
+                return;
+            }
+            // Clear the in-place line before the final render.
+            (ProgressRender::Inline, true) => {
+                write_inline(LogLevel::Progress, "\r\x1b[2K");
+            }
+            (ProgressRender::Lines | ProgressRender::Final, _) => {}
+        }
+
+        self.report_backend_error(self.backend.render_progress(&update));
+    }
+
+    /// Redraw `update` in place on stderr, ending the line once finished.
+    ///
+    /// Without a terminal on stderr, or with ANSI off, nothing can be redrawn:
+    /// only the finished line is printed.
+    pub(crate) fn render_progress_live(&self, update: &ProgressUpdate<'_>) {
+        let label = self.progress_label(update);
+        let update = self.text_progress(update, &label, Stream::Stderr);
+        let line = if self.stream_is_tty(Stream::Stderr) && self.uses_ansi() {
+            let end = if update.finished { "\n" } else { "" };
+            format!("\r\x1b[2K{}{end}", update.line())
+        } else if update.finished {
+            format!("{}\n", update.line())
+        } else {
+            return;
+        };
+
+        write_inline_on(Stream::Stderr, &line);
+    }
+
+    /// The update's label as styled by the formatter's `progress_raw`.
+    fn progress_label(&self, update: &ProgressUpdate<'_>) -> String {
+        self.styled(|f| f.progress_raw(update.label, update.current, update.total, update.finished))
+    }
+
+    /// `update` relabeled with this printer's digit grouping and the gauge
+    /// width for `stream`.
+    fn text_progress<'a>(
+        &self,
+        update: &ProgressUpdate<'_>,
+        label: &'a str,
+        stream: Stream,
+    ) -> ProgressUpdate<'a> {
+        let mut update = ProgressUpdate {
+            label,
+            group_digits: self.group_digits.load(Ordering::Relaxed),
+            ..*update
+        };
+        update.bar_width = self.progress_bar_width(&update, stream);
+        update
+    }
+}
@@ -0,0 +1,62 @@
+use crate::logging::{FormatLogger, LogEvent, LogLevel, Printer, RenderBackend};
+use std::{backtrace::BacktraceStatus, error::Error, sync::atomic::Ordering};
+
+// -----------------------------------------------------------------------------
+// Printer: error events carrying their source chain
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Flush a buffering backend (such as `BatchBackend`) as soon as an
+    /// error is written, so the lines leading up to it are out too (default
+    /// on).
+    pub fn set_flush_on_error(&self, flush: bool) {
+        self.flush_on_error.store(flush, Ordering::Relaxed);
+    }
+
+    /// Apply the flush-on-error policy after writing an event at `level`.
+    pub(crate) fn flush_after(&self, level: LogLevel) {
+        if level == LogLevel::Error && self.flush_on_error.load(Ordering::Relaxed) {
+            self.report_backend_error(self.backend.flush());
+        }
+    }
+
+    /// Error event for `err`, with its `source()` chain in an `error_chain`
+    /// field (`outer: inner: root`).
+    #[track_caller]
+    pub fn error_source<'a>(&'a self, err: &(dyn Error + 'static)) -> LogEvent<'a, Self> {
+        let event = LogEvent::new(self, LogLevel::Error, &err.to_string());
+
+        if err.source().is_none() {
+            return event;
+        }
+
+        event.field("error_chain", error_chain(err))
+    }
+
+    /// Error event for an `anyhow::Error`: the message is the top-level
+    /// error, `error_chain` holds every context layer, and `backtrace` is
+    /// added when one was captured (e.g. with `RUST_BACKTRACE=1`).
+    #[track_caller]
+    pub fn error_anyhow<'a>(&'a self, err: &anyhow::Error) -> LogEvent<'a, Self> {
+        let event = self.error_source(err.as_ref());
+
+        if err.backtrace().status() == BacktraceStatus::Captured {
+            event.field("backtrace", err.backtrace())
+        } else {
+            event
+        }
+    }
+}
+
+/// `err` and all of its sources, joined like anyhow's `{:#}` format.
+fn error_chain(err: &(dyn Error + 'static)) -> String {
+    let mut chain = err.to_string();
+    let mut source = err.source();
+
+    while let Some(e) = source {
+        chain.push_str(": ");
+        chain.push_str(&e.to_string());
+        source = e.source();
+    }
+
+    chain
+}
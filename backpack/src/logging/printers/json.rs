@@ -1,7 +1,65 @@
 use crate::logging::{
     EmitsEvents, Fields, FormatLogger, LogEvent, LogLevel, Printer, RenderBackend, TimestampMode,
+    color_scheme, flush_stream, group_digits, output_lock, paint, write_line,
 };
-use crate::{LogFormat, Verbosity};
+use crate::{LogFormat, Verbosity, logging::printers::run_id::generate_event_id};
+use std::{borrow::Cow, collections::HashMap, sync::atomic::Ordering};
+
+/// Names used for the standard keys of a JSON event.
+///
+/// Defaults to `level`, `message`, `timestamp` and `fields`:
+///
+/// ```
+/// use log_rs::logging::JsonKeyMap;
+///
+/// let keys = JsonKeyMap::default().message("msg").level("severity");
+/// assert_eq!(keys.message, "msg");
+/// assert_eq!(keys.timestamp, "timestamp");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonKeyMap {
+    pub level: String,
+    pub message: String,
+    pub timestamp: String,
+    pub fields: String,
+}
+
+impl Default for JsonKeyMap {
+    fn default() -> Self {
+        Self {
+            level: "level".into(),
+            message: "message".into(),
+            timestamp: "timestamp".into(),
+            fields: "fields".into(),
+        }
+    }
+}
+
+impl JsonKeyMap {
+    #[must_use]
+    pub fn level(mut self, key: &str) -> Self {
+        self.level = key.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn message(mut self, key: &str) -> Self {
+        self.message = key.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn timestamp(mut self, key: &str) -> Self {
+        self.timestamp = key.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn fields(mut self, key: &str) -> Self {
+        self.fields = key.to_string();
+        self
+    }
+}
 
 // -----------------------------------------------------------------------------
 // Printer: unified emit_event, JSON helpers, and builder-style APIs
@@ -10,35 +68,106 @@ impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
     // -------------------------------------------------------------------------
     // JSON emission (single unified implementation)
     // -------------------------------------------------------------------------
+    /// Emit one JSON event at `level`, with `fields` merged in after the
+    /// standard keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `json_keys` or `level_labels` lock is poisoned.
     pub fn emit_json_fields(&self, level: LogLevel, message: &str, fields: Option<&Fields>) {
-        let mut obj = serde_json::json!({
-            "level": level.as_str(),
-            "message": message,
-        });
+        self.routed(|| {
+            self.mark_error(level);
+            let fields = fields.map(|f| self.redact_fields(f));
+            let fields = fields.as_deref();
+            let keys = self.json_keys.lock().unwrap();
+            let mut obj = serde_json::json!({
+                &keys.level: self.level_label(level),
+                &keys.message: message,
+            });
 
-        let timestamp = *self.timestamp.lock().unwrap();
-        match timestamp {
-            TimestampMode::Real => {
-                obj["timestamp"] = serde_json::Value::String(chrono::Utc::now().to_rfc3339());
+            if self.event_ids.load(Ordering::Relaxed) {
+                obj["id"] = serde_json::Value::String(generate_event_id());
             }
-            TimestampMode::Disabled => {
-                // do nothing
+
+            let timestamp = *self.timestamp.lock().unwrap();
+            match timestamp {
+                TimestampMode::Real => {
+                    obj[&keys.timestamp] =
+                        serde_json::Value::String(chrono::Utc::now().to_rfc3339());
+                }
+                TimestampMode::Disabled => {
+                    // do nothing
+                }
+                TimestampMode::Fixed(value) => {
+                    obj[&keys.timestamp] = serde_json::Value::String(value.to_string());
+                }
+                TimestampMode::UnixMillis => {
+                    obj[&keys.timestamp] = chrono::Utc::now().timestamp_millis().into();
+                }
             }
-            TimestampMode::Fixed(value) => {
-                obj["timestamp"] = serde_json::Value::String(value.to_string());
+
+            if let Some(thread) = self.thread_label() {
+                obj["thread"] = serde_json::Value::String(thread);
             }
-        }
 
-        if let Some(f) = fields
-            && !f.is_empty()
-        {
-            obj["fields"] = serde_json::to_value(f).unwrap();
-        }
+            if let Some(f) = fields
+                && !f.is_empty()
+            {
+                obj[&keys.fields] = serde_json::to_value(f).unwrap();
+            }
 
-        match level {
-            LogLevel::Error => eprintln!("{obj}"),
-            _ => println!("{obj}"),
-        }
+            if let Some(run_id) = self.run_id() {
+                if !obj[&keys.fields].is_object() {
+                    obj[&keys.fields] = serde_json::json!({});
+                }
+                obj[&keys.fields]["run_id"] = serde_json::Value::String(run_id);
+            }
+            drop(keys);
+
+            self.report_backend_error(self.backend.render_json(level, &obj.to_string()));
+            self.flush_after(level);
+            if self.line_buffered.load(Ordering::Relaxed) {
+                flush_stream(level);
+            }
+        });
+    }
+
+    /// Rename the standard keys of JSON events, e.g. `message` to `msg` for
+    /// an aggregator that expects it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `json_keys` lock is poisoned.
+    pub fn set_json_key_map(&self, keys: JsonKeyMap) {
+        *self.json_keys.lock().unwrap() = keys;
+    }
+
+    /// Override the level text of JSON and CSV events, e.g. `warn` to
+    /// `WARNING` or to syslog severities. Unmapped levels keep
+    /// `LogLevel::as_str`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `level_labels` lock is poisoned.
+    pub fn set_level_labels(&self, labels: HashMap<LogLevel, String>) {
+        *self.level_labels.lock().unwrap() = labels;
+    }
+
+    pub(crate) fn level_label(&self, level: LogLevel) -> Cow<'static, str> {
+        self.level_labels
+            .lock()
+            .unwrap()
+            .get(&level)
+            .map_or(Cow::Borrowed(level.as_str()), |l| Cow::Owned(l.clone()))
+    }
+
+    /// Flush the stream after every JSON event (default on).
+    ///
+    /// Keeps newline-delimited JSON flowing through pipes one event at a
+    /// time; turn off to let the stream buffer batch writes. Only affects
+    /// backends that print to stdout/stderr.
+    pub fn set_line_buffered(&self, line_buffered: bool) {
+        self.line_buffered.store(line_buffered, Ordering::Relaxed);
     }
 
     pub fn emit_json(&self, level: LogLevel, message: &str) {
@@ -49,53 +178,67 @@ impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
     // Text-mode emission with fields support
     // -------------------------------------------------------------------------
     pub fn emit_text_fields(&self, level: LogLevel, msg: &str, fields: Option<&Fields>) {
-        // Format the message with fields appended if present
-        let formatted_msg = if let Some(f) = fields
-            && !f.is_empty()
-        {
-            let fields_str = f
-                .iter()
-                .map(|(k, v)| format!("\x1b[2m{k}={v}\x1b[0m")) // dim style
-                .collect::<Vec<_>>()
-                .join(" ");
-            format!("{msg} {fields_str}")
-        } else {
-            msg.to_string()
-        };
-
-        match level {
-            LogLevel::Info => {
-                if let Some(s) = self.inner.info(&formatted_msg) {
-                    let _ = self.backend.render_info(&s);
+        self.routed(|| {
+            self.mark_error(level);
+            let fields = fields.map(|f| self.redact_fields(f));
+            let fields = fields.as_deref();
+            let msg = self.sanitized(msg);
+            // Format the message with fields appended if present
+            let formatted_msg = if let Some(f) = fields
+                && !f.is_empty()
+            {
+                let field = |(k, v): (&String, &String)| {
+                    let (k, v) = (self.sanitized(k), self.sanitized(v));
+                    let v = if self.group_digits.load(Ordering::Relaxed) {
+                        group_digits(&v).into_owned()
+                    } else {
+                        v.into_owned()
+                    };
+                    paint(color_scheme().field, &format!("{k}={v}"))
+                };
+                let fields_str = self.styled(|_| f.iter().map(field).collect::<Vec<_>>().join(" "));
+                format!("{msg} {fields_str}")
+            } else {
+                msg.into_owned()
+            };
+
+            match level {
+                LogLevel::Info => {
+                    if let Some(s) = self.styled(|f| f.info(&formatted_msg)) {
+                        self.report_backend_error(
+                            self.backend.render_info(&self.decorate(&self.indent(&s))),
+                        );
+                    }
                 }
-            }
-            LogLevel::Warn => {
-                if let Some(s) = self.inner.warn(&formatted_msg) {
-                    let _ = self.backend.render_warning(&s);
+                LogLevel::Warn => {
+                    if let Some(s) = self.styled(|f| f.warn(&formatted_msg)) {
+                        self.report_backend_error(self.backend.render_warning(&self.decorate(&s)));
+                    }
                 }
-            }
-            LogLevel::Error => {
-                let s = self.inner.err(&formatted_msg);
-                let _ = self.backend.render_error(&s);
-            }
-            LogLevel::Debug => {
-                if matches!(self.verbosity, Verbosity::Verbose | Verbosity::Trace)
-                    && let Some(s) = self.inner.debug(&formatted_msg)
-                {
-                    let _ = self.backend.render_debug(&s);
+                LogLevel::Error => {
+                    let s = self.styled(|f| f.err(&self.linked(&formatted_msg)));
+                    self.report_backend_error(self.backend.render_error(&self.decorate(&s)));
+                    self.flush_after(level);
                 }
-            }
-            LogLevel::Trace => {
-                if self.verbosity == Verbosity::Trace
-                    && let Some(s) = self.inner.trace(&formatted_msg)
-                {
-                    let _ = self.backend.render_trace(&s);
+                LogLevel::Debug => {
+                    if matches!(self.verbosity(), Verbosity::Verbose | Verbosity::Trace)
+                        && let Some(s) = self.styled(|f| f.debug(&formatted_msg))
+                    {
+                        self.report_backend_error(self.backend.render_debug(&self.decorate(&s)));
+                    }
+                }
+                LogLevel::Trace => {
+                    if self.verbosity() == Verbosity::Trace
+                        && let Some(s) = self.styled(|f| f.trace(&formatted_msg))
+                    {
+                        self.report_backend_error(self.backend.render_trace(&self.decorate(&s)));
+                    }
+                }
+                LogLevel::Progress => {
+                    write_line(LogLevel::Progress, &self.decorate(&formatted_msg));
                 }
             }
-            LogLevel::Progress => {
-                println!("{formatted_msg}");
-            }
-        }
+        });
     }
 
     pub fn emit_text(&self, level: LogLevel, msg: &str) {
@@ -106,89 +249,136 @@ impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
     // Public: structured logging (used by Drop-based LogEvent)
     // -------------------------------------------------------------------------
     pub fn emit_event(&self, level: LogLevel, msg: &str, fields: &Fields) {
+        self.record_event(level, msg);
+        let fields = self.with_task_fields(fields);
         match self.format {
-            LogFormat::Json => self.emit_json_fields(level, msg, Some(fields)),
-            LogFormat::Text => self.emit_text_fields(level, msg, Some(fields)),
+            LogFormat::Json => self.emit_json_fields(level, msg, Some(&fields)),
+            LogFormat::Csv => self.emit_csv_fields(level, msg, Some(&fields)),
+            LogFormat::Text => self.emit_text_fields(level, msg, Some(&fields)),
+        }
+    }
+
+    /// Emit several events under one hold of the output lock.
+    ///
+    /// Cheaper than one `emit_event` per event for bulk logging, and no other
+    /// thread's output lands between them. Order and stream routing are kept.
+    pub fn emit_many(&self, events: &[(LogLevel, &str, Fields)]) {
+        let _output = output_lock();
+        for (level, msg, fields) in events {
+            self.emit_event(*level, msg, fields);
+        }
+    }
+
+    /// Merge the innermost task's fields under `fields`; the event's own win.
+    pub(crate) fn with_task_fields<'a>(&self, fields: &'a Fields) -> Cow<'a, Fields> {
+        let tasks = self.tasks.lock().unwrap();
+        match tasks.last() {
+            Some(task) if !task.fields.is_empty() => {
+                let mut merged = task.fields.clone();
+                drop(tasks);
+                merged.merge(fields);
+                Cow::Owned(merged)
+            }
+            _ => Cow::Borrowed(fields),
         }
     }
 
     // -------------------------------------------------------------------------
     // Convenience: legacy API for structured fields
     // -------------------------------------------------------------------------
+    /// Open a task whose `fields` appear on every event until its outro.
+    pub fn intro_with_fields(&self, m: &str, fields: Fields) {
+        self.report_backend_error(self.try_intro_with_fields(m, fields));
+    }
+
     pub fn info_with_fields(&self, m: &str, fields: &Fields) {
-        match self.format {
-            LogFormat::Json => self.emit_json_fields(LogLevel::Info, m, Some(fields)),
-            LogFormat::Text => self.emit_text_fields(LogLevel::Info, m, Some(fields)),
-        }
+        self.emit_event(LogLevel::Info, m, fields);
     }
 
     // -------------------------------------------------------------------------
     // Builder-style APIs (Drop-based structured logging)
     // -------------------------------------------------------------------------
+    #[track_caller]
     pub fn info<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Info, msg)
     }
 
+    #[track_caller]
     pub fn warn<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Warn, msg)
     }
 
+    #[track_caller]
     pub fn error<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Error, msg)
     }
 
+    #[track_caller]
     pub fn debug<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Debug, msg)
     }
 
+    #[track_caller]
     pub fn trace<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Trace, msg)
     }
 
+    #[track_caller]
     pub fn ok_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Info, msg)
     }
 
+    #[track_caller]
     pub fn warn_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Warn, msg)
     }
 
+    #[track_caller]
     pub fn err_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Error, msg)
     }
 
+    #[track_caller]
     pub fn info_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Info, msg)
     }
 
+    #[track_caller]
     pub fn dim_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Debug, msg)
     }
 
+    #[track_caller]
     pub fn debug_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Debug, msg)
     }
 
+    #[track_caller]
     pub fn trace_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Trace, msg)
     }
 
+    #[track_caller]
     pub fn intro_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Trace, msg)
     }
 
+    #[track_caller]
     pub fn step_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Trace, msg)
     }
 
+    #[track_caller]
     pub fn outro_event<'a>(&'a self, msg: &str) -> LogEvent<'a, Self> {
         LogEvent::new(self, LogLevel::Trace, msg)
     }
 
+    #[track_caller]
     pub fn done_event(&self) -> LogEvent<'_, Self> {
         LogEvent::new(self, LogLevel::Trace, "")
     }
 
+    #[track_caller]
     pub fn dump_tree_event(&self) -> LogEvent<'_, Self> {
         LogEvent::new(self, LogLevel::Debug, "")
     }
@@ -197,9 +387,27 @@ impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
 // Let Printer be a source of structured events for LogEvent
 impl<L: FormatLogger, B: RenderBackend> EmitsEvents for Printer<L, B> {
     fn emit_event(&self, level: LogLevel, msg: &str, fields: &crate::logging::Fields) {
-        match self.format {
-            LogFormat::Json => self.emit_json_fields(level, msg, Some(fields)),
-            LogFormat::Text => self.emit_text_fields(level, msg, Some(fields)),
-        }
+        Self::emit_event(self, level, msg, fields);
+    }
+
+    fn emit_event_at(
+        &self,
+        level: LogLevel,
+        msg: &str,
+        fields: &crate::logging::Fields,
+        location: &'static std::panic::Location<'static>,
+    ) {
+        self.emit_located(level, msg, fields, location);
+    }
+
+    fn emit_event_targeted(
+        &self,
+        level: LogLevel,
+        target: &str,
+        msg: &str,
+        fields: &crate::logging::Fields,
+        location: &'static std::panic::Location<'static>,
+    ) {
+        self.emit_targeted(level, target, msg, fields, location);
     }
 }
@@ -0,0 +1,59 @@
+use crate::LogFormat;
+use crate::logging::{Fields, FormatLogger, LogLevel, Printer, RenderBackend};
+use std::{panic::Location, sync::atomic::Ordering};
+
+// -----------------------------------------------------------------------------
+// Printer: call-site locations for structured events
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Report where each event was built: `at file:line` in text mode,
+    /// `file`/`line` fields in JSON and CSV.
+    pub fn set_show_location(&self, show: bool) {
+        self.show_location.store(show, Ordering::Relaxed);
+    }
+
+    pub(crate) fn emit_located(
+        &self,
+        level: LogLevel,
+        msg: &str,
+        fields: &Fields,
+        location: &'static Location<'static>,
+    ) {
+        if !self.show_location.load(Ordering::Relaxed) {
+            self.emit_event(level, msg, fields);
+            return;
+        }
+
+        match self.format {
+            LogFormat::Text => {
+                self.record_event(level, msg);
+                let msg = format!("{msg} at {}:{}", location.file(), location.line());
+                self.emit_text_fields(level, &msg, Some(&self.with_task_fields(fields)));
+            }
+            LogFormat::Json | LogFormat::Csv => {
+                let mut fields = fields.clone();
+                fields.insert("file".into(), location.file().to_string());
+                fields.insert("line".into(), location.line().to_string());
+                self.emit_event(level, msg, &fields);
+            }
+        }
+    }
+
+    /// `[target]` prefix in text mode, a `target` field in JSON and CSV.
+    pub(crate) fn emit_targeted(
+        &self,
+        level: LogLevel,
+        target: &str,
+        msg: &str,
+        fields: &Fields,
+        location: &'static Location<'static>,
+    ) {
+        if self.format == LogFormat::Text {
+            self.emit_located(level, &format!("[{target}] {msg}"), fields, location);
+        } else {
+            let mut fields = fields.clone();
+            fields.insert("target".into(), target.to_string());
+            self.emit_located(level, msg, &fields, location);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::logging::{FormatLogger, Printer, RenderBackend, format_duration};
+use std::{fmt::Write as _, io::Write, sync::atomic::Ordering, time::Duration};
+
+/// One task as it will appear in the markdown report.
+#[derive(Debug, Clone)]
+struct JournalTask {
+    task_id: u64,
+    label: String,
+    depth: usize,
+    duration: Option<Duration>,
+    steps: Vec<String>,
+    errors: Vec<String>,
+}
+
+/// Tasks, steps and errors in the order they were logged, kept for
+/// `Printer::render_markdown_report`.
+#[derive(Debug, Default)]
+pub struct RunJournal {
+    tasks: Vec<JournalTask>,
+    errors: Vec<String>,
+}
+
+impl RunJournal {
+    pub(crate) fn open(&mut self, task_id: u64, label: &str, depth: usize) {
+        self.tasks.push(JournalTask {
+            task_id,
+            label: label.to_string(),
+            depth,
+            duration: None,
+            steps: Vec::new(),
+            errors: Vec::new(),
+        });
+    }
+
+    pub(crate) fn close(&mut self, task_id: u64, duration: Duration) {
+        if let Some(task) = self.task_mut(Some(task_id)) {
+            task.duration = Some(duration);
+        }
+    }
+
+    /// Record a step under the open task `task_id`; steps outside any task
+    /// are not part of the report.
+    pub(crate) fn step(&mut self, task_id: Option<u64>, m: &str) {
+        if let Some(task) = self.task_mut(task_id) {
+            task.steps.push(m.to_string());
+        }
+    }
+
+    pub(crate) fn error(&mut self, task_id: Option<u64>, m: &str) {
+        match self.task_mut(task_id) {
+            Some(task) => task.errors.push(m.to_string()),
+            None => self.errors.push(m.to_string()),
+        }
+    }
+
+    fn task_mut(&mut self, task_id: Option<u64>) -> Option<&mut JournalTask> {
+        let task_id = task_id?;
+        self.tasks.iter_mut().rev().find(|t| t.task_id == task_id)
+    }
+
+    fn render(&self) -> String {
+        let mut md = String::from("## Run report\n");
+
+        if self.tasks.is_empty() && self.errors.is_empty() {
+            md.push_str("\n_No tasks were run._\n");
+            return md;
+        }
+
+        for task in &self.tasks {
+            let hashes = "#".repeat((3 + task.depth).min(6));
+            let timing = task
+                .duration
+                .map_or_else(|| "unfinished".to_string(), format_duration);
+            let _ = write!(md, "\n{hashes} {} ({timing})\n", task.label);
+
+            if !task.steps.is_empty() {
+                md.push('\n');
+                for step in &task.steps {
+                    let _ = writeln!(md, "- {step}");
+                }
+            }
+            push_errors(&mut md, &task.errors);
+        }
+
+        if !self.errors.is_empty() {
+            md.push_str("\n### Errors\n");
+            push_errors(&mut md, &self.errors);
+        }
+
+        md
+    }
+}
+
+/// Append `errors` as one fenced block, if there are any.
+fn push_errors(md: &mut String, errors: &[String]) {
+    if errors.is_empty() {
+        return;
+    }
+
+    md.push_str("\n```text\n");
+    for error in errors {
+        md.push_str(error);
+        md.push('\n');
+    }
+    md.push_str("```\n");
+}
+
+// -----------------------------------------------------------------------------
+// Printer: markdown run report
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Keep a journal of tasks, steps and errors for
+    /// `render_markdown_report` (default off).
+    ///
+    /// The journal grows with every entry, so leave it off for long-running
+    /// processes that never render a report.
+    pub fn set_markdown_journal(&self, on: bool) {
+        self.markdown_journal.store(on, Ordering::Relaxed);
+    }
+
+    fn journal_enabled(&self) -> bool {
+        self.markdown_journal.load(Ordering::Relaxed)
+    }
+
+    /// The run so far as a markdown document, e.g. for a PR comment.
+    ///
+    /// Each task is a heading with its duration (`###`, one level deeper per
+    /// nesting), its steps a bullet list, and its errors a fenced block.
+    /// Errors logged outside any task go under a trailing `Errors` heading.
+    /// Only entries logged while `set_markdown_journal` is on are included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `journal` lock is poisoned.
+    #[must_use]
+    pub fn render_markdown_report(&self) -> String {
+        self.journal.lock().unwrap().render()
+    }
+
+    /// Write `render_markdown_report` to `w`, regardless of the output format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to or flushing `w` fails.
+    pub fn write_markdown_report(&self, w: &mut dyn Write) -> anyhow::Result<()> {
+        w.write_all(self.render_markdown_report().as_bytes())?;
+        w.flush()?;
+        Ok(())
+    }
+
+    /// The id of the innermost open task, for journal entries.
+    fn current_task_id(&self) -> Option<u64> {
+        self.tasks.lock().unwrap().last().map(|t| t.task_id)
+    }
+
+    pub(crate) fn journal_open(&self, task_id: u64, label: &str, depth: usize) {
+        if self.journal_enabled() {
+            self.journal.lock().unwrap().open(task_id, label, depth);
+        }
+    }
+
+    pub(crate) fn journal_close(&self, task_id: u64, elapsed: Duration) {
+        if self.journal_enabled() {
+            self.journal.lock().unwrap().close(task_id, elapsed);
+        }
+    }
+
+    pub(crate) fn journal_step(&self, m: &str) {
+        if !self.journal_enabled() {
+            return;
+        }
+        let task_id = self.current_task_id();
+        self.journal.lock().unwrap().step(task_id, m);
+    }
+
+    pub(crate) fn journal_error(&self, m: &str) {
+        if !self.journal_enabled() {
+            return;
+        }
+        let task_id = self.current_task_id();
+        self.journal.lock().unwrap().error(task_id, m);
+    }
+}
@@ -1,15 +1,55 @@
+#[cfg(not(feature = "tracing"))]
+use crate::logging::internal::spans::{debug, error, info, trace, warn};
+use crate::logging::{Span, step_span, task_span};
 use crate::{
     LogFormat, Verbosity,
     logging::{
-        EmitsEvents, FormatLogger, GlobalLoggerType, LogLevel, RenderBackend, ScreenLogger,
-        TimestampMode, format_duration,
+        Clock, ColorScheme, EmitsEvents, Fields, FormatLogger, GlobalLoggerType, GlyphMode,
+        LogLevel, LoggerSettings, ProgressRender, ProgressUpdate, QuietProfile, RenderBackend,
+        STEP_SPINNER, ScreenLogger, Stream, StreamPolicy, TimestampFormat, TimestampMode,
+        display_width, format_duration, with_step_glyph, with_stream_policy,
+        with_verbosity_override, write_line_on,
     },
 };
-use std::{sync::Mutex, time::Instant};
-use tracing::{Level, debug, error, info, span, span::Span, trace, warn};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+#[cfg(feature = "tracing")]
+use tracing::{debug, error, info, trace, warn};
 
+pub mod builder;
+pub mod child;
+pub mod csv;
+pub mod decorate;
+pub mod dual;
+pub mod errors;
 pub mod json;
+pub mod location;
+pub mod markdown;
+pub mod notes;
+pub mod once;
+pub mod prompt;
+pub mod redact;
+pub mod report;
+pub mod run_id;
+pub mod summary;
+pub mod task_guard;
 pub mod task_tree;
+pub mod verbosity;
+pub mod width;
+
+pub use builder::PrinterBuilder;
+pub use child::ChildLogger;
+pub use json::JsonKeyMap;
+pub use markdown::RunJournal;
+pub use report::{EventCounters, EventCounts};
+pub use task_guard::TaskGuard;
 
 /// A span that tracks when it was entered so we can compute
 /// how long the task took when `outro()` / `done()` is called.
@@ -18,6 +58,30 @@ pub struct TimedSpan {
     pub span: Span,
     pub start: Instant,
     pub label: String,
+    /// Steps emitted while this task was active, including nested tasks.
+    pub step_count: usize,
+    /// Deepest nesting reached below (and including) this task.
+    pub max_depth: usize,
+    /// When the last step in this task was emitted (the intro, initially).
+    pub last_step: Instant,
+    /// Spinner frame for this task's next step, when step glyphs rotate.
+    pub glyph_frame: usize,
+    /// Consecutive runs of this label so far, counting this one; see
+    /// `Printer::set_collapse_reruns`.
+    pub run: usize,
+    /// Per-printer id pairing this task's intro and outro JSON events.
+    pub task_id: u64,
+    /// Fields stamped on every event emitted while this is the innermost task.
+    pub fields: Fields,
+}
+
+impl TimedSpan {
+    /// Time since the intro by the system clock. Printers time tasks with
+    /// their own clock instead; see `Printer::set_clock`.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
 }
 
 /// A screen logger that prints formatted messages and, in verbose/trace mode,
@@ -30,10 +94,81 @@ pub struct Printer<L: FormatLogger, B: RenderBackend> {
     pub format: LogFormat,
     pub verbosity: Verbosity,
     pub timestamp: Mutex<TimestampMode>,
+    pub csv_header: Mutex<bool>,
+    pub max_line_width: Mutex<Option<usize>>,
+    pub terminal_width: Mutex<Option<usize>>,
+    pub tty: Mutex<Option<bool>>,
+    pub backend_failed: AtomicBool,
+    pub redacted_keys: Mutex<Vec<String>>,
+    pub show_thread: AtomicBool,
+    pub text_timestamp: Mutex<Option<TimestampFormat>>,
+    pub run_id: Mutex<Option<String>>,
+    pub indent_width: AtomicUsize,
+    pub show_location: AtomicBool,
+    pub progress_render: Mutex<ProgressRender>,
+    pub group_digits: AtomicBool,
+    pub progress_bar: AtomicBool,
+    pub progress_dual: AtomicBool,
+    pub confirm_default: AtomicBool,
+    pub input_default: Mutex<String>,
+    pub next_task_id: AtomicU64,
+    pub ansi: Mutex<Option<bool>>,
+    pub color_scheme: Mutex<Option<ColorScheme>>,
+    pub dim_min_verbosity: Mutex<Verbosity>,
+    pub line_buffered: AtomicBool,
+    pub quiet_profile: Mutex<QuietProfile>,
+    pub emoji: AtomicBool,
+    pub sanitize: AtomicBool,
+    pub step_glyph_rotation: AtomicBool,
+    pub link_paths: AtomicBool,
+    pub max_task_depth: AtomicUsize,
+    pub task_depth_warned: AtomicBool,
+    pub outro_timing: AtomicBool,
+    pub counts: EventCounters,
+    pub errors_seen: AtomicBool,
+    pub started: Instant,
+    pub completed: Mutex<Vec<(String, Duration)>>,
+    pub collapse_reruns: AtomicBool,
+    /// Label and run number of the last completed task.
+    pub last_run: Mutex<Option<(String, usize)>>,
+    pub journal: Mutex<RunJournal>,
+    pub markdown_journal: AtomicBool,
+    pub notes: Mutex<Vec<String>>,
+    pub notes_printed: AtomicUsize,
+    pub json_keys: Mutex<JsonKeyMap>,
+    pub level_labels: Mutex<HashMap<LogLevel, String>>,
+    pub flush_on_error: AtomicBool,
+    pub progress_stream: Mutex<Stream>,
+    pub stream_policy: Mutex<Option<StreamPolicy>>,
+    /// Level set with `set_verbosity`, replacing `verbosity` for this printer.
+    pub verbosity_override: Mutex<Option<Verbosity>>,
+    pub clock: Mutex<Option<Arc<dyn Clock>>>,
+    pub seen_once: Mutex<HashSet<String>>,
+    pub event_ids: AtomicBool,
+}
+
+/// Bounds for the progress gauge width; see `set_progress_bar`.
+const MIN_BAR_WIDTH: usize = 10;
+const MAX_BAR_WIDTH: usize = 40;
+
+/// Open tasks allowed before intros stop pushing spans; see `set_max_task_depth`.
+const DEFAULT_MAX_TASK_DEPTH: usize = 1024;
+
+/// The `task_id` field attached to a task's intro and outro JSON events.
+fn task_id_fields(task_id: u64) -> Fields {
+    Fields::from([("task_id".to_string(), task_id.to_string())])
 }
 
 impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
     pub fn new(inner: L, backend: B, format: LogFormat, verbosity: Verbosity) -> Self {
+        Self::apply_global_verbosity(verbosity);
+        let _ = crate::logging::init();
+
+        Self::from_parts(inner, backend, format, verbosity)
+    }
+
+    /// Mirror `verbosity` into the global quiet/verbose flags.
+    fn apply_global_verbosity(verbosity: Verbosity) {
         match verbosity {
             Verbosity::Quiet => {
                 crate::config::setquiet(true);
@@ -48,269 +183,919 @@ impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
                 crate::config::setverbose(true);
             }
         }
+    }
 
-        let _ = crate::logging::init();
-
-        let printer = Self {
+    /// Assemble a printer without touching global config or tracing.
+    fn from_parts(inner: L, backend: B, format: LogFormat, verbosity: Verbosity) -> Self {
+        Self {
             inner,
             backend,
             tasks: Mutex::new(Vec::new()),
             steps: Mutex::new(Vec::new()),
             format,
             verbosity,
-            timestamp: Mutex::new(TimestampMode::Real),
+            timestamp: Mutex::new(TimestampMode::default()),
+            csv_header: Mutex::new(false),
+            max_line_width: Mutex::new(None),
+            terminal_width: Mutex::new(None),
+            tty: Mutex::new(None),
+            backend_failed: AtomicBool::new(false),
+            redacted_keys: Mutex::new(Vec::new()),
+            show_thread: AtomicBool::new(false),
+            text_timestamp: Mutex::new(None),
+            run_id: Mutex::new(None),
+            indent_width: AtomicUsize::new(2),
+            show_location: AtomicBool::new(false),
+            progress_render: Mutex::new(ProgressRender::default()),
+            group_digits: AtomicBool::new(false),
+            progress_bar: AtomicBool::new(false),
+            progress_dual: AtomicBool::new(false),
+            confirm_default: AtomicBool::new(false),
+            input_default: Mutex::new(String::new()),
+            next_task_id: AtomicU64::new(1),
+            ansi: Mutex::new(None),
+            color_scheme: Mutex::new(None),
+            dim_min_verbosity: Mutex::new(Verbosity::Normal),
+            line_buffered: AtomicBool::new(true),
+            quiet_profile: Mutex::new(QuietProfile::default()),
+            emoji: AtomicBool::new(true),
+            sanitize: AtomicBool::new(true),
+            step_glyph_rotation: AtomicBool::new(false),
+            link_paths: AtomicBool::new(false),
+            max_task_depth: AtomicUsize::new(DEFAULT_MAX_TASK_DEPTH),
+            task_depth_warned: AtomicBool::new(false),
+            outro_timing: AtomicBool::new(true),
+            counts: EventCounters::default(),
+            errors_seen: AtomicBool::new(false),
+            started: Instant::now(),
+            completed: Mutex::new(Vec::new()),
+            collapse_reruns: AtomicBool::new(false),
+            last_run: Mutex::new(None),
+            journal: Mutex::new(RunJournal::default()),
+            markdown_journal: AtomicBool::new(false),
+            notes: Mutex::new(Vec::new()),
+            notes_printed: AtomicUsize::new(0),
+            json_keys: Mutex::new(JsonKeyMap::default()),
+            level_labels: Mutex::new(HashMap::new()),
+            flush_on_error: AtomicBool::new(true),
+            progress_stream: Mutex::new(Stream::Stderr),
+            stream_policy: Mutex::new(None),
+            verbosity_override: Mutex::new(None),
+            clock: Mutex::new(None),
+            seen_once: Mutex::new(HashSet::new()),
+            event_ids: AtomicBool::new(false),
+        }
+    }
+
+    /// The `task_id` and `duration_ms` fields attached to a task's outro JSON event.
+    fn outro_fields(&self, task: &TimedSpan) -> Fields {
+        let mut fields = task_id_fields(task.task_id);
+        fields.insert(
+            "duration_ms".to_string(),
+            self.since(task.start).as_millis().to_string(),
+        );
+        fields
+    }
+
+    /// Pop the innermost task and record its duration for `summary`.
+    fn complete_task(&self) -> Option<TimedSpan> {
+        let task = self.tasks.lock().unwrap().pop()?;
+        let elapsed = self.since(task.start);
+        self.completed
+            .lock()
+            .unwrap()
+            .push((task.label.clone(), elapsed));
+        *self.last_run.lock().unwrap() = Some((task.label.clone(), task.run));
+        self.journal_close(task.task_id, elapsed);
+        Some(task)
+    }
+
+    /// Pop the current task and append its timing to an outro/done message.
+    ///
+    /// In Trace verbosity the step count and peak depth are reported too,
+    /// e.g. `Deployment complete (took 2.3s, 12 steps, depth 3)`.
+    fn finish_task(&self, s: String) -> String {
+        self.steps.lock().unwrap().clear();
+
+        let Some(task) = self.complete_task() else {
+            return s;
+        };
+
+        let mut details = Vec::new();
+
+        let elapsed = self.since(task.start);
+        if self.outro_timing.load(Ordering::Relaxed) && elapsed.as_millis() > 0 {
+            details.push(format!("took {}", format_duration(elapsed)));
+        }
+
+        if self.verbosity() == Verbosity::Trace {
+            let plural = if task.step_count == 1 { "" } else { "s" };
+            details.push(format!("{} step{plural}", task.step_count));
+            details.push(format!("depth {}", task.max_depth));
+        }
+
+        if details.is_empty() {
+            s
+        } else {
+            format!("{s} ({})", details.join(", "))
+        }
+    }
+
+    /// Build a printer whose format/verbosity come from the environment.
+    ///
+    /// `LOG_RS_FORMAT` and `LOG_RS_VERBOSITY` override the defaults; explicit
+    /// `Some(..)` arguments override both. Unparseable values are an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `LOG_RS_FORMAT` or `LOG_RS_VERBOSITY` is set to an
+    /// unknown value.
+    pub fn from_env(
+        inner: L,
+        backend: B,
+        format: Option<LogFormat>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<Self> {
+        let format = match format {
+            Some(f) => f,
+            None => std::env::var("LOG_RS_FORMAT")
+                .ok()
+                .map_or(Ok(LogFormat::default()), |v| v.parse())?,
+        };
+
+        let verbosity = match verbosity {
+            Some(v) => v,
+            None => std::env::var("LOG_RS_VERBOSITY")
+                .ok()
+                .map_or(Ok(Verbosity::default()), |v| v.parse())?,
         };
 
-        // Test-only override for deterministic snapshots
-        #[cfg(test)]
+        Ok(Self::new(inner, backend, format, verbosity))
+    }
+
+    /// Whether a message at `level` would currently be shown, going by this
+    /// printer's own verbosity (see `set_verbosity` and `with_verbosity`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `verbosity_override` lock is poisoned.
+    pub fn enabled(&self, level: LogLevel) -> bool {
+        let verbosity = self.verbosity();
+        match level {
+            LogLevel::Error => true,
+            LogLevel::Info | LogLevel::Warn | LogLevel::Progress => verbosity != Verbosity::Quiet,
+            LogLevel::Debug | LogLevel::Trace => {
+                matches!(verbosity, Verbosity::Verbose | Verbosity::Trace)
+            }
+        }
+    }
+
+    /// Choose how text-mode progress is drawn: in place, one line per
+    /// update, or only the final state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `progress_render` lock is poisoned.
+    pub fn set_progress_render(&self, render: ProgressRender) {
+        *self.progress_render.lock().unwrap() = render;
+    }
+
+    /// Draw text-mode progress with a known total as a `[████░░░░] 50%`
+    /// gauge on terminals (default off). Elsewhere the counts are shown.
+    pub fn set_progress_bar(&self, bar: bool) {
+        self.progress_bar.store(bar, Ordering::Relaxed);
+    }
+
+    /// Gauge cells for `update` on `stream`, sized to the room the terminal
+    /// leaves.
+    fn progress_bar_width(&self, update: &ProgressUpdate<'_>, stream: Stream) -> Option<usize> {
+        if !self.progress_bar.load(Ordering::Relaxed)
+            || update.total.is_none()
+            || !self.stream_is_tty(stream)
         {
-            *printer.timestamp.lock().unwrap() = TimestampMode::Disabled;
+            return None;
+        }
+
+        // Indent, label, and the ` [` `] 100%` around the cells.
+        let used = 2 * update.depth + display_width(update.label) + 8;
+        Some(
+            self.terminal_width()
+                .saturating_sub(used)
+                .clamp(MIN_BAR_WIDTH, MAX_BAR_WIDTH),
+        )
+    }
+
+    /// Insert thousands separators into progress counts and numeric field
+    /// values in text mode. JSON and CSV values are left untouched.
+    pub fn set_group_digits(&self, group: bool) {
+        self.group_digits.store(group, Ordering::Relaxed);
+    }
+
+    /// Append `(took …)` to text outros and `done` (default on).
+    ///
+    /// Turn off for output that gets diffed; JSON outros keep their
+    /// `duration_ms` field either way.
+    pub fn set_outro_timing(&self, show: bool) {
+        self.outro_timing.store(show, Ordering::Relaxed);
+    }
+
+    /// Read time from `clock` instead of the system clock.
+    ///
+    /// Task durations and step deltas then follow it exactly, so tests can
+    /// advance a `MockClock` instead of sleeping.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `clock` lock is poisoned.
+    pub fn set_clock(&self, clock: Arc<dyn Clock>) {
+        *self.clock.lock().unwrap() = Some(clock);
+    }
+
+    /// The current time by this printer's clock.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `clock` lock is poisoned.
+    pub fn now(&self) -> Instant {
+        let clock = self.clock.lock().unwrap().clone();
+        clock.map_or_else(Instant::now, |c| c.now())
+    }
+
+    /// Time elapsed since `start` by this printer's clock.
+    pub(crate) fn since(&self, start: Instant) -> Duration {
+        self.now().saturating_duration_since(start)
+    }
+
+    /// Cap how many tasks may be open at once (default 1024).
+    ///
+    /// Intros past the cap still render but push no span, and a one-time
+    /// warning points at the likely leak: intros without matching outros.
+    pub fn set_max_task_depth(&self, max: usize) {
+        self.max_task_depth.store(max, Ordering::Relaxed);
+    }
+
+    /// Number repeated runs of the same task instead of showing each as new
+    /// (default off).
+    ///
+    /// For watch-mode tools: an intro whose label matches the task that
+    /// completed just before it renders as `Deploying (run 3)`. JSON intros
+    /// keep the bare label and add a `run` field instead.
+    pub fn set_collapse_reruns(&self, collapse: bool) {
+        self.collapse_reruns.store(collapse, Ordering::Relaxed);
+    }
+
+    /// Which consecutive run of `label` an intro starts (1 unless collapsing).
+    fn next_run(&self, label: &str) -> usize {
+        if !self.collapse_reruns.load(Ordering::Relaxed) {
+            return 1;
+        }
+
+        match &*self.last_run.lock().unwrap() {
+            Some((last, run)) if last == label => run + 1,
+            _ => 1,
         }
+    }
+
+    /// Choose what quiet mode shows besides errors; see `QuietProfile`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `quiet_profile` lock is poisoned.
+    pub fn set_quiet_profile(&self, profile: QuietProfile) {
+        *self.quiet_profile.lock().unwrap() = profile;
+    }
+
+    /// The quiet profile in effect, or `None` outside quiet mode.
+    fn active_quiet_profile(&self) -> Option<QuietProfile> {
+        self.inner
+            .is_quiet()
+            .then(|| *self.quiet_profile.lock().unwrap())
+    }
+
+    fn quiet_hides_outros(&self) -> bool {
+        self.active_quiet_profile()
+            .is_some_and(|p| p != QuietProfile::Outros)
+    }
+
+    pub(crate) fn quiet_hides_summary(&self) -> bool {
+        self.active_quiet_profile() == Some(QuietProfile::ErrorsOnly)
+    }
+
+    /// Hide `dim` notes below `min` verbosity (default `Normal`).
+    ///
+    /// Quiet mode suppresses them regardless.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `dim_min_verbosity` lock is poisoned.
+    pub fn set_dim_min_verbosity(&self, min: Verbosity) {
+        *self.dim_min_verbosity.lock().unwrap() = min;
+    }
+
+    /// Force ASCII or Unicode glyphs regardless of the color setting.
+    ///
+    /// Process-wide, since formatters are stateless.
+    pub fn set_glyph_mode(&self, mode: GlyphMode) {
+        crate::logging::set_glyph_mode(mode);
+    }
 
-        printer
+    /// Route this printer's output between stdout and stderr.
+    ///
+    /// Until called, the process-wide default from the free
+    /// `set_stream_policy` applies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `stream_policy` lock is poisoned.
+    pub fn set_stream_policy(&self, policy: StreamPolicy) {
+        *self.stream_policy.lock().unwrap() = Some(policy);
+    }
+
+    /// Run `f` with this printer's stream policy and verbosity in effect on
+    /// this thread, so backends and formatters follow them without a
+    /// printer reference.
+    fn routed<T>(&self, f: impl FnOnce() -> T) -> T {
+        let policy = *self.stream_policy.lock().unwrap();
+        let verbosity = *self.verbosity_override.lock().unwrap();
+        with_stream_policy(policy, || with_verbosity_override(verbosity, f))
+    }
+
+    /// Build a printer from embedded `LoggerSettings`.
+    ///
+    /// `settings.theme` is left to the caller, who picks `inner`/`backend` to match.
+    pub fn from_settings(inner: L, backend: B, settings: &LoggerSettings) -> Self {
+        crate::config::setnocolor(settings.nocolor);
+        Self::new(inner, backend, settings.format, settings.verbosity)
     }
 }
 
-impl<L: FormatLogger, B: RenderBackend> ScreenLogger for Printer<L, B> {
-    fn intro(&self, m: &str) {
-        if let Some(s) = self.inner.intro(m) {
-            match self.format {
-                LogFormat::Json => {
-                    self.emit_json(LogLevel::Info, &s);
+// -----------------------------------------------------------------------------
+// Printer: fallible emission, surfacing backend errors to the caller
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Like `intro`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    pub fn try_intro(&self, m: &str) -> anyhow::Result<()> {
+        self.try_intro_with_fields(m, Fields::new())
+    }
+
+    /// Open a task whose `fields` are attached to every event until its outro.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `tasks` lock is poisoned.
+    pub fn try_intro_with_fields(&self, m: &str, fields: Fields) -> anyhow::Result<()> {
+        self.routed(|| {
+            let sp = task_span(m);
+            let task_id = self.next_task_id.fetch_add(1, Ordering::Relaxed);
+            let mut run = 1;
+            let mut tasks = self.tasks.lock().unwrap();
+            if tasks.len() >= self.max_task_depth.load(Ordering::Relaxed) {
+                drop(tasks);
+                self.warn_task_depth();
+            } else {
+                run = self.next_run(m);
+                let now = self.now();
+                tasks.push(TimedSpan {
+                    span: sp,
+                    start: now,
+                    label: m.to_string(),
+                    step_count: 0,
+                    max_depth: 0,
+                    last_step: now,
+                    glyph_frame: 0,
+                    run,
+                    task_id,
+                    fields: fields.clone(),
+                });
+
+                let depth = tasks.len();
+                for (i, task) in tasks.iter_mut().enumerate() {
+                    task.max_depth = task.max_depth.max(depth - i);
                 }
-                LogFormat::Text => {
-                    let _ = self.backend.render_intro(&s);
-                    if self.inner.is_verbose() {
-                        info!("{s}");
+                drop(tasks);
+                self.journal_open(task_id, m, depth - 1);
+            }
+
+            // JSON keeps the bare label and carries the count as a field.
+            let label = if run > 1 && self.format != LogFormat::Json {
+                Cow::Owned(format!("{m} (run {run})"))
+            } else {
+                Cow::Borrowed(m)
+            };
+
+            if let Some(s) = self.styled(|f| f.intro(&self.sanitized(&label))) {
+                match self.format {
+                    LogFormat::Json => {
+                        let mut fields = fields;
+                        fields.extend(task_id_fields(task_id));
+                        if run > 1 {
+                            fields.insert_json("run".into(), &run.into());
+                        }
+                        self.emit_json_fields(LogLevel::Info, &s, Some(&fields));
+                    }
+                    LogFormat::Csv => {
+                        self.emit_csv(LogLevel::Info, &s);
+                    }
+                    LogFormat::Text => {
+                        self.backend.render_intro(&self.decorate(&s))?;
+                        if self.inner.is_verbose() {
+                            info!("{s}");
+                        }
                     }
                 }
             }
-        }
 
-        let sp = span!(Level::INFO, "task", message = %m);
-        self.tasks.lock().unwrap().push(TimedSpan {
-            span: sp,
-            start: Instant::now(),
-            label: m.to_string(),
-        });
+            Ok(())
+        })
     }
 
-    fn outro(&self, m: &str) {
-        if let Some(s) = self.inner.outro(m) {
-            match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Info, &s),
-                LogFormat::Text => {
-                    self.steps.lock().unwrap().clear();
-
-                    let task = self.tasks.lock().unwrap().pop();
-
-                    let msg = {
-                        #[cfg(not(test))]
-                        {
-                            if let Some(TimedSpan { span, start, .. }) = task {
-                                drop(span);
-
-                                let elapsed = start.elapsed();
-                                let timing = format_duration(elapsed);
-
-                                if elapsed.as_millis() > 0 {
-                                    format!("{s} (took {timing})")
-                                } else {
-                                    s
-                                }
-                            } else {
-                                s
-                            }
-                        }
+    /// Like `outro`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    pub fn try_outro(&self, m: &str) -> anyhow::Result<()> {
+        self.routed(|| {
+            if self.quiet_hides_outros() {
+                self.complete_task();
+                return Ok(());
+            }
+
+            if let Some(s) = self.styled(|f| f.outro(&self.sanitized(m))) {
+                match self.format {
+                    LogFormat::Json => {
+                        let fields = self.complete_task().map(|t| self.outro_fields(&t));
+                        self.emit_json_fields(LogLevel::Info, &s, fields.as_ref());
+                    }
+                    LogFormat::Csv => {
+                        self.complete_task();
+                        self.emit_csv(LogLevel::Info, &s);
+                    }
+                    LogFormat::Text => {
+                        let msg = self.finish_task(s);
+                        self.backend.render_outro(&self.decorate(&msg))?;
 
-                        #[cfg(test)]
-                        if let Some(TimedSpan { .. }) = task {
-                            format!("{s} (took 10ms)")
-                        } else {
-                            s
+                        if self.inner.is_verbose() {
+                            info!("{msg}");
                         }
-                    };
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Like `done`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    pub fn try_done(&self) -> anyhow::Result<()> {
+        self.routed(|| {
+            if self.quiet_hides_outros() {
+                self.complete_task();
+                return Ok(());
+            }
 
-                    let _ = self.backend.render_outro(&msg);
+            if let Some(s) = self.styled(L::done) {
+                match self.format {
+                    LogFormat::Json => {
+                        let fields = self.complete_task().map(|t| self.outro_fields(&t));
+                        self.emit_json_fields(LogLevel::Info, &s, fields.as_ref());
+                    }
+                    LogFormat::Csv => {
+                        self.complete_task();
+                        self.emit_csv(LogLevel::Info, &s);
+                    }
+                    LogFormat::Text => {
+                        let msg = self.finish_task(s);
+                        self.backend.render_outro(&self.decorate(&msg))?;
 
-                    if self.inner.is_verbose() {
-                        info!("{msg}");
+                        if self.inner.is_verbose() {
+                            info!("{msg}");
+                        }
                     }
                 }
             }
-        }
+
+            Ok(())
+        })
     }
 
-    fn done(&self) {
-        if let Some(s) = self.inner.done() {
-            match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Info, &s),
-                LogFormat::Text => {
-                    self.steps.lock().unwrap().clear();
-
-                    let task = self.tasks.lock().unwrap().pop();
-                    let msg = {
-                        #[cfg(not(test))]
-                        {
-                            if let Some(TimedSpan { span, start, .. }) = task {
-                                drop(span);
-
-                                let elapsed = start.elapsed();
-                                let timing = format_duration(elapsed);
-
-                                if elapsed.as_millis() > 0 {
-                                    format!("{s} (took {timing})")
-                                } else {
-                                    s
-                                }
-                            } else {
-                                s
-                            }
-                        }
+    /// Like `step`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `tasks` lock is poisoned.
+    pub fn try_step(&self, m: &str) -> anyhow::Result<()> {
+        self.routed(|| {
+            let (since_last, frame) = {
+                let mut tasks = self.tasks.lock().unwrap();
+                for task in tasks.iter_mut() {
+                    task.step_count += 1;
+                }
 
-                        #[cfg(test)]
-                        if let Some(TimedSpan { .. }) = task {
-                            format!("{s} (took 10ms)")
-                        } else {
-                            s
-                        }
-                    };
+                tasks
+                    .last_mut()
+                    .map(|task| {
+                        let now = self.now();
+                        let delta = now.duration_since(task.last_step);
+                        task.last_step = now;
+                        task.glyph_frame += 1;
+                        (delta, task.glyph_frame - 1)
+                    })
+                    .unzip()
+            };
 
-                    let _ = self.backend.render_outro(&msg);
+            self.journal_step(m);
 
-                    if self.inner.is_verbose() {
-                        info!("{msg}");
+            let spinner = frame
+                .filter(|_| self.rotates_step_glyph())
+                .map(|i| STEP_SPINNER[i % STEP_SPINNER.len()]);
+            let step = self.styled(|f| with_step_glyph(spinner, || f.step(&self.sanitized(m))));
+            if let Some(mut s) = step {
+                if self.verbosity() == Verbosity::Trace
+                    && self.format == LogFormat::Text
+                    && let Some(delta) = since_last
+                {
+                    s = format!("{s} (+{})", format_duration(delta));
+                }
+
+                match self.format {
+                    LogFormat::Json => {
+                        self.emit_json(LogLevel::Info, &s);
+                    }
+                    LogFormat::Csv => {
+                        self.emit_csv(LogLevel::Info, &s);
+                    }
+                    LogFormat::Text => {
+                        self.backend.render_step(&self.decorate(&self.indent(&s)))?;
+
+                        if self.inner.is_verbose() {
+                            let sp = step_span(m);
+                            self.steps.lock().unwrap().push(sp);
+                            info!("{s}");
+                        }
                     }
                 }
             }
-        }
+
+            Ok(())
+        })
     }
 
-    fn step(&self, m: &str) {
-        if let Some(s) = self.inner.step(m) {
-            match self.format {
-                LogFormat::Json => {
-                    self.emit_json(LogLevel::Info, &s);
+    /// Like `ok`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    pub fn try_ok(&self, m: &str) -> anyhow::Result<()> {
+        self.routed(|| {
+            self.counts.ok.fetch_add(1, Ordering::Relaxed);
+            if let Some(s) = self.styled(|f| f.ok(&self.sanitized(m))) {
+                match self.format {
+                    LogFormat::Json => self.emit_json(LogLevel::Info, &s),
+                    LogFormat::Csv => self.emit_csv(LogLevel::Info, &s),
+                    LogFormat::Text => self
+                        .backend
+                        .render_success(&self.decorate(&self.indent(&s)))?,
                 }
-                LogFormat::Text => {
-                    let _ = self.backend.render_step(&s);
+            }
+
+            Ok(())
+        })
+    }
 
-                    if self.inner.is_verbose() {
-                        let sp = span!(Level::INFO, "step", message = %m);
-                        self.steps.lock().unwrap().push(sp);
-                        info!("{s}");
+    /// Like `warn`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    pub fn try_warn(&self, m: &str) -> anyhow::Result<()> {
+        self.routed(|| {
+            self.count_event(LogLevel::Warn);
+            if let Some(s) = self.styled(|f| f.warn(&self.sanitized(m))) {
+                match self.format {
+                    LogFormat::Json => self.emit_json(LogLevel::Warn, &s),
+                    LogFormat::Csv => self.emit_csv(LogLevel::Warn, &s),
+                    LogFormat::Text => {
+                        self.backend.render_warning(&self.decorate(&s))?;
+                        warn!("{s}");
                     }
                 }
             }
-        }
+
+            Ok(())
+        })
     }
 
-    fn ok(&self, m: &str) {
-        if let Some(s) = self.inner.ok(m) {
+    /// Like `err`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    pub fn try_err(&self, m: &str) -> anyhow::Result<()> {
+        self.routed(|| {
+            self.count_event(LogLevel::Error);
+            self.journal_error(m);
+            let s = self.styled(|f| f.err(&self.linked(&self.sanitized(m))));
+
             match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Info, &s),
+                LogFormat::Json => self.emit_json(LogLevel::Error, &s),
+                LogFormat::Csv => self.emit_csv(LogLevel::Error, &s),
                 LogFormat::Text => {
-                    let _ = self.backend.render_success(&s);
+                    self.backend.render_error(&self.decorate(&s))?;
+                    self.flush_after(LogLevel::Error);
+                    error!("{s}");
                 }
             }
-        }
+
+            Ok(())
+        })
     }
 
-    fn warn(&self, m: &str) {
-        if let Some(s) = self.inner.warn(m) {
-            match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Warn, &s),
-                LogFormat::Text => {
-                    let _ = self.backend.render_warning(&s);
-                    warn!("{s}");
+    /// Like `info`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    pub fn try_info(&self, m: &str) -> anyhow::Result<()> {
+        self.routed(|| {
+            self.count_event(LogLevel::Info);
+            if let Some(s) = self.styled(|f| f.info(&self.sanitized(m))) {
+                match self.format {
+                    LogFormat::Json => self.emit_json(LogLevel::Info, &s),
+                    LogFormat::Csv => self.emit_csv(LogLevel::Info, &s),
+                    LogFormat::Text => {
+                        self.backend.render_info(&self.decorate(&self.indent(&s)))?;
+                    }
                 }
             }
-        }
+
+            Ok(())
+        })
     }
 
-    fn err(&self, m: &str) {
-        let s = self.inner.err(m);
+    /// Like `dim`, but returns the backend's error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend fails to render it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `dim_min_verbosity` lock is poisoned.
+    pub fn try_dim(&self, m: &str) -> anyhow::Result<()> {
+        self.routed(|| {
+            let min = *self.dim_min_verbosity.lock().unwrap();
+            if self.verbosity() < min {
+                return Ok(());
+            }
 
-        match self.format {
-            LogFormat::Json => self.emit_json(LogLevel::Error, &s),
-            LogFormat::Text => {
-                let _ = self.backend.render_error(&s);
-                error!("{s}");
+            if let Some(s) = self.styled(|f| f.dim(&self.sanitized(m))) {
+                match self.format {
+                    LogFormat::Json => self.emit_json(LogLevel::Debug, &s),
+                    LogFormat::Csv => self.emit_csv(LogLevel::Debug, &s),
+                    LogFormat::Text => self.backend.render_remark(&self.decorate(&s))?,
+                }
             }
+
+            Ok(())
+        })
+    }
+
+    /// Warn once that intros are being dropped from the task stack.
+    fn warn_task_depth(&self) {
+        if !self.task_depth_warned.swap(true, Ordering::Relaxed) {
+            write_line_on(
+                Stream::Stderr,
+                &format!(
+                    "warning: task stack reached {} open tasks; further intros are not tracked (missing outro?)",
+                    self.max_task_depth.load(Ordering::Relaxed)
+                ),
+            );
+        }
+    }
+
+    /// Report the first backend failure on stderr; later ones stay silent.
+    pub(crate) fn report_backend_error(&self, result: anyhow::Result<()>) {
+        if let Err(e) = result
+            && !self.backend_failed.swap(true, Ordering::Relaxed)
+        {
+            write_line_on(
+                Stream::Stderr,
+                &format!("warning: log backend failed to render output: {e:#}"),
+            );
         }
     }
+}
+
+impl<L: FormatLogger, B: RenderBackend> ScreenLogger for Printer<L, B> {
+    fn intro(&self, m: &str) {
+        self.report_backend_error(self.try_intro(m));
+    }
+
+    fn outro(&self, m: &str) {
+        self.report_backend_error(self.try_outro(m));
+    }
+
+    fn done(&self) {
+        self.report_backend_error(self.try_done());
+    }
+
+    fn step(&self, m: &str) {
+        self.report_backend_error(self.try_step(m));
+    }
+
+    fn ok(&self, m: &str) {
+        self.report_backend_error(self.try_ok(m));
+    }
+
+    fn warn(&self, m: &str) {
+        self.report_backend_error(self.try_warn(m));
+    }
+
+    fn err(&self, m: &str) {
+        self.report_backend_error(self.try_err(m));
+    }
 
     fn info(&self, m: &str) {
-        if let Some(s) = self.inner.info(m) {
-            match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Info, &s),
-                LogFormat::Text => {
-                    let _ = self.backend.render_info(&s);
+        self.report_backend_error(self.try_info(m));
+    }
+
+    fn dim(&self, m: &str) {
+        self.report_backend_error(self.try_dim(m));
+    }
+
+    fn debug(&self, m: &str) {
+        self.routed(|| {
+            if let Some(s) = self.styled(|f| f.debug(&self.sanitized(m))) {
+                match self.format {
+                    LogFormat::Json => self.emit_json(LogLevel::Debug, &s),
+                    LogFormat::Csv => self.emit_csv(LogLevel::Debug, &s),
+                    LogFormat::Text => {
+                        debug!("{s}");
+                    }
                 }
             }
-        }
+        });
     }
 
-    fn dim(&self, m: &str) {
-        if let Some(s) = self.inner.dim(m) {
+    fn trace(&self, m: &str) {
+        self.routed(|| {
+            if let Some(s) = self.styled(|f| f.trace(&self.sanitized(m))) {
+                match self.format {
+                    LogFormat::Json => self.emit_json(LogLevel::Trace, &s),
+                    LogFormat::Csv => self.emit_csv(LogLevel::Trace, &s),
+                    LogFormat::Text => {
+                        trace!("{s}");
+                    }
+                }
+            }
+        });
+    }
+
+    fn dump_tree(&self) {
+        self.dump_task_tree();
+    }
+
+    fn progress(&self, update: &ProgressUpdate<'_>) {
+        self.routed(|| match self.format {
+            LogFormat::Json => {
+                self.emit_progress_json(update);
+                if self.progress_dual.load(Ordering::Relaxed) {
+                    self.render_progress_live(update);
+                }
+            }
+            LogFormat::Csv => self.emit_csv(LogLevel::Progress, update.label),
+            LogFormat::Text => self.render_progress_text(update),
+        });
+    }
+
+    fn table(&self, headers: &[&str], rows: &[Vec<String>]) {
+        self.routed(|| {
+            if !Self::enabled(self, LogLevel::Info) {
+                return;
+            }
+
+            // Cells keyed by header; missing cells are empty, extra cells dropped.
+            let keyed = |row: &Vec<String>| -> Fields {
+                headers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, h)| ((*h).to_string(), row.get(i).cloned().unwrap_or_default()))
+                    .collect()
+            };
+
             match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Debug, &s),
+                LogFormat::Json => {
+                    let objects = rows
+                        .iter()
+                        .map(|row| {
+                            keyed(row)
+                                .iter()
+                                .map(|(k, v)| (k.clone(), serde_json::Value::from(v.as_str())))
+                                .collect::<serde_json::Map<_, _>>()
+                                .into()
+                        })
+                        .collect();
+                    let mut fields = Fields::new();
+                    fields.insert_json("rows".into(), &serde_json::Value::Array(objects));
+                    self.emit_json_fields(LogLevel::Info, "table", Some(&fields));
+                }
+                LogFormat::Csv => {
+                    for row in rows {
+                        self.emit_csv_fields(LogLevel::Info, "", Some(&keyed(row)));
+                    }
+                }
                 LogFormat::Text => {
-                    let _ = self.backend.render_remark(&s);
+                    self.report_backend_error(self.backend.render_table(headers, rows));
                 }
             }
-        }
+        });
     }
 
-    fn debug(&self, m: &str) {
-        if let Some(s) = self.inner.debug(m) {
+    fn rule(&self, label: Option<&str>) {
+        self.routed(|| {
+            if !Self::enabled(self, LogLevel::Info) {
+                return;
+            }
+
             match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Debug, &s),
+                LogFormat::Json => {
+                    let label = label.unwrap_or_default();
+                    let fields = Fields::from([("rule".to_string(), label.to_string())]);
+                    self.emit_json_fields(LogLevel::Info, label, Some(&fields));
+                }
+                // Dividers would break the single CSV document.
+                LogFormat::Csv => {}
                 LogFormat::Text => {
-                    debug!("{s}");
+                    self.report_backend_error(self.backend.render_rule(label, self.rule_width()));
                 }
             }
-        }
+        });
     }
 
-    fn trace(&self, m: &str) {
-        if let Some(s) = self.inner.trace(m) {
+    fn heading(&self, text: &str) {
+        self.routed(|| {
+            if !Self::enabled(self, LogLevel::Info) {
+                return;
+            }
+
             match self.format {
-                LogFormat::Json => self.emit_json(LogLevel::Trace, &s),
+                LogFormat::Json => {
+                    let fields = Fields::from([("heading".to_string(), text.to_string())]);
+                    self.emit_json_fields(LogLevel::Info, text, Some(&fields));
+                }
+                LogFormat::Csv => self.emit_csv(LogLevel::Info, text),
                 LogFormat::Text => {
-                    trace!("{s}");
+                    let text = self.sanitized(text);
+                    let rendered = self.styled(|_| self.backend.render_heading(&text));
+                    self.report_backend_error(rendered);
                 }
             }
-        }
+        });
     }
 
-    fn dump_tree(&self) {
-        self.dump_task_tree();
+    fn confirm(&self, question: &str) -> anyhow::Result<bool> {
+        self.prompt_confirm(question)
     }
 
-    fn progress(&self, label: &str, current: u64, total: Option<u64>, finished: bool) {
-        match self.format {
-            LogFormat::Json => {
-                // Emit a structured progress event
-                /*
-                let mut fields = Fields::new();
-                fields.insert("label".into(), label.to_string());
-                fields.insert("current".into(), current.to_string());
-                if let Some(t) = total {
-                    fields.insert("total".into(), t.to_string());
-                }
-                fields.insert("finished".into(), finished.to_string());
-                */
+    fn input(&self, prompt: &str) -> anyhow::Result<String> {
+        self.prompt_input(prompt)
+    }
 
-                // Use the Progress level you already added
-                self.emit_json(LogLevel::Progress, label);
-            }
-            LogFormat::Text => {
-                let _ = self
-                    .backend
-                    .render_progress(label, current, total, finished);
-            }
-        }
+    fn enabled(&self, level: LogLevel) -> bool {
+        Self::enabled(self, level)
+    }
+
+    fn task_depth(&self) -> usize {
+        self.tasks.lock().unwrap().len()
+    }
+
+    fn now(&self) -> Instant {
+        Self::now(self)
+    }
+
+    fn note(&self, text: &str) {
+        self.notes.lock().unwrap().push(text.to_string());
+    }
+
+    fn had_errors(&self) -> bool {
+        Self::had_errors(self)
     }
 }
 
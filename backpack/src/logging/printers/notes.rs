@@ -0,0 +1,60 @@
+use crate::LogFormat;
+use crate::logging::{Fields, FormatLogger, LogLevel, Printer, RenderBackend, write_line};
+use std::sync::atomic::Ordering;
+
+// -----------------------------------------------------------------------------
+// Printer: end-of-run notes
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Every note added with `ScreenLogger::note`, oldest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `notes` lock is poisoned.
+    #[must_use]
+    pub fn notes(&self) -> Vec<String> {
+        self.notes.lock().unwrap().clone()
+    }
+
+    /// Print the notes added since the last flush as a `Notes:` section.
+    ///
+    /// JSON mode emits one `notes` event carrying the array as its `notes`
+    /// field, and CSV mode one `info` row per note. Does nothing when there
+    /// are no new notes; `summary` calls it after the task table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `notes` lock is poisoned.
+    pub fn flush_notes(&self) {
+        self.routed(|| {
+            let pending = {
+                let notes = self.notes.lock().unwrap();
+                notes[self.notes_printed.swap(notes.len(), Ordering::Relaxed)..].to_vec()
+            };
+            if pending.is_empty() {
+                return;
+            }
+
+            match self.format {
+                LogFormat::Json => {
+                    let mut fields = Fields::new();
+                    fields.insert_json("notes".into(), &serde_json::json!(pending));
+                    self.emit_json_fields(LogLevel::Info, "notes", Some(&fields));
+                    return;
+                }
+                LogFormat::Csv => {
+                    for note in &pending {
+                        self.emit_csv(LogLevel::Info, note);
+                    }
+                    return;
+                }
+                LogFormat::Text => {}
+            }
+
+            write_line(LogLevel::Info, "Notes:");
+            for note in &pending {
+                write_line(LogLevel::Info, &format!("  - {}", self.sanitized(note)));
+            }
+        });
+    }
+}
@@ -0,0 +1,50 @@
+use crate::logging::{FormatLogger, Printer, RenderBackend, ScreenLogger};
+use std::panic::Location;
+
+// -----------------------------------------------------------------------------
+// Printer: messages shown at most once per run
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Warn with `msg` the first time `key` is seen; later calls are dropped.
+    ///
+    /// Meant for notices such as deprecations that should not repeat for
+    /// the rest of the run. Keys are shared with `error_once`.
+    pub fn warn_once(&self, key: &str, msg: &str) {
+        if self.first_sighting(key) {
+            ScreenLogger::warn(self, msg);
+        }
+    }
+
+    /// Like `warn_once`, but logs `msg` as an error.
+    pub fn error_once(&self, key: &str, msg: &str) {
+        if self.first_sighting(key) {
+            ScreenLogger::err(self, msg);
+        }
+    }
+
+    /// `warn_once` keyed by the calling line instead of an explicit key.
+    #[track_caller]
+    pub fn warn_once_here(&self, msg: &str) {
+        self.warn_once(&call_site(Location::caller()), msg);
+    }
+
+    /// `error_once` keyed by the calling line instead of an explicit key.
+    #[track_caller]
+    pub fn error_once_here(&self, msg: &str) {
+        self.error_once(&call_site(Location::caller()), msg);
+    }
+
+    /// Record `key`, returning whether it was new.
+    fn first_sighting(&self, key: &str) -> bool {
+        self.seen_once.lock().unwrap().insert(key.to_string())
+    }
+}
+
+fn call_site(location: &Location<'_>) -> String {
+    format!(
+        "{}:{}:{}",
+        location.file(),
+        location.line(),
+        location.column()
+    )
+}
@@ -0,0 +1,44 @@
+use crate::LogFormat;
+use crate::logging::{FormatLogger, Printer, RenderBackend};
+use std::{io::IsTerminal, sync::atomic::Ordering};
+
+// -----------------------------------------------------------------------------
+// Printer: interactive prompts with non-blocking fallbacks
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Answer returned by `confirm` when prompting isn't possible.
+    pub fn set_confirm_default(&self, default: bool) {
+        self.confirm_default.store(default, Ordering::Relaxed);
+    }
+
+    /// Answer returned by `input` when prompting isn't possible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `input_default` lock is poisoned.
+    pub fn set_input_default(&self, default: &str) {
+        *self.input_default.lock().unwrap() = default.to_string();
+    }
+
+    /// Prompts only block in text mode, outside quiet mode, on a terminal.
+    pub(crate) fn can_prompt(&self) -> bool {
+        self.format == LogFormat::Text && !self.inner.is_quiet() && std::io::stdin().is_terminal()
+    }
+
+    pub(crate) fn prompt_confirm(&self, question: &str) -> anyhow::Result<bool> {
+        let default = self.confirm_default.load(Ordering::Relaxed);
+        if !self.can_prompt() {
+            return Ok(default);
+        }
+
+        self.backend.confirm(question, default)
+    }
+
+    pub(crate) fn prompt_input(&self, prompt: &str) -> anyhow::Result<String> {
+        if !self.can_prompt() {
+            return Ok(self.input_default.lock().unwrap().clone());
+        }
+
+        self.backend.input(prompt)
+    }
+}
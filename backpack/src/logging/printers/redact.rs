@@ -0,0 +1,54 @@
+use crate::logging::{Fields, FormatLogger, Printer, RenderBackend};
+use std::borrow::Cow;
+
+/// Replacement for the values of redacted fields.
+pub const REDACTED: &str = "***";
+
+// -----------------------------------------------------------------------------
+// Printer: masking of sensitive field values
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Mask the value of any field whose key matches `key` (case-insensitive).
+    ///
+    /// A leading `*` matches by suffix (`*_token`), a trailing `*` by prefix
+    /// (`secret_*`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `redacted_keys` lock is poisoned.
+    pub fn add_redacted_key(&self, key: &str) {
+        let key = key.to_ascii_lowercase();
+        let mut keys = self.redacted_keys.lock().unwrap();
+
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    /// Return `fields` with redacted values masked, borrowing when nothing matches.
+    pub(crate) fn redact_fields<'a>(&self, fields: &'a Fields) -> Cow<'a, Fields> {
+        let keys = self.redacted_keys.lock().unwrap();
+
+        if !fields.keys().any(|k| is_redacted(&keys, k)) {
+            return Cow::Borrowed(fields);
+        }
+
+        let mut redacted = fields.clone();
+        for k in fields.keys().filter(|k| is_redacted(&keys, k)) {
+            redacted.insert(k.clone(), REDACTED.to_string());
+        }
+        Cow::Owned(redacted)
+    }
+}
+
+fn is_redacted(patterns: &[String], key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+
+    patterns
+        .iter()
+        .any(|p| match (p.strip_prefix('*'), p.strip_suffix('*')) {
+            (Some(suffix), _) => key.ends_with(suffix),
+            (None, Some(prefix)) => key.starts_with(prefix),
+            (None, None) => key == *p,
+        })
+}
@@ -0,0 +1,129 @@
+use crate::logging::{FormatLogger, LogLevel, Printer, RenderBackend};
+use serde::Serialize;
+use std::{
+    io::Write,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Running totals of `ok`/`warn`/`err`/`info` calls on a printer.
+///
+/// Calls and structured events are counted even when verbosity hides the
+/// message.
+#[derive(Debug, Default)]
+pub struct EventCounters {
+    pub ok: AtomicUsize,
+    pub warn: AtomicUsize,
+    pub err: AtomicUsize,
+    pub info: AtomicUsize,
+}
+
+/// A snapshot of `EventCounters`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct EventCounts {
+    pub ok: usize,
+    pub warn: usize,
+    pub err: usize,
+    pub info: usize,
+}
+
+// -----------------------------------------------------------------------------
+// Printer: machine-readable end-of-run report
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    #[must_use]
+    pub fn counts(&self) -> EventCounts {
+        EventCounts {
+            ok: self.counts.ok.load(Ordering::Relaxed),
+            warn: self.counts.warn.load(Ordering::Relaxed),
+            err: self.counts.err.load(Ordering::Relaxed),
+            info: self.counts.info.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Count an event at `level` from any output path.
+    ///
+    /// `Info`, `Warn` and `Error` map onto `info`, `warn` and `err`; the
+    /// other levels are not counted.
+    pub(crate) fn count_event(&self, level: LogLevel) {
+        let counter = match level {
+            LogLevel::Info => &self.counts.info,
+            LogLevel::Warn => &self.counts.warn,
+            LogLevel::Error => &self.counts.err,
+            LogLevel::Debug | LogLevel::Trace | LogLevel::Progress => return,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        self.mark_error(level);
+    }
+
+    /// Count a structured event and journal it if it is an error.
+    pub(crate) fn record_event(&self, level: LogLevel, msg: &str) {
+        self.count_event(level);
+        if level == LogLevel::Error {
+            self.journal_error(msg);
+        }
+    }
+
+    /// Remember that an error-level event was written, for `had_errors`.
+    pub(crate) fn mark_error(&self, level: LogLevel) {
+        if level == LogLevel::Error {
+            self.errors_seen.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Whether an error has been logged since the printer was created.
+    ///
+    /// Covers `err` and error-level structured events, and is not reset by
+    /// `take_counts`.
+    #[must_use]
+    pub fn had_errors(&self) -> bool {
+        self.errors_seen.load(Ordering::Relaxed)
+    }
+
+    /// Like `counts`, but also resets every counter to zero (`had_errors`
+    /// keeps its answer).
+    ///
+    /// Lets a monitoring loop report per-interval totals. Each counter is
+    /// swapped atomically, so no call is lost or counted twice.
+    #[must_use]
+    pub fn take_counts(&self) -> EventCounts {
+        EventCounts {
+            ok: self.counts.ok.swap(0, Ordering::Relaxed),
+            warn: self.counts.warn.swap(0, Ordering::Relaxed),
+            err: self.counts.err.swap(0, Ordering::Relaxed),
+            info: self.counts.info.swap(0, Ordering::Relaxed),
+        }
+    }
+
+    /// Write one JSON object summarizing the run to `w`.
+    ///
+    /// Holds the `ok`/`warn`/`err` counts, the time since the printer was
+    /// created, the completed tasks (slowest first) and every note, whatever
+    /// the output format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    pub fn write_report(&self, w: &mut dyn Write) -> anyhow::Result<()> {
+        let tasks: Vec<_> = self
+            .completed_tasks()
+            .into_iter()
+            .map(|(task, d)| {
+                serde_json::json!({
+                    "task": task,
+                    "duration_ms": d.as_millis(),
+                })
+            })
+            .collect();
+
+        let report = serde_json::json!({
+            "counts": self.counts(),
+            "duration_ms": self.since(self.started).as_millis(),
+            "tasks": tasks,
+            "notes": self.notes(),
+        });
+
+        serde_json::to_writer(&mut *w, &report)?;
+        writeln!(w)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::logging::{FormatLogger, Printer, RenderBackend};
+use std::{
+    hash::{BuildHasher, Hasher, RandomState},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// -----------------------------------------------------------------------------
+// Printer: run-scoped correlation id
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Tag every event with a correlation id.
+    ///
+    /// `Some(id)` uses the given id; `None` generates a short random one.
+    /// JSON events get a `run_id` field and text lines a `[run_id]` prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `run_id` lock is poisoned.
+    pub fn set_run_id(&self, id: Option<String>) {
+        *self.run_id.lock().unwrap() = Some(id.unwrap_or_else(generate_run_id));
+    }
+
+    /// Stop tagging events with a run id.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `run_id` lock is poisoned.
+    pub fn clear_run_id(&self) {
+        *self.run_id.lock().unwrap() = None;
+    }
+
+    /// The active run id, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `run_id` lock is poisoned.
+    pub fn run_id(&self) -> Option<String> {
+        self.run_id.lock().unwrap().clone()
+    }
+
+    /// Give every JSON event a unique `id` (a random UUID v4), so pipelines
+    /// that retry delivery can drop duplicates. Off by default.
+    pub fn set_event_ids(&self, enabled: bool) {
+        self.event_ids.store(enabled, Ordering::Relaxed);
+    }
+}
+
+/// Nanoseconds since the epoch, or 0 if the clock is before it.
+fn now_nanos() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos())
+}
+
+/// An 8-hex-digit id, random per process and call.
+fn generate_run_id() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(now_nanos());
+    hasher.write_u32(std::process::id());
+
+    format!("{:08x}", hasher.finish() & 0xffff_ffff)
+}
+
+/// A random UUID v4 like `0b9c6f2e-4d1a-4c3b-9e8f-1a2b3c4d5e6f`.
+pub(crate) fn generate_event_id() -> String {
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+    let mut bits = 0u128;
+    for _ in 0..2 {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(SEQUENCE.fetch_add(1, Ordering::Relaxed));
+        hasher.write_u128(now_nanos());
+        bits = (bits << 64) | u128::from(hasher.finish());
+    }
+
+    // Version 4 in the 13th hex digit, RFC 4122 variant in the 17th.
+    bits = (bits & !(0xf << 76)) | (0x4 << 76);
+    bits = (bits & !(0x3 << 62)) | (0x2 << 62);
+
+    let hex = format!("{bits:032x}");
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
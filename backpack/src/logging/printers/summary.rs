@@ -0,0 +1,79 @@
+use crate::LogFormat;
+use crate::logging::{
+    Fields, FormatLogger, LogLevel, Printer, RenderBackend, display_width, format_duration,
+    write_line,
+};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+// Printer: summary of completed tasks
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Completed tasks with their durations, slowest first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `completed` lock is poisoned.
+    pub fn completed_tasks(&self) -> Vec<(String, Duration)> {
+        let mut completed = self.completed.lock().unwrap().clone();
+        completed.sort_by_key(|(_, d)| std::cmp::Reverse(*d));
+        completed
+    }
+
+    /// Print every completed task and how long it took, slowest first,
+    /// followed by any pending notes (see `flush_notes`).
+    ///
+    /// Text and CSV modes print an aligned table; JSON mode emits a single
+    /// `summary` event whose `tasks` field is an array of
+    /// `{"task", "duration_ms"}` objects.
+    pub fn summary(&self) {
+        self.routed(|| {
+            if self.quiet_hides_summary() {
+                return;
+            }
+
+            self.task_summary();
+            self.flush_notes();
+        });
+    }
+
+    fn task_summary(&self) {
+        let completed = self.completed_tasks();
+
+        if self.format == LogFormat::Json {
+            let rows: Vec<_> = completed
+                .iter()
+                .map(|(task, d)| {
+                    serde_json::json!({
+                        "task": task,
+                        "duration_ms": d.as_millis(),
+                    })
+                })
+                .collect();
+            let mut fields = Fields::new();
+            fields.insert_json("tasks".into(), &serde_json::Value::Array(rows));
+            self.emit_json_fields(LogLevel::Info, "summary", Some(&fields));
+            return;
+        }
+
+        if completed.is_empty() {
+            write_line(LogLevel::Info, "(no completed tasks)");
+            return;
+        }
+
+        let width = completed
+            .iter()
+            .map(|(task, _)| display_width(task))
+            .max()
+            .unwrap_or(0);
+
+        write_line(LogLevel::Info, "Task summary:");
+        for (task, d) in &completed {
+            let pad = " ".repeat(width - display_width(task));
+            write_line(
+                LogLevel::Info,
+                &format!("  {task}{pad}  {:>8}", format_duration(*d)),
+            );
+        }
+    }
+}
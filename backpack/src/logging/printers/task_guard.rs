@@ -0,0 +1,46 @@
+use crate::logging::{FormatLogger, Printer, RenderBackend, ScreenLogger};
+
+/// An open task that closes itself when dropped.
+///
+/// Created by `Printer::task`. Dropping the guard emits `outro(label)`, so
+/// intros and outros stay balanced across `?` returns and panics; call
+/// `done` to finish with the formatter's `done` line instead.
+#[must_use = "dropping the guard closes the task immediately"]
+pub struct TaskGuard<'a, L: FormatLogger, B: RenderBackend> {
+    printer: &'a Printer<L, B>,
+    label: String,
+    finished: bool,
+}
+
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Open a task that ends when the returned guard goes out of scope.
+    pub fn task(&self, label: &str) -> TaskGuard<'_, L, B> {
+        ScreenLogger::intro(self, label);
+        TaskGuard {
+            printer: self,
+            label: label.to_string(),
+            finished: false,
+        }
+    }
+}
+
+impl<L: FormatLogger, B: RenderBackend> TaskGuard<'_, L, B> {
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Finish the task with `done` rather than the labelled outro.
+    pub fn done(mut self) {
+        self.finished = true;
+        ScreenLogger::done(self.printer);
+    }
+}
+
+impl<L: FormatLogger, B: RenderBackend> Drop for TaskGuard<'_, L, B> {
+    fn drop(&mut self) {
+        if !self.finished {
+            ScreenLogger::outro(self.printer, &self.label);
+        }
+    }
+}
@@ -1,25 +1,110 @@
-use crate::logging::{FormatLogger, Printer, RenderBackend, format_duration};
+use crate::{
+    Verbosity,
+    logging::{
+        FormatLogger, LogLevel, Printer, RenderBackend, display_width, format_duration,
+        with_stream_policy, write_line,
+    },
+};
+use std::{sync::PoisonError, time::Instant};
 
 // -----------------------------------------------------------------------------
 // Printer: add dump task tree
 // -----------------------------------------------------------------------------
 impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Print the open tasks and how long each has run, in verbose mode only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `tasks` lock is poisoned.
     pub fn dump_task_tree(&self) {
-        if !self.inner.is_verbose() {
+        self.routed(|| {
+            if !self.inner.is_verbose() {
+                return;
+            }
+
+            let tasks = self.tasks.lock().unwrap();
+            if tasks.is_empty() {
+                write_line(LogLevel::Info, "(no active tasks)");
+                return;
+            }
+
+            // Pad by display columns so wide (e.g. CJK) labels keep the timing
+            // column aligned.
+            let number_width = tasks.len().to_string().len();
+            let label_width = tasks
+                .iter()
+                .map(|t| display_width(&t.label))
+                .max()
+                .unwrap_or(0);
+
+            write_line(LogLevel::Info, "Active tasks:");
+            for (i, t) in tasks.iter().enumerate() {
+                let elapsed = self.since(t.start);
+                let timing = format_duration(elapsed);
+                let pad = " ".repeat(label_width - display_width(&t.label));
+                write_line(
+                    LogLevel::Info,
+                    &format!(
+                        "  {:>number_width$}. {}{pad} (started, +{})",
+                        i + 1,
+                        t.label,
+                        timing
+                    ),
+                );
+            }
+        });
+    }
+}
+
+impl<L: FormatLogger, B: RenderBackend> Drop for Printer<L, B> {
+    /// Report tasks that were introduced but never closed.
+    ///
+    /// Only in Verbose/Trace: a leaked task is a bug for the developer, not
+    /// something end users should see.
+    fn drop(&mut self) {
+        // No `lock().unwrap()` here: a poisoned lock must not turn the drop
+        // into a second panic.
+        let verbosity = self
+            .verbosity_override
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .unwrap_or(self.verbosity);
+        if verbosity < Verbosity::Verbose {
             return;
         }
 
-        let tasks = self.tasks.lock().unwrap();
+        let now = self
+            .clock
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+            .map_or_else(Instant::now, |c| c.now());
+        let tasks = self.tasks.get_mut().unwrap_or_else(PoisonError::into_inner);
         if tasks.is_empty() {
-            println!("(no active tasks)");
             return;
         }
 
-        println!("Active tasks:");
-        for (i, t) in tasks.iter().enumerate() {
-            let elapsed = t.start.elapsed();
-            let timing = format_duration(elapsed);
-            println!("  {}. {} (started, +{})", i + 1, t.label, timing);
-        }
+        let plural = if tasks.len() == 1 { "" } else { "s" };
+        let mut lines = vec![format!(
+            "warning: printer dropped with {} unfinished task{plural}:",
+            tasks.len()
+        )];
+        lines.extend(tasks.iter().map(|t| {
+            format!(
+                "  - {} (open for {})",
+                t.label,
+                format_duration(now.saturating_duration_since(t.start))
+            )
+        }));
+
+        let policy = *self
+            .stream_policy
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        with_stream_policy(policy, || {
+            for line in &lines {
+                write_line(LogLevel::Warn, line);
+            }
+        });
     }
 }
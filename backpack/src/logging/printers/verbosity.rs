@@ -0,0 +1,69 @@
+use crate::{
+    Verbosity,
+    logging::{FormatLogger, Printer, RenderBackend},
+};
+use std::sync::PoisonError;
+
+// -----------------------------------------------------------------------------
+// Printer: runtime verbosity
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// The current level: the one from `set_verbosity`, else the one the
+    /// printer was built with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `verbosity_override` lock is poisoned.
+    #[must_use]
+    pub fn verbosity(&self) -> Verbosity {
+        self.verbosity_override
+            .lock()
+            .unwrap()
+            .unwrap_or(self.verbosity)
+    }
+
+    /// Change verbosity after construction.
+    ///
+    /// Only this printer is affected: its formatters see the new level while
+    /// it writes, and the global quiet/verbose flags are left alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `verbosity_override` lock is poisoned.
+    pub fn set_verbosity(&self, verbosity: Verbosity) {
+        *self.verbosity_override.lock().unwrap() = Some(verbosity);
+    }
+
+    /// Run `f` at `verbosity`, then restore the previous level, even if `f`
+    /// panics.
+    ///
+    /// Handy for tracing one operation without turning it on everywhere.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `verbosity_override` lock is poisoned.
+    pub fn with_verbosity<T>(&self, verbosity: Verbosity, f: impl FnOnce() -> T) -> T {
+        struct Restore<'a, L: FormatLogger, B: RenderBackend> {
+            printer: &'a Printer<L, B>,
+            previous: Option<Verbosity>,
+        }
+
+        impl<L: FormatLogger, B: RenderBackend> Drop for Restore<'_, L, B> {
+            fn drop(&mut self) {
+                let mut current = self
+                    .printer
+                    .verbosity_override
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                *current = self.previous;
+            }
+        }
+
+        let _restore = Restore {
+            printer: self,
+            previous: *self.verbosity_override.lock().unwrap(),
+        };
+        self.set_verbosity(verbosity);
+        f()
+    }
+}
@@ -0,0 +1,122 @@
+use crate::logging::{FormatLogger, Printer, RenderBackend, Stream, truncate_to_width};
+use std::{
+    borrow::Cow,
+    io::{IsTerminal, stderr, stdout},
+};
+
+const DEFAULT_RULE_WIDTH: usize = 60;
+
+/// Assumed terminal width when stdout is not a terminal.
+const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+// -----------------------------------------------------------------------------
+// Printer: text-mode line width limiting
+// -----------------------------------------------------------------------------
+impl<L: FormatLogger, B: RenderBackend> Printer<L, B> {
+    /// Truncate text-mode lines to `width` display columns (`None` disables).
+    ///
+    /// JSON and CSV output are never truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `max_line_width` lock is poisoned.
+    pub fn set_max_line_width(&self, width: Option<usize>) {
+        *self.max_line_width.lock().unwrap() = width;
+    }
+
+    /// Set the max line width from the current terminal size.
+    ///
+    /// Leaves the setting untouched when stdout is not a terminal and no
+    /// width is pinned with `set_terminal_width`.
+    pub fn detect_max_line_width(&self) {
+        if let Some(width) = self.probe_width() {
+            self.set_max_line_width(Some(width));
+        }
+    }
+
+    /// Pin the terminal width used by rules and width detection.
+    ///
+    /// `None` (the default) queries the live terminal again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `terminal_width` lock is poisoned.
+    pub fn set_terminal_width(&self, width: Option<usize>) {
+        *self.terminal_width.lock().unwrap() = width;
+    }
+
+    /// Treat the output streams as terminals (`Some(true)`) or not, instead
+    /// of probing.
+    ///
+    /// `None` (the default) checks the real stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `tty` lock is poisoned.
+    pub fn set_tty(&self, tty: Option<bool>) {
+        *self.tty.lock().unwrap() = tty;
+    }
+
+    /// Whether stdout should be treated as a terminal.
+    pub fn is_tty(&self) -> bool {
+        self.stream_is_tty(Stream::Stdout)
+    }
+
+    /// Whether `stream` should be treated as a terminal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `tty` lock is poisoned.
+    pub fn stream_is_tty(&self, stream: Stream) -> bool {
+        let forced = *self.tty.lock().unwrap();
+        forced.unwrap_or_else(|| match stream {
+            Stream::Stdout => stdout().is_terminal(),
+            Stream::Stderr => stderr().is_terminal(),
+        })
+    }
+
+    /// The pinned terminal width, else the live one, else 80.
+    pub fn terminal_width(&self) -> usize {
+        self.probe_width().unwrap_or(DEFAULT_TERMINAL_WIDTH)
+    }
+
+    /// Pinned width, or the live width when stdout is a terminal.
+    fn probe_width(&self) -> Option<usize> {
+        let pinned = *self.terminal_width.lock().unwrap();
+
+        pinned.or_else(|| {
+            stdout()
+                .is_terminal()
+                .then(terminal_size::terminal_size)
+                .flatten()
+                .map(|(terminal_size::Width(w), _)| usize::from(w))
+        })
+    }
+
+    /// Width for dividers: the max line width, else the terminal width, else 60.
+    pub(crate) fn rule_width(&self) -> usize {
+        let configured = *self.max_line_width.lock().unwrap();
+
+        configured
+            .or_else(|| self.probe_width())
+            .unwrap_or(DEFAULT_RULE_WIDTH)
+    }
+
+    /// Apply the configured max line width to each line of `s`.
+    pub(crate) fn fit_width<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let Some(max) = *self.max_line_width.lock().unwrap() else {
+            return Cow::Borrowed(s);
+        };
+
+        if !s.contains('\n') {
+            return truncate_to_width(s, max);
+        }
+
+        Cow::Owned(
+            s.split('\n')
+                .map(|line| truncate_to_width(line, max))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+}
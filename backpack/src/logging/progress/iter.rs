@@ -0,0 +1,67 @@
+use crate::logging::Progress;
+use std::time::Duration;
+
+/// Iterator adapter that ticks a `Progress` once per item.
+///
+/// Created by [`Progress::wrap_iter`]. The progress finishes when the
+/// iterator is exhausted, or when the adapter is dropped early.
+pub struct ProgressIter<I> {
+    iter: I,
+    progress: Option<Progress>,
+}
+
+impl Progress {
+    /// Wrap an iterator so each item ticks progress, using `len()` as the total.
+    pub fn wrap_iter<I: ExactSizeIterator>(label: &str, iter: I) -> ProgressIter<I> {
+        let total = iter.len() as u64;
+
+        ProgressIter {
+            iter,
+            progress: Some(Self::with_total(label, total)),
+        }
+    }
+}
+
+impl<I> ProgressIter<I> {
+    /// Cap how often ticks re-render; see [`Progress::throttle`].
+    #[must_use]
+    pub fn throttle(mut self, interval: Duration) -> Self {
+        self.progress = self.progress.take().map(|p| p.throttle(interval));
+        self
+    }
+
+    fn finish(&mut self) {
+        if let Some(progress) = self.progress.take() {
+            let label = progress.label.clone();
+            progress.finish(&label);
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for ProgressIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+
+        if item.is_none() {
+            self.finish();
+        } else if let Some(progress) = self.progress.as_mut() {
+            progress.tick();
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for ProgressIter<I> {}
+
+impl<I> Drop for ProgressIter<I> {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
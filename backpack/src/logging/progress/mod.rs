@@ -1,4 +1,11 @@
-use crate::logging::L;
+use crate::logging::{L, ProgressUpdate, approx_f64, logger};
+use std::time::{Duration, Instant};
+
+mod iter;
+mod multi;
+
+pub use iter::*;
+pub use multi::*;
 
 /// Lightweight progress handle for long-running tasks.
 pub struct Progress {
@@ -6,9 +13,37 @@ pub struct Progress {
     pub(crate) total: Option<u64>,
     pub(crate) current: u64,
     pub(crate) finished: bool,
+    pub(crate) throttle: Duration,
+    pub(crate) last_render: Option<Instant>,
+    /// Task depth at creation, so nested progress indents under its task.
+    pub(crate) depth: usize,
+    /// Weight of the newest interval in the ETA moving average, in `(0, 1]`.
+    pub(crate) eta_smoothing: f64,
+    /// Smoothed seconds per item, once two updates have been observed.
+    pub(crate) avg_interval: Option<f64>,
+    /// Time and position of the last observed update.
+    pub(crate) last_tick: Option<(Instant, u64)>,
+    /// When the handle was created; JSON progress events derive `rate` from it.
+    pub(crate) started: Instant,
+    /// Called with `(current, total)` on every `tick`/`update`.
+    pub(crate) on_update: Option<UpdateCallback>,
+    /// Failed attempts reported with `retry`.
+    pub(crate) retries: u32,
+    /// Row in a `MultiProgress` block, once added to one.
+    pub(crate) row: Option<(MultiProgress, usize)>,
 }
 
+/// Receives `(current, total)` for each progress update.
+pub type UpdateCallback = Box<dyn Fn(u64, Option<u64>) + Send>;
+
 impl Progress {
+    /// Default minimum interval between two rendered updates; pass
+    /// `Duration::ZERO` to `throttle` to render every call.
+    pub const DEFAULT_THROTTLE: Duration = Duration::from_millis(50);
+
+    /// Default weight of the newest interval in the ETA moving average.
+    pub const DEFAULT_ETA_SMOOTHING: f64 = 0.3;
+
     /// Create a progress handle without a known total.
     #[must_use]
     pub fn new(label: &str) -> Self {
@@ -20,6 +55,16 @@ impl Progress {
             total: None,
             current: 0,
             finished: false,
+            throttle: Self::DEFAULT_THROTTLE,
+            last_render: None,
+            depth: L.task_depth(),
+            eta_smoothing: Self::DEFAULT_ETA_SMOOTHING,
+            avg_interval: None,
+            last_tick: None,
+            started: L.now(),
+            on_update: None,
+            retries: 0,
+            row: None,
         }
     }
 
@@ -33,39 +78,208 @@ impl Progress {
             total: Some(total),
             current: 0,
             finished: false,
+            throttle: Self::DEFAULT_THROTTLE,
+            last_render: None,
+            depth: L.task_depth(),
+            eta_smoothing: Self::DEFAULT_ETA_SMOOTHING,
+            avg_interval: None,
+            last_tick: None,
+            started: L.now(),
+            on_update: None,
+            retries: 0,
+            row: None,
+        }
+    }
+
+    /// Cap how often `tick`/`update` re-render.
+    ///
+    /// Updates arriving less than `interval` after the last render only
+    /// advance the internal state. `finish` always renders.
+    #[must_use]
+    pub const fn throttle(mut self, interval: Duration) -> Self {
+        self.throttle = interval;
+        self
+    }
+
+    /// Set how strongly the ETA follows the latest tick interval.
+    ///
+    /// `1.0` uses only the newest interval; smaller values smooth out bursts
+    /// and stalls. Values are clamped to `(0, 1]`.
+    pub const fn set_eta_smoothing(&mut self, factor: f64) {
+        self.eta_smoothing = factor.clamp(f64::EPSILON, 1.0);
+    }
+
+    /// Report every `tick`/`update` to `cb` as `(current, total)`.
+    ///
+    /// Lets an embedding app drive its own progress widget. The callback runs
+    /// in addition to normal rendering and is not throttled.
+    pub fn on_update(&mut self, cb: UpdateCallback) {
+        self.on_update = Some(cb);
+    }
+
+    /// Estimated time remaining, once the total and a rate are known.
+    #[must_use]
+    pub fn eta(&self) -> Option<Duration> {
+        let remaining = self.total?.saturating_sub(self.current);
+        let secs = self.avg_interval? * approx_f64(remaining);
+        Some(Duration::from_secs_f64(secs))
+    }
+
+    /// Fold the interval since the last observed update into the average.
+    ///
+    /// Zero-length intervals and updates that don't advance are skipped, so
+    /// bursts can't drive the ETA to zero.
+    pub(crate) fn observe(&mut self, now: Instant) {
+        let Some((last, last_count)) = self.last_tick else {
+            self.last_tick = Some((now, self.current));
+            return;
+        };
+
+        let elapsed = now.saturating_duration_since(last).as_secs_f64();
+        let advanced = self.current.saturating_sub(last_count);
+        if elapsed <= 0.0 || advanced == 0 {
+            return;
         }
+
+        let per_item = elapsed / approx_f64(advanced);
+        self.avg_interval = Some(self.avg_interval.map_or(per_item, |avg| {
+            self.eta_smoothing.mul_add(per_item - avg, avg)
+        }));
+        self.last_tick = Some((now, self.current));
     }
 
     /// Manually update progress with an explicit current/total.
+    ///
+    /// Does nothing once the progress has finished or aborted.
     pub fn update(&mut self, current: u64, total: u64) {
+        if self.finished {
+            return;
+        }
+
         self.current = current;
         self.total = Some(total);
+        self.observe(L.now());
+        self.notify();
 
         // Semantic progress event; backend decides how to render
-        let () = L.progress(&self.label, self.current, self.total, false);
+        self.render();
     }
 
     /// Increment progress by 1 and emit an update.
+    ///
+    /// Does nothing once the progress has finished or aborted.
     pub fn tick(&mut self) {
+        if self.finished {
+            return;
+        }
+
         self.current += 1;
-        let () = L.progress(&self.label, self.current, self.total, false);
+        self.observe(L.now());
+        self.notify();
+        self.render();
+    }
+
+    /// Report a failed attempt that will be retried, e.g. a dropped connection.
+    ///
+    /// Emits a warning (`label: retrying (reason)`) without advancing the
+    /// count. Does nothing once the progress has finished or aborted.
+    pub fn retry(&mut self, reason: &str) {
+        if self.finished {
+            return;
+        }
+
+        self.retries += 1;
+        logger().warn(&format!("{}: retrying ({reason})", self.label));
+    }
+
+    /// How many times `retry` has been called.
+    #[must_use]
+    pub const fn retries(&self) -> u32 {
+        self.retries
     }
 
     /// Finish the progress with a final message.
     ///
     /// `msg` is the final label shown by the backend (e.g. "Done", "Completed").
+    /// After any `retry`, the outro adds the count: `msg (finished with 1 retry)`.
     pub fn finish(mut self, msg: &str) {
         if self.finished {
             return;
         }
 
         // Final progress event, marked as finished
-        let () = L.progress(msg, self.current, self.total, true);
+        self.emit(&ProgressUpdate {
+            finished: true,
+            depth: self.depth,
+            elapsed: Some(L.now().saturating_duration_since(self.started)),
+            ..ProgressUpdate::new(msg, self.current, self.total)
+        });
 
         // Preserve your existing outro/done semantics for non-progress-aware backends
-        let _ = crate::logging::outro(msg);
+        let outro = match self.retries {
+            0 => msg.to_string(),
+            1 => format!("{msg} (finished with 1 retry)"),
+            n => format!("{msg} (finished with {n} retries)"),
+        };
+        let _ = crate::logging::outro(&outro);
         let _ = crate::logging::done();
 
         self.finished = true;
     }
+
+    /// Mark the progress as failed instead of done.
+    ///
+    /// Emits a final aborted update and an error line (`✗ label: reason`)
+    /// in place of the success outro.
+    pub fn abort(mut self, reason: &str) {
+        if self.finished {
+            return;
+        }
+
+        self.emit(&ProgressUpdate {
+            finished: true,
+            aborted: true,
+            depth: self.depth,
+            elapsed: Some(L.now().saturating_duration_since(self.started)),
+            ..ProgressUpdate::new(&self.label, self.current, self.total)
+        });
+
+        logger().err(&format!("{}: {reason}", self.label));
+
+        self.finished = true;
+    }
+
+    /// Send `update` to the global logger, inside this handle's multi-progress
+    /// row if it has one.
+    fn emit(&self, update: &ProgressUpdate<'_>) {
+        match &self.row {
+            Some((multi, index)) => multi.in_row(*index, || L.progress(update)),
+            None => L.progress(update),
+        }
+    }
+
+    fn notify(&self) {
+        if let Some(cb) = &self.on_update {
+            cb(self.current, self.total);
+        }
+    }
+
+    /// Emit an in-flight update unless one was rendered within the throttle window.
+    fn render(&mut self) {
+        let now = L.now();
+
+        if let Some(last) = self.last_render
+            && now.duration_since(last) < self.throttle
+        {
+            return;
+        }
+
+        self.last_render = Some(now);
+        self.emit(&ProgressUpdate {
+            depth: self.depth,
+            eta: self.eta(),
+            elapsed: Some(now.saturating_duration_since(self.started)),
+            ..ProgressUpdate::new(&self.label, self.current, self.total)
+        });
+    }
 }
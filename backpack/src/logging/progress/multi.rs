@@ -0,0 +1,124 @@
+use crate::logging::{
+    LogLevel, Progress, lines_written, output_lock, with_thread_local, write_inline,
+};
+use std::{
+    cell::Cell,
+    fmt::Write,
+    sync::{Arc, Mutex},
+};
+
+/// Coordinates several concurrent `Progress` handles, e.g. parallel downloads.
+///
+/// On a terminal each handle added with [`MultiProgress::add`] owns one row
+/// of a block that is redrawn in place, so their updates no longer fight
+/// over a single `\r` line. Otherwise (not a TTY, or non-text output) every
+/// update prints its own line as usual and the rows are only tracked.
+///
+/// Create one with `Printer::multi_progress`. Output written by other code
+/// while the block is live pushes it down; the next redraw starts a fresh
+/// block below instead of overwriting that output.
+#[derive(Clone)]
+pub struct MultiProgress {
+    region: Arc<Mutex<LiveRegion>>,
+}
+
+/// The rows of one `MultiProgress` and what was last drawn of them.
+struct LiveRegion {
+    live: bool,
+    rows: Vec<String>,
+    /// Rows currently on screen above the cursor.
+    drawn: usize,
+    /// `lines_written` right after the last redraw.
+    seen: u64,
+}
+
+thread_local! {
+    /// The block and row the progress update being rendered on this thread
+    /// belongs to.
+    static ACTIVE_ROW: Cell<Option<(MultiProgress, usize)>> = const { Cell::new(None) };
+}
+
+impl MultiProgress {
+    /// A coordinator that redraws a live block when `live`, else prints lines.
+    #[must_use]
+    pub fn new(live: bool) -> Self {
+        Self {
+            region: Arc::new(Mutex::new(LiveRegion {
+                live,
+                rows: Vec::new(),
+                drawn: 0,
+                seen: 0,
+            })),
+        }
+    }
+
+    /// Whether updates are redrawn as a block rather than printed as lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `region` lock is poisoned.
+    #[must_use]
+    pub fn is_live(&self) -> bool {
+        self.region.lock().unwrap().live
+    }
+
+    /// Give `progress` the next row of the block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `region` lock is poisoned.
+    #[must_use]
+    pub fn add(&self, mut progress: Progress) -> Progress {
+        let mut region = self.region.lock().unwrap();
+        region.rows.push(progress.label.clone());
+        progress.row = Some((self.clone(), region.rows.len() - 1));
+        progress
+    }
+
+    /// The current text of each row, in block order.
+    ///
+    /// Rows start out as their label and follow the latest live update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `region` lock is poisoned.
+    #[must_use]
+    pub fn rows(&self) -> Vec<String> {
+        self.region.lock().unwrap().rows.clone()
+    }
+
+    /// Run `f` with progress rendering on this thread targeting row `index`.
+    pub(crate) fn in_row<T>(&self, index: usize, f: impl FnOnce() -> T) -> T {
+        with_thread_local(&ACTIVE_ROW, Some((self.clone(), index)), f)
+    }
+
+    /// The live block and row the current progress update belongs to, if any.
+    pub(crate) fn live_row() -> Option<(Self, usize)> {
+        ACTIVE_ROW
+            .with(|row| {
+                let active = row.take();
+                row.set(active.clone());
+                active
+            })
+            .filter(|(multi, _)| multi.is_live())
+    }
+
+    /// Store `line` as row `index` and redraw the whole block.
+    pub(crate) fn draw_row(&self, index: usize, line: String) {
+        let _output = output_lock();
+        let mut region = self.region.lock().unwrap();
+        region.rows[index] = line;
+
+        let mut block = String::new();
+        if region.drawn > 0 && region.seen == lines_written() {
+            let _ = write!(block, "\x1b[{}A", region.drawn);
+        }
+        for row in &region.rows {
+            let _ = writeln!(block, "\r\x1b[2K{row}");
+        }
+
+        write_inline(LogLevel::Progress, &block);
+        region.drawn = region.rows.len();
+        region.seen = lines_written();
+    }
+}
@@ -1,23 +1,3 @@
-// ============================================================================
-// 7. DEV-MODE BANNER (ROADMAP-LIKE, BUT IMPLEMENTED)
-// ============================================================================
-#[cfg(test)]
-mod dev_mode_banner_tests {
-    #[test]
-    #[ignore]
-    fn dev_mode_banner_prints_when_rust_log_is_debug_or_trace() {
-        // This is tricky to test reliably because `init()` is global and only runs once.
-        // Placeholder: when run in isolation with RUST_LOG=debug or trace, we expect
-        // a banner containing the project name to be printed to stdout.
-        //
-        // You can turn this into a real test by:
-        //   - spawning a subprocess with RUST_LOG=debug
-        //   - capturing its stdout
-        //   - asserting the banner is present
-        assert!(true);
-    }
-}
-
 // ============================================================================
 // 8. ROADMAP FEATURE PLACEHOLDERS (IGNORED)
 // ============================================================================
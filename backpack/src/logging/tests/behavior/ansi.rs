@@ -0,0 +1,86 @@
+mod ansi_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    #[test]
+    fn printers_keep_independent_color_settings() {
+        let colored = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        let plain = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        colored.set_ansi(true);
+        plain.set_ansi(false);
+
+        let colored_out = capture_stdout(|| ScreenLogger::ok(&colored, "saved"));
+        let plain_out = capture_stdout(|| ScreenLogger::ok(&plain, "saved"));
+
+        assert!(colored_out.contains("\x1b[32m"), "{colored_out:?}");
+        assert!(!plain_out.contains('\x1b'), "{plain_out:?}");
+        assert!(plain_out.contains("saved"));
+    }
+
+    #[test]
+    fn fields_follow_the_printer_color_setting() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_ansi(false);
+
+        let out = capture_stdout(|| printer.info("saved").field("rows", 3).emit());
+
+        assert_eq!(out, "INFO: saved rows=3\n");
+    }
+
+    #[test]
+    fn override_is_restored_after_formatting() {
+        let expected = ansi_enabled();
+
+        assert!(!with_ansi(Some(false), ansi_enabled));
+        assert!(with_ansi(Some(true), ansi_enabled));
+        assert_eq!(ansi_enabled(), expected);
+    }
+
+    #[test]
+    fn overrides_are_restored_after_a_panic() {
+        let ansi = ansi_enabled();
+        let emoji = emoji_enabled();
+
+        let result = std::panic::catch_unwind(|| {
+            with_ansi(Some(!ansi), || with_emoji(!emoji, || panic!("boom")));
+        });
+
+        assert!(result.is_err());
+        assert_eq!(ansi_enabled(), ansi);
+        assert_eq!(emoji_enabled(), emoji);
+    }
+}
+
+mod config_color_tests {
+    use crate::config::Config;
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    #[test]
+    fn nocolor_config_strips_ansi_from_ok() {
+        let config: Config =
+            toml::from_str("quiet = false\nverbose = false\nnocolor = true\n").unwrap();
+        let printer =
+            make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal).with_config(&config);
+
+        let out = capture_stdout(|| ScreenLogger::ok(&printer, "saved"));
+
+        assert!(!out.contains('\x1b'), "{out:?}");
+        assert!(out.contains("saved"));
+    }
+
+    #[test]
+    fn color_config_keeps_the_printer_setting() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_ansi(true);
+        printer.apply_config(&Config::default());
+
+        let out = capture_stdout(|| ScreenLogger::ok(&printer, "saved"));
+
+        assert!(out.contains("\x1b[32m"), "{out:?}");
+    }
+}
@@ -0,0 +1,150 @@
+mod batch_backend_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// A cloneable in-memory writer.
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl SharedBuf {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn shutdown_drains_batch_in_one_write() {
+        let buf = SharedBuf::default();
+        let printer = Printer::new(
+            MockLogger::new(Verbosity::Normal),
+            BatchBackend::new(buf.clone()).with_batch_size(10_000),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        for i in 0..1000 {
+            ScreenLogger::info(&printer, &format!("line {i}"));
+        }
+        assert_eq!(printer.backend.flush_count(), 0);
+        assert!(buf.contents().is_empty());
+
+        printer.backend.shutdown().unwrap();
+
+        assert_eq!(printer.backend.flush_count(), 1);
+        let out = buf.contents();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 1000);
+        assert_eq!(lines[0], "INFO: line 0");
+        assert_eq!(lines[999], "INFO: line 999");
+    }
+
+    #[test]
+    fn batch_size_triggers_write() {
+        let buf = SharedBuf::default();
+        let backend = BatchBackend::new(buf.clone()).with_batch_size(3);
+
+        for line in ["a", "b", "c", "d"] {
+            backend.render_info(line).unwrap();
+        }
+
+        assert_eq!(backend.flush_count(), 1);
+        assert_eq!(buf.contents(), "a\nb\nc\n");
+    }
+
+    #[test]
+    fn flush_interval_writes_pending_lines() {
+        let buf = SharedBuf::default();
+        let backend = BatchBackend::new(buf.clone());
+        backend.set_flush_interval(Duration::from_millis(10));
+
+        backend.render_info("tick").unwrap();
+        std::thread::sleep(Duration::from_millis(200));
+
+        assert_eq!(buf.contents(), "tick\n");
+        backend.shutdown().unwrap();
+    }
+
+    fn buffered_printer(buf: &SharedBuf) -> Printer<MockLogger, BatchBackend<SharedBuf>> {
+        Printer::new(
+            MockLogger::new(Verbosity::Normal),
+            BatchBackend::new(buf.clone()).with_batch_size(10_000),
+            LogFormat::Text,
+            Verbosity::Normal,
+        )
+    }
+
+    #[test]
+    fn error_flushes_the_lines_before_it() {
+        let buf = SharedBuf::default();
+        let printer = buffered_printer(&buf);
+
+        ScreenLogger::info(&printer, "connecting");
+        ScreenLogger::info(&printer, "retrying");
+        assert!(buf.contents().is_empty());
+
+        let _ = capture_stderr(|| ScreenLogger::err(&printer, "connection refused"));
+
+        assert_eq!(
+            buf.contents(),
+            "INFO: connecting\nINFO: retrying\nERR: connection refused\n"
+        );
+    }
+
+    #[test]
+    fn error_events_flush_too() {
+        let buf = SharedBuf::default();
+        let printer = buffered_printer(&buf);
+
+        ScreenLogger::info(&printer, "connecting");
+        let _ = capture_stderr(|| printer.error("gave up").field("attempts", 3).emit());
+
+        assert_eq!(printer.backend.flush_count(), 1);
+        assert!(buf.contents().starts_with("INFO: connecting\nERR: gave up"));
+    }
+
+    #[test]
+    fn csv_errors_flush_too() {
+        let buf = SharedBuf::default();
+        let printer = Printer::new(
+            MockLogger::new(Verbosity::Normal),
+            BatchBackend::new(buf.clone()).with_batch_size(10_000),
+            LogFormat::Csv,
+            Verbosity::Normal,
+        );
+        printer.backend.render_info("held back").unwrap();
+
+        let out = capture_stdout(|| ScreenLogger::err(&printer, "connection refused"));
+
+        assert_eq!(buf.contents(), "held back\n");
+        assert!(out.ends_with("error,ERR: connection refused\n"), "{out:?}");
+    }
+
+    #[test]
+    fn disabled_policy_keeps_errors_buffered() {
+        let buf = SharedBuf::default();
+        let printer = buffered_printer(&buf);
+        printer.set_flush_on_error(false);
+
+        ScreenLogger::info(&printer, "connecting");
+        let _ = capture_stderr(|| ScreenLogger::err(&printer, "connection refused"));
+
+        assert!(buf.contents().is_empty());
+        assert_eq!(printer.backend.flush_count(), 0);
+    }
+}
@@ -0,0 +1,118 @@
+mod capture_backend_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    #[test]
+    fn json_events_reach_the_backend() {
+        let backend = CaptureBackend::new();
+        let printer = Printer::new(
+            SimpleLogger,
+            backend.clone(),
+            LogFormat::Json,
+            Verbosity::Normal,
+        )
+        .with_timestamp_mode(TimestampMode::Disabled);
+
+        let out = capture_stdout(|| {
+            printer.info("deployed").field("region", "eu").emit();
+        });
+
+        assert_eq!(out, "", "JSON must not bypass the backend");
+        let lines = backend.lines();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].0, LogLevel::Info);
+
+        let event: Value = serde_json::from_str(&lines[0].1).unwrap();
+        assert_eq!(event["message"], "deployed");
+        assert_eq!(event["fields"]["region"], "eu");
+    }
+
+    #[test]
+    fn text_lines_are_captured_in_order() {
+        let backend = CaptureBackend::new();
+        let printer = Printer::new(
+            MockLogger::new(Verbosity::Normal),
+            backend.clone(),
+            LogFormat::Text,
+            Verbosity::Normal,
+        )
+        .with_timestamp_mode(TimestampMode::Disabled);
+
+        ScreenLogger::intro(&printer, "build");
+        ScreenLogger::warn(&printer, "slow");
+        ScreenLogger::err(&printer, "failed");
+
+        assert_eq!(
+            backend.take(),
+            vec![
+                (LogLevel::Info, "INTRO: build".to_string()),
+                (LogLevel::Warn, "WARN: slow".to_string()),
+                (LogLevel::Error, "ERR: failed".to_string()),
+            ]
+        );
+        assert!(backend.lines().is_empty());
+    }
+}
+
+mod capture_drain_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn drain_filters_by_min_level_and_empties_the_buffer() {
+        let printer = Printer::new(
+            MockLogger::new(Verbosity::Normal),
+            CaptureBackend::new(),
+            LogFormat::Json,
+            Verbosity::Normal,
+        )
+        .with_timestamp_mode(TimestampMode::Disabled);
+
+        printer.info("starting").emit();
+        printer.warn("slow disk").field("mount", "/data").emit();
+        printer.error("write failed").emit();
+
+        let events = printer.drain_captured(Some(LogLevel::Warn));
+
+        let summary: Vec<_> = events
+            .iter()
+            .map(|e| (e.level, e.message.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (LogLevel::Warn, "slow disk"),
+                (LogLevel::Error, "write failed")
+            ]
+        );
+        assert_eq!(
+            events[0].fields.get("mount").map(String::as_str),
+            Some("/data")
+        );
+        assert!(printer.backend.drain(None).is_empty());
+    }
+
+    #[test]
+    fn text_lines_drain_as_rendered() {
+        let backend = CaptureBackend::new();
+        let printer = Printer::new(
+            MockLogger::new(Verbosity::Normal),
+            backend.clone(),
+            LogFormat::Text,
+            Verbosity::Normal,
+        )
+        .with_timestamp_mode(TimestampMode::Disabled);
+
+        ScreenLogger::info(&printer, "hello");
+
+        let events = backend.drain(None);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].message, "INFO: hello");
+        assert!(events[0].fields.is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+mod child_logger_behavior_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+    use std::sync::Arc;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn child_output_carries_tag_parent_does_not() {
+        let printer = Arc::new(make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        ));
+        let child = printer.child("db");
+
+        let out = capture_stdout(|| {
+            ScreenLogger::info(&*printer, "host message");
+            ScreenLogger::info(&child, "library message");
+        });
+
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines,
+            vec!["INFO: host message", "INFO: [db] library message"]
+        );
+    }
+
+    #[test]
+    fn child_verbosity_override_only_narrows() {
+        let printer = Arc::new(make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        ));
+        let child = printer.child("db").with_verbosity(Verbosity::Quiet);
+
+        let out = capture_stdout(|| {
+            ScreenLogger::info(&child, "hidden");
+            ScreenLogger::ok(&child, "hidden too");
+        });
+
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn child_outlives_the_borrow_and_moves_to_a_thread() {
+        let printer = Arc::new(make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        ));
+        let child = printer.child("worker");
+
+        let out = capture_stdout(|| {
+            std::thread::spawn(move || ScreenLogger::info(&child, "from a thread"))
+                .join()
+                .unwrap();
+        });
+
+        assert_eq!(out, "INFO: [worker] from a thread\n");
+    }
+}
@@ -0,0 +1,99 @@
+mod concurrent_output_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use serde_json::Value;
+
+    #[test]
+    fn concurrent_json_emits_never_interleave() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_timestamp_mode(TimestampMode::Disabled);
+
+        let out = capture_stdout(|| {
+            std::thread::scope(|s| {
+                for t in 0..8 {
+                    let printer = &printer;
+                    s.spawn(move || {
+                        for i in 0..50 {
+                            printer
+                                .info(&format!("thread {t} event {i} {}", "x".repeat(200)))
+                                .field("thread", t)
+                                .field("event", i)
+                                .emit();
+                        }
+                    });
+                }
+            });
+        });
+
+        let lines: Vec<&str> = out.lines().filter(|l| !l.trim().is_empty()).collect();
+        assert_eq!(lines.len(), 8 * 50);
+        for line in lines {
+            serde_json::from_str::<Value>(line)
+                .unwrap_or_else(|e| panic!("interleaved line {line:?}: {e}"));
+        }
+    }
+}
+
+mod emit_many_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn batch_keeps_order_and_stream_routing() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        let row = |n: &str| Fields::from([("row".to_string(), n.to_string())]);
+
+        let mut out = String::new();
+        let err = capture_stderr(|| {
+            out = capture_stdout(|| {
+                printer.emit_many(&[
+                    (LogLevel::Info, "checked", row("1")),
+                    (LogLevel::Error, "missing id", row("2")),
+                    (LogLevel::Warn, "empty name", row("3")),
+                ]);
+            });
+        });
+
+        let plain = |s: &str| s.replace("\x1b[2m", "").replace("\x1b[0m", "");
+        assert_eq!(plain(&out), "INFO: checked row=1\nWARN: empty name row=3\n");
+        assert_eq!(plain(&err), "ERR: missing id row=2\n");
+    }
+
+    #[test]
+    fn batch_writes_are_not_split_by_other_threads() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_timestamp_mode(TimestampMode::Disabled);
+        let batch: Vec<_> = (0..20)
+            .map(|_| (LogLevel::Info, "batched", Fields::new()))
+            .collect();
+
+        let out = capture_stdout(|| {
+            std::thread::scope(|s| {
+                s.spawn(|| {
+                    for _ in 0..50 {
+                        printer.info("single").emit();
+                    }
+                });
+                s.spawn(|| printer.emit_many(&batch));
+            });
+        });
+
+        let batched = |l: &&str| l.contains("batched");
+        let first = out.lines().position(|l| batched(&l)).unwrap();
+        assert_eq!(out.lines().filter(batched).count(), 20);
+        assert_eq!(
+            out.lines().skip(first).take(20).filter(batched).count(),
+            20,
+            "batch was interleaved:\n{out}"
+        );
+    }
+}
@@ -0,0 +1,42 @@
+mod csv_format_behavior_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn csv_mode_writes_header_once_then_one_row_per_event() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Csv,
+            Verbosity::Normal,
+        );
+
+        let out = capture_stdout(|| {
+            printer
+                .info("Imported record")
+                .field("id", 1)
+                .field("name", "Smith, John");
+            printer
+                .info("Imported record")
+                .field("id", 2)
+                .field("name", "Jane \"JJ\" Doe");
+        });
+
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "level,message,id,name",
+                "info,Imported record,1,\"Smith, John\"",
+                "info,Imported record,2,\"Jane \"\"JJ\"\" Doe\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn csv_escape_quotes_line_breaks() {
+        assert_eq!(csv::csv_escape("plain"), "plain");
+        assert_eq!(csv::csv_escape("two\nlines"), "\"two\nlines\"");
+    }
+}
@@ -0,0 +1,47 @@
+mod heading_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn heading(format: LogFormat, ansi: bool) -> String {
+        let printer = make_printer(SimpleLogger, format, Verbosity::Normal);
+        printer.set_ansi(ansi);
+        capture_stdout(|| ScreenLogger::heading(&printer, "Database"))
+    }
+
+    #[test]
+    fn colored_heading_is_bold_between_blank_lines() {
+        assert_eq!(
+            heading(LogFormat::Text, true),
+            "\n\x1b[1;4mDatabase\x1b[0m\n\n"
+        );
+    }
+
+    #[test]
+    fn nocolor_heading_is_plain() {
+        assert_eq!(heading(LogFormat::Text, false), "\nDatabase\n\n");
+    }
+
+    #[test]
+    fn json_heading_emits_marker() {
+        let out = heading(LogFormat::Json, false);
+        let event: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+
+        assert_eq!(event["level"], "info");
+        assert_eq!(event["message"], "Database");
+        assert_eq!(event["fields"]["heading"], "Database");
+    }
+
+    #[test]
+    fn quiet_printer_skips_heading() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Quiet),
+            LogFormat::Text,
+            Verbosity::Quiet,
+        );
+
+        let out = capture_stdout(|| ScreenLogger::heading(&printer, "Database"));
+        assert_eq!(out, "");
+    }
+}
@@ -73,4 +73,206 @@ mod json_format_behavior_tests {
         assert_eq!(v["fields"]["role"], "admin");
         assert_snapshot!(out);
     }
+
+    #[test]
+    fn json_task_ids_pair_intro_and_outro() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            ScreenLogger::intro(&printer, "outer");
+            ScreenLogger::intro(&printer, "inner");
+            ScreenLogger::outro(&printer, "inner done");
+            ScreenLogger::outro(&printer, "outer done");
+        });
+
+        let ids: Vec<String> = out
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                let v: Value = serde_json::from_str(l).expect("Expected valid JSON");
+                v["fields"]["task_id"].as_str().unwrap().to_string()
+            })
+            .collect();
+
+        assert_eq!(ids.len(), 4);
+        assert_ne!(ids[0], ids[1], "nested tasks need distinct ids");
+        assert_eq!(ids[2], ids[1], "inner outro pairs with inner intro");
+        assert_eq!(ids[3], ids[0], "outer outro pairs with outer intro");
+    }
+
+    /// Records each flush so tests can see where event boundaries fall.
+    #[derive(Default)]
+    struct CountingWriter {
+        buf: Vec<u8>,
+        flushed_at: Vec<usize>,
+    }
+
+    impl std::io::Write for CountingWriter {
+        fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+            self.buf.extend_from_slice(data);
+            Ok(data.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.flushed_at.push(self.buf.len());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn line_buffered_writes_flush_after_each_event() {
+        let mut out = CountingWriter::default();
+
+        write_line_to(&mut out, r#"{"message":"one"}"#, true).unwrap();
+        write_line_to(&mut out, r#"{"message":"two"}"#, true).unwrap();
+
+        let text = String::from_utf8(out.buf).unwrap();
+        assert_eq!(text, "{\"message\":\"one\"}\n{\"message\":\"two\"}\n");
+        // Each flush lands exactly on a line boundary.
+        assert_eq!(out.flushed_at, vec![18, 36]);
+    }
+
+    #[test]
+    fn unbuffered_writes_leave_flushing_to_the_stream() {
+        let mut out = CountingWriter::default();
+
+        write_line_to(&mut out, "{}", false).unwrap();
+
+        assert!(out.flushed_at.is_empty());
+    }
+
+    #[test]
+    fn json_events_are_newline_delimited_when_piped() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        assert!(
+            printer
+                .line_buffered
+                .load(std::sync::atomic::Ordering::Relaxed)
+        );
+
+        let out = capture_stdout(|| {
+            printer.info("first").emit();
+            printer.info("second").emit();
+        });
+
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            serde_json::from_str::<Value>(line).expect("Expected one JSON object per line");
+        }
+    }
+}
+
+mod json_key_map_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::{Value, json};
+
+    fn first_event(printer: &Printer<SimpleLogger, SimpleBackend>) -> Value {
+        let out = capture_stdout(|| printer.info("deployed").field("env", "prod").emit());
+        serde_json::from_str(out.lines().next().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn renamed_keys_replace_the_standard_ones() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_timestamp_mode(TimestampMode::Fixed("2024-01-01T00:00:00Z"));
+        printer.set_json_key_map(
+            JsonKeyMap::default()
+                .message("msg")
+                .level("severity")
+                .timestamp("ts"),
+        );
+
+        assert_eq!(
+            first_event(&printer),
+            json!({
+                "severity": "info",
+                "msg": "deployed",
+                "ts": "2024-01-01T00:00:00Z",
+                "fields": { "env": "prod" },
+            })
+        );
+    }
+
+    #[test]
+    fn default_map_keeps_the_standard_keys() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_timestamp_mode(TimestampMode::Disabled);
+
+        assert_eq!(
+            first_event(&printer),
+            json!({ "level": "info", "message": "deployed", "fields": { "env": "prod" } })
+        );
+    }
+}
+
+mod level_label_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+    use std::collections::HashMap;
+
+    fn syslog_printer() -> Printer<SimpleLogger, SimpleBackend> {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_timestamp_mode(TimestampMode::Disabled);
+        printer.set_level_labels(HashMap::from([
+            (LogLevel::Error, "3".to_string()),
+            (LogLevel::Warn, "4".to_string()),
+        ]));
+        printer
+    }
+
+    fn level_of(out: &str) -> Value {
+        let event: Value = serde_json::from_str(out.lines().next().unwrap()).unwrap();
+        event["level"].clone()
+    }
+
+    #[test]
+    fn mapped_levels_render_with_their_label() {
+        let printer = syslog_printer();
+
+        let err = capture_stderr(|| printer.error("disk full").emit());
+
+        assert_eq!(level_of(&err), "3");
+    }
+
+    #[test]
+    fn unmapped_levels_keep_the_default_text() {
+        let printer = syslog_printer();
+
+        let out = capture_stdout(|| printer.info("started").emit());
+
+        assert_eq!(level_of(&out), "info");
+    }
+}
+
+mod unix_timestamp_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use serde_json::Value;
+
+    #[test]
+    fn unix_millis_timestamp_is_a_number_near_now() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_timestamp_mode(TimestampMode::UnixMillis);
+
+        let before = chrono::Utc::now().timestamp_millis();
+        let out = capture_stdout(|| printer.info("tick").emit());
+        let after = chrono::Utc::now().timestamp_millis();
+
+        let event: Value = serde_json::from_str(out.lines().next().unwrap()).unwrap();
+        let timestamp = event["timestamp"]
+            .as_i64()
+            .expect("timestamp is not a number");
+        assert!(
+            (before..=after).contains(&timestamp),
+            "{timestamp} not in {before}..={after}"
+        );
+    }
 }
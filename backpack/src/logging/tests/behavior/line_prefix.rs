@@ -0,0 +1,114 @@
+mod thread_prefix_behavior_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use serde_json::Value;
+
+    #[test]
+    fn text_lines_carry_thread_names_when_enabled() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Trace),
+            LogFormat::Text,
+            Verbosity::Trace,
+        );
+        printer.set_show_thread(true);
+
+        let out = capture_stdout(|| {
+            std::thread::scope(|scope| {
+                for name in ["worker-a", "worker-b"] {
+                    std::thread::Builder::new()
+                        .name(name.to_string())
+                        .spawn_scoped(scope, || {
+                            for i in 0..3 {
+                                ScreenLogger::info(&printer, &format!("item {i}"));
+                            }
+                        })
+                        .unwrap();
+                }
+            });
+        });
+
+        assert_eq!(out.matches("[worker-a] INFO: item").count(), 3);
+        assert_eq!(out.matches("[worker-b] INFO: item").count(), 3);
+    }
+
+    #[test]
+    fn thread_prefix_is_off_at_normal_verbosity() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_show_thread(true);
+
+        let out = capture_stdout(|| ScreenLogger::info(&printer, "hello"));
+
+        assert_eq!(out.trim_end(), "INFO: hello");
+    }
+
+    #[test]
+    fn json_events_get_a_thread_key() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Verbose),
+            LogFormat::Json,
+            Verbosity::Verbose,
+        );
+        printer.set_show_thread(true);
+
+        let out = capture_stdout(|| {
+            std::thread::scope(|scope| {
+                std::thread::Builder::new()
+                    .name("json-worker".to_string())
+                    .spawn_scoped(scope, || printer.info("hello").emit())
+                    .unwrap();
+            });
+        });
+
+        let v: Value = serde_json::from_str(out.trim()).expect("Expected valid JSON");
+        assert_eq!(v["thread"], "json-worker");
+    }
+}
+
+mod text_timestamp_behavior_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn fixed_timestamp_prefixes_text_lines_when_enabled() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_timestamp_mode(TimestampMode::Fixed("10:30:00"));
+
+        let default = capture_stdout(|| ScreenLogger::ok(&printer, "saved"));
+        printer
+            .set_text_timestamp(Some(TimestampFormat::Time))
+            .unwrap();
+        let stamped = capture_stdout(|| ScreenLogger::ok(&printer, "saved"));
+
+        assert_eq!(default.trim_end(), "OK: saved");
+        assert_eq!(stamped.trim_end(), "10:30:00 OK: saved");
+    }
+
+    #[test]
+    fn invalid_custom_pattern_is_rejected() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let result = printer.set_text_timestamp(Some(TimestampFormat::Custom("%H:%Q")));
+
+        assert!(result.is_err());
+        assert_eq!(*printer.text_timestamp.lock().unwrap(), None);
+        assert_eq!(
+            capture_stdout(|| ScreenLogger::ok(&printer, "saved")),
+            "OK: saved\n"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+mod line_width_behavior_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn truncates_plain_ascii_line() {
+        assert_eq!(truncate_to_width("hello world", 8), "hello w…");
+        assert_eq!(truncate_to_width("short", 8), "short");
+    }
+
+    #[test]
+    fn ansi_escapes_do_not_count_toward_width() {
+        let colored = "\x1b[32m✔\x1b[0m deployed";
+        assert_eq!(display_width(colored), 10);
+        assert_eq!(truncate_to_width(colored, 10), colored);
+        assert_eq!(
+            truncate_to_width(colored, 6),
+            "\x1b[32m✔\x1b[0m dep…\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn wide_cjk_chars_count_as_two_columns() {
+        let cjk = "日本語のテキスト";
+        assert_eq!(display_width(cjk), 16);
+        // 6 columns = 2 wide chars + 1 for the ellipsis, never half a char
+        assert_eq!(truncate_to_width(cjk, 6), "日本…");
+    }
+
+    #[test]
+    fn printer_truncates_text_lines_but_not_json() {
+        let text = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        text.set_max_line_width(Some(12));
+
+        let out = capture_stdout(|| ScreenLogger::info(&text, "a very long message"));
+        assert_eq!(out.trim_end(), "INFO: a ver…");
+
+        let json = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Json,
+            Verbosity::Normal,
+        );
+        json.set_max_line_width(Some(12));
+
+        let out = capture_stdout(|| ScreenLogger::info(&json, "a very long message"));
+        assert!(out.contains("INFO: a very long message"));
+    }
+}
@@ -0,0 +1,68 @@
+mod link_paths_tests {
+    use crate::banner::set_hyperlinks;
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serial_test::serial;
+
+    fn err_output(link: bool, hyperlinks: bool) -> String {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_link_paths(link);
+
+        set_hyperlinks(Some(hyperlinks));
+        let err = capture_stderr(|| ScreenLogger::err(&printer, "failed at src/main.rs:10."));
+        set_hyperlinks(None);
+
+        // Only the rendered line; a tracing subscriber may echo it after.
+        err.lines().next().unwrap_or_default().to_string()
+    }
+
+    #[test]
+    #[serial]
+    fn locations_are_wrapped_in_osc8_links_when_enabled() {
+        let err = err_output(true, true);
+
+        let url = std::path::absolute("src/main.rs").unwrap();
+        let expected = format!(
+            "ERR: failed at \x1b]8;;file://{}\x1b\\src/main.rs:10\x1b]8;;\x1b\\.",
+            url.display()
+        );
+        assert_eq!(err, expected);
+    }
+
+    #[test]
+    #[serial]
+    fn locations_stay_plain_when_disabled() {
+        assert_eq!(err_output(false, true), "ERR: failed at src/main.rs:10.");
+    }
+
+    #[test]
+    #[serial]
+    fn locations_stay_plain_without_hyperlink_support() {
+        assert_eq!(err_output(true, false), "ERR: failed at src/main.rs:10.");
+    }
+
+    #[test]
+    #[serial]
+    fn addresses_are_not_mistaken_for_paths() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_link_paths(true);
+
+        set_hyperlinks(Some(true));
+        let err = capture_stderr(|| {
+            ScreenLogger::err(&printer, "cannot reach 127.0.0.1:8080 or localhost:80");
+        });
+        set_hyperlinks(None);
+
+        assert!(!err.contains("\x1b]8"), "{err:?}");
+    }
+}
@@ -0,0 +1,119 @@
+mod markdown_report_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use std::{sync::Arc, time::Duration};
+
+    fn printer() -> (Printer<MockLogger, SimpleBackend>, Arc<MockClock>) {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        let clock = Arc::new(MockClock::new());
+        printer.set_clock(clock.clone());
+        printer.set_markdown_journal(true);
+        (printer, clock)
+    }
+
+    #[test]
+    fn tasks_become_headings_and_steps_bullets() {
+        let (printer, clock) = printer();
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                ScreenLogger::intro(&printer, "build");
+                ScreenLogger::step(&printer, "compile");
+                ScreenLogger::step(&printer, "link");
+                clock.advance(Duration::from_millis(40));
+                ScreenLogger::outro(&printer, "built");
+
+                ScreenLogger::intro(&printer, "test");
+                ScreenLogger::step(&printer, "unit");
+                ScreenLogger::err(&printer, "3 tests failed");
+                clock.advance(Duration::from_millis(5));
+                ScreenLogger::done(&printer);
+            });
+        });
+
+        assert_eq!(
+            printer.render_markdown_report(),
+            "## Run report\n\
+             \n### build (40ms)\n\n- compile\n- link\n\
+             \n### test (5ms)\n\n- unit\n\n```text\n3 tests failed\n```\n"
+        );
+    }
+
+    #[test]
+    fn nested_tasks_go_one_level_deeper() {
+        let (printer, _clock) = printer();
+
+        let _ = capture_stdout(|| {
+            ScreenLogger::intro(&printer, "deploy");
+            ScreenLogger::intro(&printer, "upload");
+            ScreenLogger::step(&printer, "assets");
+        });
+
+        let md = printer.render_markdown_report();
+        assert!(md.contains("\n### deploy (unfinished)\n"), "{md}");
+        assert!(
+            md.contains("\n#### upload (unfinished)\n\n- assets\n"),
+            "{md}"
+        );
+    }
+
+    #[test]
+    fn errors_outside_tasks_get_their_own_section() {
+        let (printer, _clock) = printer();
+
+        let _ = capture_stderr(|| ScreenLogger::err(&printer, "config missing"));
+
+        let mut out = Vec::new();
+        printer.write_markdown_report(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "## Run report\n\n### Errors\n\n```text\nconfig missing\n```\n"
+        );
+    }
+
+    #[test]
+    fn journal_is_off_by_default() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                ScreenLogger::intro(&printer, "build");
+                ScreenLogger::step(&printer, "compile");
+                ScreenLogger::err(&printer, "failed");
+                ScreenLogger::done(&printer);
+            });
+        });
+
+        assert_eq!(
+            printer.render_markdown_report(),
+            "## Run report\n\n_No tasks were run._\n"
+        );
+    }
+
+    #[test]
+    fn structured_errors_are_journaled() {
+        let (printer, _clock) = printer();
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                printer.emit_event(LogLevel::Error, "disk full", &Fields::new());
+                printer.emit_event(LogLevel::Warn, "disk nearly full", &Fields::new());
+            });
+        });
+
+        assert_eq!(
+            printer.render_markdown_report(),
+            "## Run report\n\n### Errors\n\n```text\ndisk full\n```\n"
+        );
+    }
+}
@@ -1,3 +1,26 @@
+pub mod ansi;
+pub mod batch_backend;
+pub mod capture_backend;
+pub mod child_logger;
+pub mod concurrent_output;
+pub mod csv_format;
+pub mod heading;
 pub mod json_format;
+pub mod line_prefix;
+pub mod line_width;
+pub mod link_paths;
+pub mod markdown_report;
+#[cfg(feature = "modern")]
+pub mod modern_fallback;
+pub mod notes;
+pub mod once;
 pub mod printer_behavior;
+pub mod prompt;
+pub mod report;
+pub mod rule;
+pub mod sanitize;
+pub mod step_glyph;
+pub mod stream_policy;
+pub mod strip_ansi;
 pub mod structured_fields;
+pub mod table;
@@ -0,0 +1,142 @@
+mod modern_fallback_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use std::io;
+
+    fn not_a_tty(_: &str) -> io::Result<()> {
+        Err(io::Error::other("not a terminal"))
+    }
+
+    #[test]
+    fn failed_render_falls_back_to_plain_text() {
+        let backend = ModernBackend::new();
+
+        let out = capture_stdout(|| {
+            backend
+                .render_or_fallback(LogLevel::Info, "deploy finished", not_a_tty)
+                .unwrap();
+        });
+
+        assert_eq!(out, "deploy finished\n");
+    }
+
+    #[test]
+    fn fallback_routes_by_level() {
+        let backend = ModernBackend::new();
+
+        let mut out = String::new();
+        let err = capture_stderr(|| {
+            out = capture_stdout(|| {
+                backend
+                    .render_or_fallback(LogLevel::Error, "deploy failed", not_a_tty)
+                    .unwrap();
+            });
+        });
+
+        assert_eq!(out, "");
+        assert_eq!(err, "deploy failed\n");
+    }
+
+    #[test]
+    fn disabled_fallback_propagates_the_error() {
+        let backend = ModernBackend::new().with_fallback(false);
+
+        let out = capture_stdout(|| {
+            let err = backend
+                .render_or_fallback(LogLevel::Info, "deploy finished", not_a_tty)
+                .unwrap_err();
+            assert_eq!(err.to_string(), "not a terminal");
+        });
+
+        assert_eq!(out, "");
+    }
+
+    #[test]
+    fn successful_render_prints_nothing_extra() {
+        let backend = ModernBackend::new();
+
+        let out = capture_stdout(|| {
+            backend
+                .render_or_fallback(LogLevel::Info, "quiet", |_| Ok(()))
+                .unwrap();
+        });
+
+        assert_eq!(out, "");
+    }
+}
+
+mod modern_progress_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn update(current: u64, total: Option<u64>, finished: bool) -> ProgressUpdate<'static> {
+        ProgressUpdate {
+            label: "Uploading",
+            current,
+            total,
+            finished,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: None,
+            elapsed: None,
+            bar_width: None,
+        }
+    }
+
+    #[test]
+    fn updates_reuse_one_bar_and_report_completion() {
+        let backend = ModernBackend::new();
+
+        let mut out = String::new();
+        let _ = capture_stderr(|| {
+            out = capture_stdout(|| {
+                for n in 1..=4 {
+                    backend.render_progress(&update(n, Some(5), false)).unwrap();
+                }
+                backend.render_progress(&update(5, Some(5), true)).unwrap();
+            });
+        });
+
+        assert_eq!(backend.bars_started(), 1);
+        assert!(out.contains("Uploading"), "{out:?}");
+        assert!(out.contains("(5/5, 100%) done"), "{out:?}");
+    }
+
+    #[test]
+    fn a_finished_bar_lets_the_next_progress_start_fresh() {
+        let backend = ModernBackend::new();
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                backend.render_progress(&update(3, None, false)).unwrap();
+                backend.render_progress(&update(7, None, true)).unwrap();
+                backend.render_progress(&update(1, Some(2), false)).unwrap();
+            });
+        });
+
+        assert_eq!(backend.bars_started(), 2);
+    }
+
+    #[test]
+    fn a_spinner_becomes_a_bar_once_the_total_is_known() {
+        let backend = ModernBackend::new();
+
+        let mut out = String::new();
+        let _ = capture_stderr(|| {
+            out = capture_stdout(|| {
+                backend.render_progress(&update(1, None, false)).unwrap();
+                backend.render_progress(&update(2, Some(4), false)).unwrap();
+                backend.render_progress(&update(3, Some(4), false)).unwrap();
+                backend.render_progress(&update(4, Some(4), true)).unwrap();
+            });
+        });
+
+        assert_eq!(backend.bars_started(), 2);
+        assert_eq!(out.matches("Uploading").count(), 1, "{out:?}");
+        assert!(out.contains("(4/4, 100%) done"), "{out:?}");
+    }
+}
@@ -0,0 +1,88 @@
+mod notes_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::{Value, json};
+
+    fn printer(format: LogFormat) -> Printer<MockLogger, SimpleBackend> {
+        make_printer(
+            MockLogger::new(Verbosity::Normal),
+            format,
+            Verbosity::Normal,
+        )
+    }
+
+    fn run_with_notes(printer: &Printer<MockLogger, SimpleBackend>) -> String {
+        capture_stdout(|| {
+            ScreenLogger::intro(printer, "build");
+            ScreenLogger::note(printer, "set CARGO_INCREMENTAL=1 for faster rebuilds");
+            ScreenLogger::outro(printer, "built");
+            ScreenLogger::note(printer, "3 warnings can be fixed with `cargo fix`");
+        })
+    }
+
+    #[test]
+    fn notes_are_printed_after_the_summary() {
+        let printer = printer(LogFormat::Text);
+        let during = run_with_notes(&printer);
+
+        let out = capture_stdout(|| printer.summary());
+
+        assert!(!during.contains("CARGO_INCREMENTAL"), "{during:?}");
+        let notes = &out[out.find("Notes:").expect("no notes section")..];
+        assert_eq!(
+            notes,
+            "Notes:\n  - set CARGO_INCREMENTAL=1 for faster rebuilds\n  - 3 warnings can be fixed with `cargo fix`\n"
+        );
+        assert!(out.find("Task summary:") < out.find("Notes:"));
+    }
+
+    #[test]
+    fn notes_are_flushed_once() {
+        let printer = printer(LogFormat::Text);
+        run_with_notes(&printer);
+
+        let _ = capture_stdout(|| printer.flush_notes());
+        let again = capture_stdout(|| printer.flush_notes());
+
+        assert_eq!(again, "");
+    }
+
+    #[test]
+    fn json_notes_are_an_array_in_output_and_report() {
+        let printer = printer(LogFormat::Json);
+        run_with_notes(&printer);
+        let expected = json!([
+            "set CARGO_INCREMENTAL=1 for faster rebuilds",
+            "3 warnings can be fixed with `cargo fix`",
+        ]);
+
+        let out = capture_stdout(|| printer.flush_notes());
+        let flushed: Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(flushed["message"], "notes");
+        assert_eq!(flushed["fields"]["notes"], expected);
+
+        let mut buf = Vec::new();
+        printer.write_report(&mut buf).unwrap();
+        let report: Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(report["notes"], expected);
+    }
+
+    #[test]
+    fn csv_notes_are_rows() {
+        let printer = printer(LogFormat::Csv);
+        run_with_notes(&printer);
+
+        let out = capture_stdout(|| printer.flush_notes());
+
+        assert!(!out.contains("Notes:"), "{out:?}");
+        assert!(
+            out.ends_with(
+                "info,set CARGO_INCREMENTAL=1 for faster rebuilds\n\
+                 info,3 warnings can be fixed with `cargo fix`\n"
+            ),
+            "{out:?}"
+        );
+    }
+}
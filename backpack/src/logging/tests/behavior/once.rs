@@ -0,0 +1,58 @@
+mod once_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn printer() -> Printer<MockLogger, SimpleBackend> {
+        make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        )
+    }
+
+    #[test]
+    fn warn_once_drops_repeats_of_a_key() {
+        let printer = printer();
+
+        let out = capture_stdout(|| {
+            for _ in 0..3 {
+                printer.warn_once("deprecated-x", "`x` is deprecated; use `y`");
+            }
+            printer.warn_once("deprecated-z", "`z` is deprecated");
+        });
+
+        assert_eq!(
+            out,
+            "WARN: `x` is deprecated; use `y`\nWARN: `z` is deprecated\n"
+        );
+    }
+
+    #[test]
+    fn error_once_shares_keys_with_warn_once() {
+        let printer = printer();
+
+        let err = capture_stderr(|| {
+            let _ = capture_stdout(|| printer.warn_once("config", "config is old"));
+            printer.error_once("config", "config is invalid");
+        });
+
+        // Warnings are also mirrored to tracing on stderr; only the error matters.
+        assert!(!err.contains("config is invalid"), "{err:?}");
+    }
+
+    #[test]
+    fn call_site_variant_keys_by_location() {
+        let printer = printer();
+
+        let out = capture_stdout(|| {
+            for _ in 0..3 {
+                printer.warn_once_here("from the loop");
+            }
+            printer.warn_once_here("from another line");
+        });
+
+        assert_eq!(out, "WARN: from the loop\nWARN: from another line\n");
+    }
+}
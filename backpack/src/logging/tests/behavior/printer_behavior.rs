@@ -83,6 +83,41 @@ mod verbosity_behavior_tests {
 
         assert!(predicates::str::contains("boom").eval(&err));
     }
+
+    #[test]
+    fn dim_threshold_hides_notes_below_verbose() {
+        let normal = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        normal.set_dim_min_verbosity(Verbosity::Verbose);
+
+        let out = capture_stdout(|| ScreenLogger::dim(&normal, "found .env"));
+        assert!(out.trim().is_empty());
+
+        let verbose = make_printer(
+            MockLogger::new(Verbosity::Verbose),
+            LogFormat::Text,
+            Verbosity::Verbose,
+        );
+        verbose.set_dim_min_verbosity(Verbosity::Verbose);
+
+        let out = capture_stdout(|| ScreenLogger::dim(&verbose, "found .env"));
+        assert!(predicates::str::contains("DIM: found .env").eval(&out));
+    }
+
+    #[test]
+    fn dim_shown_at_normal_by_default() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let out = capture_stdout(|| ScreenLogger::dim(&printer, "found .env"));
+        assert!(predicates::str::contains("DIM: found .env").eval(&out));
+    }
 }
 
 mod printing_behavior_tests {
@@ -182,3 +217,71 @@ mod printer_forwarding_tests {
         assert!(predicates::str::contains("processing").eval(&out));
     }
 }
+
+mod scoped_verbosity_tests {
+    use super::*;
+
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn debug_shows_only_inside_the_closure() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+
+        let inside = capture_stderr(|| {
+            printer.with_verbosity(Verbosity::Trace, || {
+                printer.debug("inside the block");
+            });
+        });
+        let after = capture_stderr(|| {
+            printer.debug("after the block");
+        });
+
+        assert!(inside.contains("inside the block"), "{inside:?}");
+        assert!(!after.contains("after the block"), "{after:?}");
+        assert_eq!(printer.verbosity(), Verbosity::Normal);
+    }
+
+    #[test]
+    #[serial]
+    fn verbosity_is_restored_after_a_panic() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            printer.with_verbosity(Verbosity::Trace, || panic!("boom"));
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(printer.verbosity(), Verbosity::Normal);
+        assert!(!config::isverbose());
+    }
+
+    #[test]
+    #[serial]
+    fn set_verbosity_stays_with_its_printer() {
+        let verbose = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        let normal = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        verbose.set_verbosity(Verbosity::Verbose);
+
+        let err = capture_stderr(|| {
+            verbose.debug("from verbose");
+            normal.debug("from normal");
+        });
+
+        assert!(!config::isverbose());
+        assert!(err.contains("from verbose"), "{err:?}");
+        assert!(!err.contains("from normal"), "{err:?}");
+    }
+
+    #[test]
+    #[serial]
+    fn enabled_follows_set_verbosity() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        assert!(!printer.enabled(LogLevel::Debug));
+
+        printer.set_verbosity(Verbosity::Verbose);
+
+        assert!(printer.enabled(LogLevel::Debug));
+        assert!(!config::isverbose());
+    }
+}
@@ -0,0 +1,58 @@
+mod prompt_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use std::io::Cursor;
+    use test_case::test_case;
+
+    #[test_case("y\n", false, true ; "y")]
+    #[test_case("YES\n", false, true ; "yes uppercase")]
+    #[test_case("n\n", true, false ; "n")]
+    #[test_case("\n", true, true ; "empty uses default yes")]
+    #[test_case("maybe\n", false, false ; "unrecognized uses default no")]
+    fn confirm_parses_answer(answer: &str, default: bool, expected: bool) {
+        let mut out = Vec::new();
+        let got = read_confirm(&mut Cursor::new(answer), &mut out, "Continue?", default).unwrap();
+
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn confirm_shows_default_hint() {
+        let mut out = Vec::new();
+        read_confirm(&mut Cursor::new("y\n"), &mut out, "Deploy?", false).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "Deploy? [y/N] ");
+    }
+
+    #[test]
+    fn input_strips_line_ending() {
+        let mut out = Vec::new();
+        let got = read_input(&mut Cursor::new("prod-eu\r\n"), &mut out, "Region:").unwrap();
+
+        assert_eq!(got, "prod-eu");
+    }
+
+    #[test]
+    fn json_mode_returns_defaults_without_blocking() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_confirm_default(true);
+        printer.set_input_default("staging");
+
+        assert!(ScreenLogger::confirm(&printer, "Deploy?").unwrap());
+        assert_eq!(ScreenLogger::input(&printer, "Region:").unwrap(), "staging");
+    }
+
+    #[test]
+    fn quiet_mode_returns_defaults_without_blocking() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Quiet),
+            LogFormat::Text,
+            Verbosity::Quiet,
+        );
+
+        assert!(!ScreenLogger::confirm(&printer, "Deploy?").unwrap());
+        assert_eq!(ScreenLogger::input(&printer, "Region:").unwrap(), "");
+    }
+}
@@ -0,0 +1,194 @@
+mod report_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    #[test]
+    fn report_has_counts_and_completed_tasks() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                ScreenLogger::intro(&printer, "build");
+                ScreenLogger::ok(&printer, "compiled");
+                ScreenLogger::ok(&printer, "linked");
+                ScreenLogger::warn(&printer, "slow link");
+                ScreenLogger::outro(&printer, "built");
+                ScreenLogger::intro(&printer, "test");
+                ScreenLogger::err(&printer, "1 failure");
+                ScreenLogger::outro(&printer, "tested");
+            });
+        });
+
+        let mut buf = Vec::new();
+        printer.write_report(&mut buf).unwrap();
+        let report: Value = serde_json::from_slice(&buf).unwrap();
+
+        assert_eq!(report["counts"]["ok"], 2);
+        assert_eq!(report["counts"]["warn"], 1);
+        assert_eq!(report["counts"]["err"], 1);
+        assert!(report["duration_ms"].is_u64());
+
+        let mut tasks: Vec<&str> = report["tasks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["task"].as_str().unwrap())
+            .collect();
+        tasks.sort_unstable();
+        assert_eq!(tasks, ["build", "test"]);
+    }
+
+    #[test]
+    fn hidden_messages_still_count() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Quiet),
+            LogFormat::Text,
+            Verbosity::Quiet,
+        );
+
+        let _ = capture_stdout(|| ScreenLogger::ok(&printer, "done"));
+
+        assert_eq!(
+            printer.counts(),
+            EventCounts {
+                ok: 1,
+                warn: 0,
+                err: 0,
+                info: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn structured_events_count_too() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Json,
+            Verbosity::Normal,
+        );
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                printer.info("cache warmed").field("entries", 12).emit();
+                printer.warn("slow query").emit();
+                printer.error("connection lost").emit();
+                printer.debug("not counted").emit();
+                printer.info_with_fields("legacy", &Fields::new());
+            });
+        });
+
+        assert_eq!(
+            printer.counts(),
+            EventCounts {
+                ok: 0,
+                warn: 1,
+                err: 1,
+                info: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn take_counts_resets_between_samples() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        let zero = EventCounts {
+            ok: 0,
+            warn: 0,
+            err: 0,
+            info: 0,
+        };
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                ScreenLogger::err(&printer, "disk full");
+                ScreenLogger::err(&printer, "retry failed");
+                ScreenLogger::info(&printer, "retrying later");
+            });
+        });
+        assert_eq!(
+            printer.take_counts(),
+            EventCounts {
+                err: 2,
+                info: 1,
+                ..zero
+            }
+        );
+
+        let _ = capture_stdout(|| ScreenLogger::warn(&printer, "queue backing up"));
+        assert_eq!(printer.take_counts(), EventCounts { warn: 1, ..zero });
+        assert_eq!(printer.counts(), zero);
+    }
+}
+
+mod exit_code_tests {
+    use crate::logging::internal::globals::reset_logger;
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serial_test::serial;
+
+    fn printer() -> Printer<MockLogger, SimpleBackend> {
+        make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        )
+    }
+
+    #[test]
+    #[serial]
+    fn logged_error_yields_exit_code_one() {
+        let printer = printer();
+        let _ = capture_stderr(|| ScreenLogger::err(&printer, "disk full"));
+        assert!(printer.had_errors());
+
+        set_logger(printer);
+        assert_eq!(exit_code(), 1);
+        reset_logger();
+    }
+
+    #[test]
+    fn error_events_count_and_survive_take_counts() {
+        let printer = printer();
+        let _ = capture_stderr(|| printer.error("disk full").field("free", 0).emit());
+        assert!(printer.had_errors());
+
+        let _ = printer.take_counts();
+        assert!(printer.had_errors());
+    }
+
+    #[test]
+    fn direct_error_emits_count_as_errors() {
+        let printer = printer();
+        let _ = capture_stderr(|| printer.emit_text(LogLevel::Error, "disk full"));
+        assert!(printer.had_errors());
+    }
+
+    #[test]
+    #[serial]
+    fn clean_run_yields_exit_code_zero() {
+        let printer = printer();
+        let _ = capture_stdout(|| {
+            ScreenLogger::ok(&printer, "built");
+            ScreenLogger::warn(&printer, "slow disk");
+        });
+        assert!(!printer.had_errors());
+
+        set_logger(printer);
+        assert_eq!(exit_code(), 0);
+        reset_logger();
+        assert_eq!(exit_code(), 0);
+    }
+}
@@ -0,0 +1,89 @@
+mod rule_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unlabeled_rule_spans_configured_width() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_max_line_width(Some(24));
+
+        let out = capture_stdout(|| ScreenLogger::rule(&printer, None));
+
+        let line = out.trim_end_matches('\n');
+        let fill = glyph("─", "-");
+        assert_eq!(line, fill.repeat(24));
+    }
+
+    #[test]
+    fn labeled_rule_centers_label() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_max_line_width(Some(24));
+
+        let out = capture_stdout(|| ScreenLogger::rule(&printer, Some("Section")));
+
+        let line = out.trim_end_matches('\n');
+        let fill = glyph("─", "-");
+        assert_eq!(
+            line,
+            format!("{} Section {}", fill.repeat(7), fill.repeat(8))
+        );
+        assert_eq!(display_width(line), 24);
+    }
+
+    #[test]
+    fn rule_follows_pinned_terminal_width() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_terminal_width(Some(40));
+
+        let out = capture_stdout(|| ScreenLogger::rule(&printer, None));
+
+        assert_eq!(out.trim_end_matches('\n'), glyph("─", "-").repeat(40));
+        assert_eq!(printer.terminal_width(), 40);
+    }
+
+    #[test]
+    fn pinned_width_feeds_width_detection() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_terminal_width(Some(12));
+        printer.detect_max_line_width();
+
+        let out = capture_stdout(|| ScreenLogger::info(&printer, "a line that is too long"));
+
+        assert_eq!(display_width(out.trim_end_matches('\n')), 12);
+    }
+
+    #[test]
+    fn json_rule_emits_marker() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| ScreenLogger::rule(&printer, Some("Section")));
+
+        let value: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "level": "info",
+                "message": "Section",
+                "fields": {"rule": "Section"},
+            })
+        );
+    }
+
+    #[test]
+    fn json_rule_follows_key_map_and_level_labels() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_json_key_map(JsonKeyMap {
+            level: "severity".into(),
+            ..JsonKeyMap::default()
+        });
+        printer.set_level_labels([(LogLevel::Info, "INFO".to_string())].into());
+
+        let out = capture_stdout(|| ScreenLogger::rule(&printer, None));
+
+        let value: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(value["severity"], "INFO");
+        assert_eq!(value["fields"]["rule"], "");
+    }
+}
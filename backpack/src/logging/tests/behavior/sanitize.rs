@@ -0,0 +1,66 @@
+mod sanitize_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn text_printer() -> Printer<MockLogger, SimpleBackend> {
+        make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        )
+    }
+
+    #[test]
+    fn clear_screen_sequence_is_escaped() {
+        let printer = text_printer();
+
+        let out = capture_stdout(|| ScreenLogger::info(&printer, "user said \x1b[2J hi"));
+
+        assert_eq!(out, "INFO: user said \\x1b[2J hi\n");
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn newlines_and_tabs_pass_through() {
+        assert_eq!(escape_control("a\tb\nc"), "a\tb\nc");
+        assert_eq!(escape_control("bell\x07"), "bell\\x07");
+    }
+
+    #[test]
+    fn event_fields_are_escaped_too() {
+        let printer = text_printer();
+
+        let out = capture_stdout(|| {
+            printer
+                .info("login\x1b[2J")
+                .field("name", "\x1b]0;pwned\x07")
+                .emit();
+        });
+
+        assert!(out.contains("login\\x1b[2J"), "{out:?}");
+        assert!(out.contains("name=\\x1b]0;pwned\\x07"), "{out:?}");
+    }
+
+    #[test]
+    fn sanitizing_can_be_turned_off() {
+        let printer = text_printer();
+        printer.set_sanitize(false);
+
+        let out = capture_stdout(|| ScreenLogger::warn(&printer, "raw \x1b[2J"));
+
+        assert_eq!(out, "WARN: raw \x1b[2J\n");
+    }
+
+    #[test]
+    fn json_relies_on_serde_escaping() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| printer.info("x\x1b[2J").emit());
+
+        assert!(!out.contains('\x1b'), "{out:?}");
+        let event: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(event["message"], "x\x1b[2J");
+    }
+}
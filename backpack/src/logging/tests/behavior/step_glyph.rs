@@ -0,0 +1,51 @@
+mod step_glyph_rotation_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serial_test::serial;
+
+    /// The glyph in front of each step line.
+    fn step_glyphs(tty: bool) -> Vec<String> {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Verbose);
+        printer.set_ansi(false);
+        printer.set_tty(Some(tty));
+        printer.set_step_glyph_rotation(true);
+
+        set_glyph_mode(GlyphMode::Unicode);
+        let out = capture_stdout(|| {
+            ScreenLogger::intro(&printer, "deploy");
+            ScreenLogger::step(&printer, "build");
+            ScreenLogger::step(&printer, "upload");
+            ScreenLogger::intro(&printer, "verify");
+            ScreenLogger::step(&printer, "ping");
+            ScreenLogger::outro(&printer, "verified");
+            ScreenLogger::step(&printer, "notify");
+            ScreenLogger::outro(&printer, "deployed");
+        });
+        set_glyph_mode(GlyphMode::Auto);
+
+        out.lines()
+            .map(str::trim_start)
+            .filter(|l| {
+                ["build", "upload", "ping", "notify"]
+                    .iter()
+                    .any(|s| l.ends_with(s))
+            })
+            .map(|l| l.split_whitespace().next().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    #[serial]
+    fn steps_keep_the_static_glyph_without_a_tty() {
+        assert_eq!(step_glyphs(false), ["⠿", "⠿", "⠿", "⠿"]);
+    }
+
+    #[test]
+    #[serial]
+    fn steps_advance_the_spinner_per_task_on_a_tty() {
+        // The nested task starts its own rotation; the outer one resumes after.
+        assert_eq!(step_glyphs(true), ["⠋", "⠙", "⠋", "⠹"]);
+    }
+}
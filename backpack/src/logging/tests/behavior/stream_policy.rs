@@ -0,0 +1,61 @@
+mod stream_policy_behavior_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use serial_test::serial;
+    use test_case::test_case;
+
+    /// Returns (stdout, stderr) for one info and one error event.
+    fn capture_info_and_error(policy: StreamPolicy) -> (String, String) {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_stream_policy(policy);
+
+        let mut err = String::new();
+        let out = capture_stdout(|| {
+            err = capture_stderr(|| {
+                ScreenLogger::info(&printer, "an info");
+                ScreenLogger::err(&printer, "an error");
+            });
+        });
+
+        (out, err)
+    }
+
+    #[test_case(StreamPolicy::InfoToStdout, true, false ; "info to stdout")]
+    #[test_case(StreamPolicy::AllToStderr, false, false ; "all to stderr")]
+    #[test_case(StreamPolicy::AllToStdout, true, true ; "all to stdout")]
+    #[serial]
+    fn routes_info_and_error_per_policy(
+        policy: StreamPolicy,
+        info_on_stdout: bool,
+        error_on_stdout: bool,
+    ) {
+        let (out, err) = capture_info_and_error(policy);
+        // Exact lines: the tracing mirror of `err` also lands on stderr.
+        let has = |stream: &str, line: &str| stream.lines().any(|l| l == line);
+
+        assert_eq!(has(&out, "INFO: an info"), info_on_stdout);
+        assert_eq!(has(&err, "INFO: an info"), !info_on_stdout);
+        assert_eq!(has(&out, "ERR: an error"), error_on_stdout);
+        assert_eq!(has(&err, "ERR: an error"), !error_on_stdout);
+    }
+
+    #[test]
+    #[serial]
+    fn policy_is_per_printer() {
+        let has = |stream: &str, line: &str| stream.lines().any(|l| l == line);
+
+        let (out, err) = capture_info_and_error(StreamPolicy::AllToStdout);
+        assert!(has(&out, "ERR: an error"), "{out:?}");
+        assert!(!has(&err, "ERR: an error"), "{err:?}");
+
+        let (out, err) = capture_info_and_error(StreamPolicy::default());
+        assert!(has(&out, "INFO: an info"), "{out:?}");
+        assert!(has(&err, "ERR: an error"), "{err:?}");
+        assert_eq!(stream_policy(), StreamPolicy::default());
+    }
+}
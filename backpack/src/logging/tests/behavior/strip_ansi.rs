@@ -0,0 +1,34 @@
+mod strip_ansi_tests {
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn removes_color_codes() {
+        assert_eq!(strip_ansi("\x1b[1;32m✔\x1b[0m deployed"), "✔ deployed");
+        assert_eq!(strip_ansi("plain text"), "plain text");
+    }
+
+    #[test]
+    fn removes_cursor_moves_and_line_clears() {
+        assert_eq!(
+            strip_ansi("\r\x1b[2KCopy 5/10\x1b[1A\x1b[10G"),
+            "\rCopy 5/10"
+        );
+    }
+
+    #[test]
+    fn removes_osc8_hyperlinks_but_keeps_their_text() {
+        let link = "see \x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\ here";
+        assert_eq!(strip_ansi(link), "see docs here");
+        assert_eq!(strip_ansi("\x1b]0;title\x07after"), "after");
+    }
+
+    #[test]
+    fn drops_truncated_trailing_sequences() {
+        assert_eq!(strip_ansi("done\x1b["), "done");
+        assert_eq!(strip_ansi("done\x1b[38;5"), "done");
+        assert_eq!(strip_ansi("done\x1b]8;;https://exa"), "done");
+        assert_eq!(strip_ansi("done\x1b"), "done");
+    }
+}
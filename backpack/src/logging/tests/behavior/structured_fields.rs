@@ -10,7 +10,8 @@ mod structured_fields_tests {
     #[test]
     fn json_mode_emits_structured_fields_on_drop_snapshot() {
         let logger = MockLogger::new(Verbosity::Normal);
-        let printer = Printer::new(logger, SimpleBackend, LogFormat::Json, Verbosity::Normal);
+        let printer = Printer::new(logger, SimpleBackend, LogFormat::Json, Verbosity::Normal)
+            .with_timestamp_mode(TimestampMode::Disabled);
 
         let out = capture_stdout(|| {
             printer
@@ -204,7 +205,8 @@ mod structured_fields_tests {
             SimpleBackend,
             LogFormat::Json,
             Verbosity::Normal,
-        );
+        )
+        .with_timestamp_mode(TimestampMode::Disabled);
 
         let text_out = capture_stdout(|| {
             printer_text
@@ -252,3 +254,472 @@ mod structured_fields_tests {
         assert_snapshot!(out);
     }
 }
+
+mod redaction_tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn redacted_keys_are_masked_in_json() {
+        let logger = MockLogger::new(Verbosity::Normal);
+        let printer = Printer::new(logger, SimpleBackend, LogFormat::Json, Verbosity::Normal);
+        printer.add_redacted_key("Password");
+        printer.add_redacted_key("*_token");
+
+        let out = capture_stdout(|| {
+            printer
+                .info("Login")
+                .field("user", "alice")
+                .field("password", "hunter2")
+                .field("api_token", "abc");
+        });
+
+        let v: Value = serde_json::from_str(out.trim()).expect("Expected valid JSON");
+        assert_eq!(v["fields"]["password"], "***");
+        assert_eq!(v["fields"]["api_token"], "***");
+        assert_eq!(v["fields"]["user"], "alice");
+    }
+
+    #[test]
+    fn redacted_keys_are_masked_in_text() {
+        let logger = MockLogger::new(Verbosity::Normal);
+        let printer = Printer::new(logger, SimpleBackend, LogFormat::Text, Verbosity::Normal);
+        printer.add_redacted_key("password");
+        printer.add_redacted_key("*_token");
+
+        let out = capture_stdout(|| {
+            printer
+                .info("Login")
+                .field("user", "alice")
+                .field("PASSWORD", "hunter2")
+                .field("api_token", "abc");
+        });
+
+        assert!(out.contains("PASSWORD=***"));
+        assert!(out.contains("api_token=***"));
+        assert!(out.contains("user=alice"));
+        assert!(!out.contains("hunter2"));
+        assert!(!out.contains("abc"));
+    }
+}
+
+mod run_id_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    #[test]
+    fn generated_run_id_is_shared_across_events() {
+        let logger = MockLogger::new(Verbosity::Normal);
+        let printer = Printer::new(logger, SimpleBackend, LogFormat::Json, Verbosity::Normal);
+        printer.set_run_id(None);
+
+        let out = capture_stdout(|| {
+            printer.info("first").emit();
+            printer.warn("second").field("attempt", 2).emit();
+        });
+
+        let ids: Vec<Value> = out
+            .lines()
+            .map(|l| serde_json::from_str::<Value>(l).unwrap()["fields"]["run_id"].clone())
+            .collect();
+
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0], ids[1]);
+        assert_eq!(ids[0].as_str().map(str::len), Some(8));
+        assert_eq!(ids[0].as_str(), printer.run_id().as_deref());
+    }
+
+    #[test]
+    fn explicit_run_id_prefixes_text_lines() {
+        let logger = MockLogger::new(Verbosity::Normal);
+        let printer = Printer::new(logger, SimpleBackend, LogFormat::Text, Verbosity::Normal);
+        printer.set_run_id(Some("req-42".to_string()));
+
+        let tagged = capture_stdout(|| ScreenLogger::ok(&printer, "saved"));
+        printer.clear_run_id();
+        let plain = capture_stdout(|| ScreenLogger::ok(&printer, "saved"));
+
+        assert_eq!(tagged.trim_end(), "[req-42] OK: saved");
+        assert_eq!(plain.trim_end(), "OK: saved");
+    }
+}
+
+mod event_id_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    fn ids(enabled: bool) -> Vec<Value> {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_event_ids(enabled);
+
+        let out = capture_stdout(|| {
+            printer.info("first").emit();
+            printer.info("first").emit();
+        });
+
+        out.lines()
+            .map(|l| serde_json::from_str::<Value>(l).unwrap()["id"].clone())
+            .collect()
+    }
+
+    #[test]
+    fn enabled_ids_are_distinct_uuids() {
+        let ids = ids(true);
+
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        for id in &ids {
+            let id = id.as_str().expect("missing id");
+            let groups: Vec<usize> = id.split('-').map(str::len).collect();
+            assert_eq!(groups, [8, 4, 4, 4, 12], "{id}");
+            assert_eq!(&id[14..15], "4", "not a v4 uuid: {id}");
+        }
+    }
+
+    #[test]
+    fn disabled_events_have_no_id() {
+        assert_eq!(ids(false), [Value::Null, Value::Null]);
+    }
+}
+
+mod source_location_tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn json_event_carries_call_site() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_show_location(true);
+
+        let out = capture_stdout(|| {
+            printer.info("located").field("user_id", 7).emit();
+        });
+
+        let event: Value = serde_json::from_str(out.trim()).unwrap();
+        let file = event["fields"]["file"].as_str().unwrap();
+        let line: u32 = event["fields"]["line"].as_str().unwrap().parse().unwrap();
+
+        assert!(file.ends_with("structured_fields.rs"), "file: {file}");
+        assert!(line > 0);
+        assert_eq!(event["fields"]["user_id"], "7");
+    }
+
+    #[test]
+    fn text_event_appends_call_site() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_show_location(true);
+
+        let out = capture_stdout(|| {
+            printer.info("located").emit();
+        });
+
+        assert!(out.contains("located at "), "got: {out}");
+        assert!(out.contains("structured_fields.rs:"), "got: {out}");
+    }
+
+    #[test]
+    fn location_is_off_by_default() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            printer.info("plain").emit();
+        });
+
+        assert!(!out.contains("\"file\""));
+    }
+}
+
+mod target_tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn json_event_carries_target_field() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            printer.info("x").target("db").emit();
+        });
+
+        let event: Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(event["fields"]["target"], "db");
+        assert_eq!(event["message"], "x");
+    }
+
+    #[test]
+    fn text_event_is_prefixed_with_target() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let out = capture_stdout(|| {
+            printer
+                .warn("slow query")
+                .target("db")
+                .field("ms", 900)
+                .emit();
+        });
+
+        assert!(out.contains("[db] slow query"), "got: {out}");
+        assert!(!out.contains("target="), "got: {out}");
+    }
+
+    #[test]
+    fn child_tag_and_target_combine() {
+        let printer = std::sync::Arc::new(make_printer(
+            SimpleLogger,
+            LogFormat::Json,
+            Verbosity::Normal,
+        ));
+        let child = printer.child("worker");
+
+        let out = capture_stdout(|| {
+            LogEvent::new(&child, LogLevel::Info, "ready")
+                .target("cache")
+                .emit();
+        });
+
+        let event: Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(event["fields"]["target"], "cache");
+        assert_eq!(event["message"], "[worker] ready");
+    }
+}
+
+mod error_chain_tests {
+    use super::*;
+    use anyhow::Context;
+    use serde_json::Value;
+
+    fn failing_read() -> anyhow::Result<()> {
+        Err(std::io::Error::other("connection reset"))
+            .context("reading config")
+            .context("starting server")
+    }
+
+    #[test]
+    fn anyhow_context_layers_appear_in_event() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        let err = failing_read().unwrap_err();
+
+        let out = capture_stderr(|| {
+            printer.error_anyhow(&err);
+        });
+
+        let event: Value = out
+            .lines()
+            .find_map(|l| serde_json::from_str(l).ok())
+            .expect("no JSON event");
+        assert_eq!(event["level"], "error");
+        assert_eq!(event["message"], "starting server");
+        assert_eq!(
+            event["fields"]["error_chain"],
+            "starting server: reading config: connection reset"
+        );
+    }
+
+    #[test]
+    fn error_without_source_has_no_chain_field() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        let err = std::io::Error::other("disk full");
+
+        let out = capture_stderr(|| {
+            printer.error_source(&err);
+        });
+
+        let event: Value = out
+            .lines()
+            .find_map(|l| serde_json::from_str(l).ok())
+            .expect("no JSON event");
+        assert_eq!(event["message"], "disk full");
+        assert!(event.get("fields").is_none());
+    }
+}
+
+mod field_order_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn text_mode_keeps_insertion_order() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let out = capture_stdout(|| {
+            printer
+                .info("Batch done")
+                .field("processed", 1250)
+                .field("failed", 23)
+                .field("skipped", 5)
+                .emit();
+        });
+
+        let keys: Vec<&str> = out
+            .split("\x1b[2m")
+            .skip(1)
+            .filter_map(|f| f.split('=').next())
+            .collect();
+        assert_eq!(keys, ["processed", "failed", "skipped"]);
+    }
+
+    #[test]
+    fn reinserting_a_key_keeps_its_position() {
+        let mut fields = Fields::new();
+        fields.insert("b".into(), "1".into());
+        fields.insert("a".into(), "2".into());
+
+        assert_eq!(fields.insert("b".into(), "3".into()), Some("1".to_string()));
+        assert_eq!(
+            fields.iter().collect::<Vec<_>>(),
+            [
+                (&"b".to_string(), &"3".to_string()),
+                (&"a".to_string(), &"2".to_string())
+            ]
+        );
+    }
+}
+
+mod json_value_field_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::{Value, json};
+
+    #[test]
+    fn nested_json_field_round_trips_as_object() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        let request = json!({"method": "GET", "path": "/"});
+
+        let out = capture_stdout(|| {
+            printer
+                .info("handled")
+                .field_json("request", request.clone())
+                .field("status", 200)
+                .emit();
+        });
+
+        let event: Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(event["fields"]["request"], request);
+        assert_eq!(event["fields"]["status"], "200");
+    }
+
+    #[test]
+    fn text_mode_prints_compact_json() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let out = capture_stdout(|| {
+            printer
+                .info("handled")
+                .field_json("tags", json!(["a", "b"]))
+                .emit();
+        });
+
+        assert!(out.contains(r#"tags=["a","b"]"#), "{out:?}");
+    }
+
+    #[test]
+    fn json_values_survive_task_fields_and_redaction() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.intro_with_fields("Serve", Fields::from([("stage".into(), "serve".into())]));
+        printer.add_redacted_key("token");
+
+        let out = capture_stdout(|| {
+            printer
+                .info("handled")
+                .field_json("request", json!({"path": "/"}))
+                .field_json("token", json!({"secret": 1}))
+                .emit();
+        });
+
+        let event: Value = out
+            .lines()
+            .filter_map(|l| serde_json::from_str::<Value>(l).ok())
+            .find(|e| e["message"] == "handled")
+            .unwrap();
+        assert_eq!(event["fields"]["request"], json!({"path": "/"}));
+        assert_eq!(event["fields"]["token"], "***");
+        assert_eq!(event["fields"]["stage"], "serve");
+    }
+
+    #[test]
+    fn plain_insert_clears_the_json_flag() {
+        let mut fields = Fields::new();
+        fields.insert_json("k".into(), &json!({"a": 1}));
+        assert!(fields.is_json("k"));
+
+        fields.insert("k".into(), "{\"a\":1}".into());
+        assert!(!fields.is_json("k"));
+        assert_eq!(
+            serde_json::to_value(&fields).unwrap(),
+            json!({"k": "{\"a\":1}"})
+        );
+    }
+
+    #[test]
+    fn merge_keeps_json_values_raw() {
+        let mut base = Fields::new();
+        base.insert_json("k".into(), &json!([1]));
+        base.insert("plain".into(), "x".into());
+
+        let mut overlay = Fields::new();
+        overlay.insert_json("plain".into(), &json!({"n": 2}));
+        base.merge(&overlay);
+
+        assert!(base.is_json("k"));
+        assert!(base.is_json("plain"));
+        assert_eq!(
+            serde_json::to_value(&base).unwrap(),
+            json!({"k": [1], "plain": {"n": 2}})
+        );
+
+        base.remove("k");
+        assert!(!base.is_json("k"));
+    }
+}
+
+mod conditional_event_tests {
+    use super::*;
+
+    fn slow_request(printer: &Printer<MockLogger, SimpleBackend>, ms: u64) -> String {
+        capture_stdout(|| {
+            printer.warn("slow request").field("ms", ms).when(ms > 100);
+        })
+    }
+
+    #[test]
+    fn false_condition_suppresses_the_event() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        assert_eq!(slow_request(&printer, 40), "");
+    }
+
+    #[test]
+    fn true_condition_emits_on_drop() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let out = slow_request(&printer, 250);
+        assert!(out.contains("WARN: slow request"), "{out:?}");
+        assert!(out.contains("ms=250"), "{out:?}");
+    }
+}
@@ -0,0 +1,88 @@
+mod table_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    fn rows() -> Vec<Vec<String>> {
+        vec![
+            vec!["api".into(), "running".into(), "3".into()],
+            vec!["scheduler".into(), "stopped".into()],
+            vec!["db".into(), "running".into(), "1".into()],
+        ]
+    }
+
+    #[test]
+    fn text_table_aligns_columns_to_widest_cell() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            ScreenLogger::table(&printer, &["name", "status", "replicas"], &rows());
+        });
+
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "name       status   replicas",
+                "---------  -------  --------",
+                "api        running  3",
+                "scheduler  stopped",
+                "db         running  1",
+            ]
+        );
+    }
+
+    #[test]
+    fn json_table_emits_one_object_per_row() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            ScreenLogger::table(&printer, &["name", "status", "replicas"], &rows());
+        });
+
+        let value: Value = serde_json::from_str(out.trim()).unwrap();
+        assert_eq!(value["message"], "table");
+        let objects = value["fields"]["rows"].as_array().unwrap();
+        assert_eq!(objects.len(), 3);
+        assert_eq!(objects[0]["name"], "api");
+        assert_eq!(objects[0]["replicas"], "3");
+        assert_eq!(objects[1]["status"], "stopped");
+        assert_eq!(objects[1]["replicas"], "");
+    }
+
+    #[test]
+    fn json_table_goes_through_the_backend() {
+        let backend = CaptureBackend::new();
+        let printer = Printer::new(
+            SimpleLogger,
+            backend.clone(),
+            LogFormat::Json,
+            Verbosity::Normal,
+        )
+        .with_timestamp_mode(TimestampMode::Fixed("2024-01-01T00:00:00Z"));
+
+        let out = capture_stdout(|| {
+            ScreenLogger::table(&printer, &["name"], &rows());
+        });
+
+        assert_eq!(out, "", "JSON must not bypass the backend");
+        let lines = backend.lines();
+        assert_eq!(lines.len(), 1);
+        let event: Value = serde_json::from_str(&lines[0].1).unwrap();
+        assert_eq!(event["timestamp"], "2024-01-01T00:00:00Z");
+        assert_eq!(event["fields"]["rows"][2]["name"], "db");
+    }
+
+    #[test]
+    fn quiet_mode_hides_table() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Quiet);
+
+        let out = capture_stdout(|| {
+            ScreenLogger::table(&printer, &["name"], &rows());
+        });
+
+        assert!(out.is_empty());
+    }
+}
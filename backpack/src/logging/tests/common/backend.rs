@@ -0,0 +1,113 @@
+use crate::logging::*;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+/// Backend that renders nothing and counts progress renders.
+#[derive(Clone, Default)]
+pub struct CountingBackend {
+    pub progress_calls: Arc<AtomicUsize>,
+}
+
+impl CountingBackend {
+    pub fn progress_count(&self) -> usize {
+        self.progress_calls.load(Ordering::SeqCst)
+    }
+}
+
+impl RenderBackend for CountingBackend {
+    fn render_error(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_info(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_remark(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_step(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_success(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_warning(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_intro(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_outro(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_debug(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_trace(&self, _msg: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn render_progress(&self, _update: &ProgressUpdate<'_>) -> anyhow::Result<()> {
+        self.progress_calls.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+/// Backend whose every render fails, for exercising error propagation.
+pub struct FailingBackend;
+
+impl RenderBackend for FailingBackend {
+    fn render_error(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_error failed")
+    }
+
+    fn render_info(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_info failed")
+    }
+
+    fn render_remark(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_remark failed")
+    }
+
+    fn render_step(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_step failed")
+    }
+
+    fn render_success(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_success failed")
+    }
+
+    fn render_warning(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_warning failed")
+    }
+
+    fn render_intro(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_intro failed")
+    }
+
+    fn render_outro(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_outro failed")
+    }
+
+    fn render_debug(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_debug failed")
+    }
+
+    fn render_trace(&self, _msg: &str) -> anyhow::Result<()> {
+        anyhow::bail!("render_trace failed")
+    }
+
+    fn render_progress(&self, _update: &ProgressUpdate<'_>) -> anyhow::Result<()> {
+        anyhow::bail!("render_progress failed")
+    }
+}
@@ -1,7 +1,9 @@
+mod backend;
 mod capture;
 mod mock_logger;
 mod printer;
 
+pub(crate) use backend::*;
 pub(crate) use capture::*;
 pub(crate) use mock_logger::*;
 pub(crate) use printer::*;
@@ -1,9 +1,25 @@
 use crate::logging::*;
+use std::sync::Arc;
 
+/// A printer with timestamps off and a `MockClock`, so durations only move
+/// when a test advances the clock.
 pub fn make_printer<L: FormatLogger + 'static>(
     inner: L,
     format: LogFormat,
     verbosity: Verbosity,
 ) -> Printer<L, SimpleBackend> {
-    Printer::new(inner, SimpleBackend, format, verbosity)
+    make_clocked_printer(inner, format, verbosity).0
+}
+
+/// Like `make_printer`, also returning the clock to advance.
+pub fn make_clocked_printer<L: FormatLogger + 'static>(
+    inner: L,
+    format: LogFormat,
+    verbosity: Verbosity,
+) -> (Printer<L, SimpleBackend>, Arc<MockClock>) {
+    let printer = Printer::new(inner, SimpleBackend, format, verbosity)
+        .with_timestamp_mode(TimestampMode::Disabled);
+    let clock = Arc::new(MockClock::new());
+    printer.set_clock(clock.clone());
+    (printer, clock)
 }
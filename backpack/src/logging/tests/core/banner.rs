@@ -0,0 +1,79 @@
+use crate::logging::*;
+use serial_test::serial;
+use std::sync::atomic::Ordering;
+
+/// `dev_banner()` under `RUST_LOG=debug` with `LOG_RS_NO_BANNER` set to `value`.
+fn banner_with_no_banner_env(value: Option<&str>) -> Option<String> {
+    let disabled = globals::NO_BANNER.swap(false, Ordering::Relaxed);
+    let rust_log = std::env::var_os("RUST_LOG");
+    unsafe {
+        std::env::set_var("RUST_LOG", "debug");
+        match value {
+            Some(v) => std::env::set_var("LOG_RS_NO_BANNER", v),
+            None => std::env::remove_var("LOG_RS_NO_BANNER"),
+        }
+    }
+
+    let banner = dev_banner();
+
+    unsafe {
+        std::env::remove_var("LOG_RS_NO_BANNER");
+        match rust_log {
+            Some(v) => std::env::set_var("RUST_LOG", v),
+            None => std::env::remove_var("RUST_LOG"),
+        }
+    }
+    globals::NO_BANNER.store(disabled, Ordering::Relaxed);
+    banner
+}
+
+#[test]
+#[serial]
+fn banner_is_disabled_by_env_var() {
+    let banner = banner_with_no_banner_env(None).expect("banner with RUST_LOG=debug");
+    assert!(banner.contains("Welcome to"), "{banner}");
+
+    for value in ["1", "true", "yes"] {
+        assert_eq!(banner_with_no_banner_env(Some(value)), None, "{value}");
+    }
+}
+
+#[test]
+#[serial]
+fn falsy_env_values_keep_the_banner() {
+    for value in ["0", "false", "FALSE", ""] {
+        let banner = banner_with_no_banner_env(Some(value));
+        assert!(
+            banner.is_some_and(|b| b.contains("Welcome to")),
+            "{value:?}"
+        );
+    }
+}
+
+#[test]
+#[serial]
+fn disable_banner_suppresses_banner() {
+    disable_banner();
+    assert!(banner_disabled());
+}
+
+#[test]
+fn dev_mode_banner_prints_when_rust_log_is_debug_or_trace() {
+    for level in ["debug", "trace", "DEBUG", "Trace"] {
+        assert!(should_show_banner(Some(level), false), "{level}");
+    }
+
+    for level in [
+        "info",
+        "warn",
+        "error",
+        "",
+        "my_crate=trace",
+        "debug,hyper=info",
+    ] {
+        assert!(!should_show_banner(Some(level), false), "{level}");
+    }
+
+    assert!(!should_show_banner(None, false));
+    assert!(!should_show_banner(Some("trace"), true));
+}
@@ -0,0 +1,60 @@
+use crate::logging::tests::common::*;
+use crate::logging::*;
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+#[serial]
+fn builder_builds_independent_printers() {
+    let mut builder = Printer::builder(SimpleLogger, SimpleBackend).verbosity(Verbosity::Normal);
+
+    let text = builder.build();
+    assert!(builder.primary_built());
+    assert!(globals::INIT.get().is_some());
+
+    builder.set_format(LogFormat::Json);
+    builder.set_verbosity(Verbosity::Verbose);
+    let json = builder.build();
+
+    assert_eq!(text.format, LogFormat::Text);
+    assert_eq!(json.format, LogFormat::Json);
+    assert_eq!(json.verbosity(), Verbosity::Verbose);
+
+    // Only the primary printer sets global flags.
+    assert!(!crate::config::isverbose());
+
+    let out = capture_stdout(|| {
+        ScreenLogger::ok(&text, "from text");
+        ScreenLogger::ok(&json, "from json");
+    });
+
+    assert!(out.contains("from text"), "{out}");
+    assert!(
+        out.lines()
+            .any(|l| l.starts_with('{') && l.contains("from json")),
+        "{out}"
+    );
+}
+
+#[test]
+#[serial]
+fn builder_runs_init_for_the_primary_only() {
+    static INITS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut builder = Printer::builder(SimpleLogger, SimpleBackend);
+    builder.init = || {
+        INITS.fetch_add(1, Ordering::Relaxed);
+        println!("Welcome to the builder test");
+        Ok(())
+    };
+
+    let out = capture_stdout(|| {
+        for _ in 0..3 {
+            let _ = builder.build();
+        }
+    });
+
+    assert_eq!(INITS.load(Ordering::Relaxed), 1);
+    assert_eq!(out.matches("Welcome to").count(), 1, "{out}");
+}
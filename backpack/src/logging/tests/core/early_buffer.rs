@@ -0,0 +1,122 @@
+use crate::logging::tests::common::*;
+use crate::logging::*;
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+
+fn capture_printer(backend: &CaptureBackend) -> Printer<MockLogger, CaptureBackend> {
+    Printer::new(
+        MockLogger::new(Verbosity::Normal),
+        backend.clone(),
+        LogFormat::Text,
+        Verbosity::Normal,
+    )
+    .with_timestamp_mode(TimestampMode::Disabled)
+}
+
+#[test]
+#[serial]
+fn events_before_set_logger_are_replayed_in_order() {
+    crate::logging::internal::globals::reset_logger();
+    buffer_early();
+
+    L.info("constructing").field("pool", 4).emit();
+    logger().warn("config missing, using defaults");
+    assert_eq!(EARLY.len(), 2);
+
+    let backend = CaptureBackend::new();
+    set_logger(capture_printer(&backend));
+
+    assert_eq!(
+        backend.messages(),
+        [
+            "INFO: constructing \u{1b}[2mpool=4\u{1b}[0m",
+            "WARN: config missing, using defaults",
+        ]
+    );
+    assert!(EARLY.is_empty());
+
+    crate::logging::internal::globals::reset_logger();
+}
+
+#[test]
+#[serial]
+fn replayed_events_keep_their_target_and_call_site() {
+    crate::logging::internal::globals::reset_logger();
+    buffer_early();
+
+    L.info("pool ready").target("db").emit();
+    EARLY.emit_event(LogLevel::Warn, "direct", &Fields::new());
+
+    let backend = CaptureBackend::new();
+    let printer = Printer::new(
+        MockLogger::new(Verbosity::Normal),
+        backend.clone(),
+        LogFormat::Json,
+        Verbosity::Normal,
+    );
+    printer.set_show_location(true);
+    set_logger(printer);
+    let events: Vec<serde_json::Value> = backend
+        .messages()
+        .iter()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(events.len(), 2);
+
+    assert_eq!(events[0]["message"], "pool ready");
+    assert_eq!(events[0]["fields"]["target"], "db");
+    for event in &events {
+        assert!(
+            event["fields"]["file"]
+                .as_str()
+                .unwrap()
+                .ends_with("early_buffer.rs"),
+            "{event}"
+        );
+    }
+
+    crate::logging::internal::globals::reset_logger();
+}
+
+#[test]
+#[serial]
+fn full_buffer_drops_oldest_and_reports_it() {
+    crate::logging::internal::globals::reset_logger();
+    buffer_early();
+    EARLY.set_capacity(2);
+
+    for i in 0..5 {
+        logger().info(&format!("event {i}"));
+    }
+
+    let backend = CaptureBackend::new();
+    set_logger(capture_printer(&backend));
+    EARLY.set_capacity(EarlyBuffer::DEFAULT_CAPACITY);
+
+    assert_eq!(
+        backend.messages(),
+        [
+            "WARN: 3 early log events were dropped (buffer full)",
+            "INFO: event 3",
+            "INFO: event 4",
+        ]
+    );
+
+    crate::logging::internal::globals::reset_logger();
+}
+
+#[test]
+#[serial]
+fn buffer_early_keeps_an_installed_logger() {
+    crate::logging::internal::globals::reset_logger();
+    let backend = CaptureBackend::new();
+    set_logger(capture_printer(&backend));
+
+    buffer_early();
+    logger().ok("direct");
+
+    assert_eq!(backend.messages(), ["OK: direct"]);
+    assert!(EARLY.is_empty());
+
+    crate::logging::internal::globals::reset_logger();
+}
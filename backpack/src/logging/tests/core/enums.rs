@@ -34,3 +34,57 @@ fn verbosity_hierarchy_flags_match() {
     assert!(!trace.is_quiet());
     assert!(trace.is_verbose());
 }
+
+#[test]
+fn verbosity_and_format_parse_case_insensitively() {
+    assert_eq!("Verbose".parse::<Verbosity>().unwrap(), Verbosity::Verbose);
+    assert_eq!(" quiet ".parse::<Verbosity>().unwrap(), Verbosity::Quiet);
+    assert_eq!("JSON".parse::<LogFormat>().unwrap(), LogFormat::Json);
+    assert!("loud".parse::<Verbosity>().is_err());
+    assert!("xml".parse::<LogFormat>().is_err());
+}
+
+#[test]
+fn log_level_ordering_follows_severity() {
+    assert!(LogLevel::Error > LogLevel::Info);
+    assert!(LogLevel::Warn > LogLevel::Progress);
+    assert!(LogLevel::Progress > LogLevel::Info);
+    assert!(LogLevel::Trace < LogLevel::Debug);
+
+    let sorted: Vec<LogLevel> = LogLevel::all().collect();
+    let mut shuffled = vec![
+        LogLevel::Error,
+        LogLevel::Trace,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Progress,
+        LogLevel::Debug,
+    ];
+    shuffled.sort();
+    assert_eq!(shuffled, sorted);
+}
+
+#[test]
+fn log_level_from_str_round_trips() {
+    for level in LogLevel::all() {
+        assert_eq!(level.as_str().parse::<LogLevel>().unwrap(), level);
+    }
+
+    assert_eq!("WARN".parse::<LogLevel>().unwrap(), LogLevel::Warn);
+    assert!("fatal".parse::<LogLevel>().is_err());
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn log_level_converts_to_and_from_tracing() {
+    assert_eq!(tracing::Level::from(LogLevel::Warn), tracing::Level::WARN);
+    assert_eq!(LogLevel::from(tracing::Level::WARN), LogLevel::Warn);
+    assert_eq!(
+        tracing::Level::from(LogLevel::Progress),
+        tracing::Level::INFO
+    );
+
+    for level in LogLevel::all().filter(|l| *l != LogLevel::Progress) {
+        assert_eq!(LogLevel::from(tracing::Level::from(level)), level);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::logging::internal::log::load_env;
+use crate::logging::tests::common::*;
+use crate::logging::*;
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn env_failure_is_a_warning_and_logging_still_works() {
+    let mut loaded = true;
+    let err = capture_stderr(|| {
+        loaded = load_env(|| Err(anyhow::anyhow!(".env line 3: expected KEY=VALUE")));
+    });
+
+    assert!(!loaded);
+    assert!(
+        err.contains("warning: failed to load environment"),
+        "{err:?}"
+    );
+    assert!(err.contains(".env line 3: expected KEY=VALUE"), "{err:?}");
+
+    let printer = make_printer(
+        MockLogger::new(Verbosity::Normal),
+        LogFormat::Text,
+        Verbosity::Normal,
+    );
+    let out = capture_stdout(|| ScreenLogger::ok(&printer, "still logging"));
+    assert_eq!(out, "OK: still logging\n");
+}
+
+#[test]
+fn env_success_reports_loaded() {
+    assert!(load_env(|| Ok::<(), anyhow::Error>(())));
+}
@@ -1,3 +1,14 @@
+pub mod banner;
+pub mod builder;
+pub mod early_buffer;
 pub mod enums;
+pub mod env_fallback;
 pub mod mock_logger;
+#[cfg(not(feature = "tracing"))]
+pub mod no_tracing;
+pub mod null_logger;
 pub mod printer_core;
+pub mod profile;
+pub mod settings;
+#[cfg(feature = "tracing")]
+pub mod tracing_filter;
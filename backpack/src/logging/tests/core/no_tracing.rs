@@ -0,0 +1,58 @@
+//! Compiled only with `--no-default-features`: the printer must keep working
+//! without `tracing` installed.
+
+mod no_tracing_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn text_output_and_task_timing_work_without_tracing() {
+        let (printer, clock) = make_clocked_printer(
+            MockLogger::new(Verbosity::Verbose),
+            LogFormat::Text,
+            Verbosity::Verbose,
+        );
+
+        let out = capture_stdout(|| {
+            ScreenLogger::intro(&printer, "build");
+            ScreenLogger::ok(&printer, "compiled");
+            clock.advance(std::time::Duration::from_millis(10));
+            ScreenLogger::outro(&printer, "build");
+        });
+
+        assert_eq!(
+            out,
+            "INTRO: build\n  OK: compiled\nOUTRO: build (took 10ms)\n"
+        );
+        assert_eq!(ScreenLogger::task_depth(&printer), 0);
+        assert_eq!(printer.completed_tasks().len(), 1);
+    }
+
+    #[test]
+    fn json_output_works_without_tracing() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Json,
+            Verbosity::Normal,
+        );
+
+        let out = capture_stdout(|| {
+            ScreenLogger::intro(&printer, "build");
+            ScreenLogger::ok(&printer, "compiled");
+            ScreenLogger::outro(&printer, "build");
+        });
+
+        let events: Vec<serde_json::Value> = out
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[1]["message"], "OK: compiled");
+        assert_eq!(
+            events[0]["fields"]["task_id"],
+            events[2]["fields"]["task_id"]
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use crate::logging::tests::common::*;
+use crate::logging::*;
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+
+#[test]
+fn null_logger_discards_everything() {
+    let logger = NullLogger;
+
+    let out = capture_stdout(|| {
+        logger.ok("x");
+        logger.intro("task");
+        logger.outro("done");
+        logger.rule(None);
+    });
+
+    assert_eq!(out, "");
+    assert!(!logger.confirm("Proceed?").unwrap());
+    assert_eq!(logger.input("Name:").unwrap(), "");
+    assert!(!ScreenLogger::enabled(&logger, LogLevel::Error));
+}
+
+#[test]
+#[serial]
+fn log_or_null_is_a_noop_when_unset() {
+    crate::logging::internal::globals::reset_logger();
+
+    let out = capture_stdout(|| log_or_null().ok("x"));
+
+    assert_eq!(out, "");
+}
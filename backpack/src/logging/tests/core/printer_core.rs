@@ -42,3 +42,130 @@ fn printer_step_stack_initially_empty() {
 
     assert!(printer.steps.lock().unwrap().is_empty());
 }
+
+#[test]
+fn printer_enabled_reflects_verbosity() {
+    let normal = Printer::new(
+        MockLogger::new(Verbosity::Normal),
+        SimpleBackend,
+        LogFormat::Text,
+        Verbosity::Normal,
+    );
+    let verbose = Printer::new(
+        MockLogger::new(Verbosity::Verbose),
+        SimpleBackend,
+        LogFormat::Text,
+        Verbosity::Verbose,
+    );
+    let quiet = Printer::new(
+        MockLogger::new(Verbosity::Quiet),
+        SimpleBackend,
+        LogFormat::Text,
+        Verbosity::Quiet,
+    );
+
+    assert!(!normal.enabled(LogLevel::Debug));
+    assert!(normal.enabled(LogLevel::Info));
+    assert!(verbose.enabled(LogLevel::Debug));
+    assert!(verbose.enabled(LogLevel::Trace));
+    assert!(!quiet.enabled(LogLevel::Info));
+    assert!(quiet.enabled(LogLevel::Error));
+}
+
+#[test]
+fn try_methods_surface_backend_errors() {
+    let printer = Printer::new(
+        MockLogger::new(Verbosity::Normal),
+        FailingBackend,
+        LogFormat::Text,
+        Verbosity::Normal,
+    );
+
+    let err = printer.try_ok("saved").unwrap_err();
+    assert_eq!(err.to_string(), "render_success failed");
+    assert!(printer.try_info("note").is_err());
+}
+
+#[test]
+fn infallible_methods_warn_once_on_backend_failure() {
+    let printer = Printer::new(
+        MockLogger::new(Verbosity::Normal),
+        FailingBackend,
+        LogFormat::Text,
+        Verbosity::Normal,
+    );
+
+    let err = capture_stderr(|| {
+        ScreenLogger::info(&printer, "first");
+        ScreenLogger::ok(&printer, "second");
+    });
+
+    assert_eq!(err.matches("log backend failed").count(), 1);
+    assert!(err.contains("render_info failed"));
+}
+
+#[test]
+fn printer_defaults_to_real_timestamps() {
+    let printer = Printer::new(
+        SimpleLogger,
+        SimpleBackend,
+        LogFormat::Json,
+        Verbosity::Normal,
+    );
+
+    assert_eq!(*printer.timestamp.lock().unwrap(), TimestampMode::Real);
+}
+
+#[test]
+fn with_timestamp_mode_disabled_omits_json_timestamp() {
+    let printer = Printer::new(
+        SimpleLogger,
+        SimpleBackend,
+        LogFormat::Json,
+        Verbosity::Normal,
+    )
+    .with_timestamp_mode(TimestampMode::Disabled);
+
+    let out = capture_stdout(|| {
+        ScreenLogger::info(&printer, "deterministic");
+    });
+
+    let event: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+    assert_eq!(event["message"], "  deterministic");
+    assert!(event.get("timestamp").is_none());
+}
+
+/// Fails to compile if a printer stops being shareable across threads.
+const fn assert_send_sync<T: Send + Sync>() {}
+
+const _: () = {
+    assert_send_sync::<Printer<SimpleLogger, SimpleBackend>>();
+    #[cfg(feature = "modern")]
+    assert_send_sync::<Printer<ModernLogger, ModernBackend>>();
+    assert_send_sync::<NullLogger>();
+};
+
+#[test]
+fn printer_can_move_to_and_be_shared_across_threads() {
+    let printer = std::sync::Arc::new(make_printer(
+        MockLogger::new(Verbosity::Quiet),
+        LogFormat::Text,
+        Verbosity::Quiet,
+    ));
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let printer = std::sync::Arc::clone(&printer);
+            std::thread::spawn(move || {
+                ScreenLogger::ok(&*printer, &format!("worker {i}"));
+                printer.counts().ok
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(printer.counts().ok, 4);
+}
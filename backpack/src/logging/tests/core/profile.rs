@@ -0,0 +1,80 @@
+use crate::config;
+use crate::logging::tests::common::*;
+use crate::logging::*;
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+
+/// Puts the global quiet/verbose/nocolor flags back, since building a
+/// profile's printer sets them.
+struct RestoreFlags {
+    quiet: bool,
+    verbose: bool,
+    nocolor: bool,
+}
+
+impl RestoreFlags {
+    fn save() -> Self {
+        Self {
+            quiet: config::isquiet(),
+            verbose: config::isverbose(),
+            nocolor: config::isnocolor(),
+        }
+    }
+}
+
+impl Drop for RestoreFlags {
+    fn drop(&mut self) {
+        config::setquiet(self.quiet);
+        config::setverbose(self.verbose);
+        config::setnocolor(self.nocolor);
+    }
+}
+
+#[test]
+#[serial]
+fn ci_profile_builds_a_json_normal_printer() {
+    let _restore = RestoreFlags::save();
+    let profile = LogProfile::ci();
+    assert_eq!(profile.name, "ci");
+    assert_eq!(profile.settings.format, LogFormat::Json);
+    assert_eq!(profile.settings.verbosity, Verbosity::Normal);
+
+    let logger = profile.build();
+    assert!(logger.enabled(LogLevel::Info));
+    assert!(!logger.enabled(LogLevel::Debug));
+
+    let out = capture_stdout(|| logger.info("ready"));
+    let line: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+    assert_eq!(line["level"], "info");
+    assert_eq!(line["message"].as_str().unwrap().trim(), "ready");
+}
+
+#[test]
+#[serial]
+fn quiet_and_dev_profiles_set_their_verbosity() {
+    let _restore = RestoreFlags::save();
+    assert!(!LogProfile::quiet().build().enabled(LogLevel::Info));
+    assert!(LogProfile::dev().build().enabled(LogLevel::Debug));
+}
+
+#[test]
+fn args_override_verbosity_and_format() {
+    let profile = LogProfile::quiet().with_args(["app", "--json", "-vv", "serve"]);
+
+    assert_eq!(profile.settings.verbosity, Verbosity::Trace);
+    assert_eq!(profile.settings.format, LogFormat::Json);
+    assert_eq!(profile.settings.theme, Theme::Simple);
+}
+
+#[test]
+#[serial]
+fn built_profiles_install_with_set_logger() {
+    let _restore = RestoreFlags::save();
+    crate::logging::internal::globals::reset_logger();
+    set_logger(LogProfile::ci().build());
+
+    let out = capture_stdout(|| logger().info("installed"));
+    crate::logging::internal::globals::reset_logger();
+
+    assert!(out.contains("installed"), "{out:?}");
+}
@@ -0,0 +1,76 @@
+use crate::config;
+use crate::logging::*;
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn logger_settings_from_toml_build_a_printer() {
+    let settings: LoggerSettings = toml::from_str(
+        r#"
+        format = "json"
+        verbosity = "verbose"
+        nocolor = true
+        theme = "simple"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        settings,
+        LoggerSettings {
+            format: LogFormat::Json,
+            verbosity: Verbosity::Verbose,
+            nocolor: true,
+            theme: Theme::Simple,
+        }
+    );
+
+    let printer = Printer::from_settings(SimpleLogger, SimpleBackend, &settings);
+    assert_eq!(printer.format, LogFormat::Json);
+    assert_eq!(printer.verbosity(), Verbosity::Verbose);
+    assert!(config::isnocolor());
+
+    config::setnocolor(false);
+}
+
+#[test]
+fn logger_settings_round_trip_through_json_with_defaults() {
+    let settings: LoggerSettings = serde_json::from_str(r#"{ "format": "csv" }"#).unwrap();
+    assert_eq!(settings.format, LogFormat::Csv);
+    assert_eq!(settings.verbosity, Verbosity::Normal);
+    assert_eq!(settings.theme, Theme::Modern);
+
+    let json = serde_json::to_string(&settings).unwrap();
+    assert_eq!(
+        json,
+        r#"{"format":"csv","verbosity":"normal","nocolor":false,"theme":"modern"}"#
+    );
+}
+
+#[test]
+#[serial]
+fn printer_from_env_reads_overrides_and_explicit_args_win() {
+    unsafe {
+        std::env::set_var("LOG_RS_FORMAT", "json");
+        std::env::set_var("LOG_RS_VERBOSITY", "Trace");
+    }
+
+    let from_env = Printer::from_env(SimpleLogger, SimpleBackend, None, None).unwrap();
+    let explicit =
+        Printer::from_env(SimpleLogger, SimpleBackend, Some(LogFormat::Text), None).unwrap();
+
+    unsafe { std::env::set_var("LOG_RS_FORMAT", "logfmt") };
+    let invalid = Printer::from_env(SimpleLogger, SimpleBackend, None, None);
+
+    unsafe {
+        std::env::remove_var("LOG_RS_FORMAT");
+        std::env::remove_var("LOG_RS_VERBOSITY");
+    }
+
+    assert_eq!(from_env.format, LogFormat::Json);
+    assert_eq!(from_env.verbosity(), Verbosity::Trace);
+    assert_eq!(explicit.format, LogFormat::Text);
+    assert_eq!(explicit.verbosity(), Verbosity::Trace);
+    assert!(invalid.is_err());
+}
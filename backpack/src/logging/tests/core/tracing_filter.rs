@@ -0,0 +1,57 @@
+use crate::logging::{DEFAULT_TRACING_FILTER, internal::log::fmt_layer};
+use pretty_assertions::assert_eq;
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+use tracing_subscriber::{Registry, fmt::writer::BoxMakeWriter, prelude::*};
+
+/// `io::Write` into a shared buffer, so the test can read what the layer wrote.
+#[derive(Clone, Default)]
+struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn run_filtered(directive: &str) -> String {
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let layer = fmt_layer(directive, BoxMakeWriter::new(move || writer.clone())).unwrap();
+
+    tracing::subscriber::with_default(Registry::default().with(layer), || {
+        tracing::debug!("below the threshold");
+        tracing::warn!("at the threshold");
+    });
+
+    let bytes = buffer.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn directive_drops_events_below_its_level() {
+    let out = run_filtered("warn");
+
+    assert!(out.contains("at the threshold"), "{out:?}");
+    assert!(!out.contains("below the threshold"), "{out:?}");
+}
+
+#[test]
+fn default_directive_passes_everything() {
+    let out = run_filtered(DEFAULT_TRACING_FILTER);
+
+    assert_eq!(out.lines().count(), 2, "{out:?}");
+}
+
+#[test]
+fn invalid_directive_is_rejected() {
+    let layer = fmt_layer::<Registry>("app=loud", BoxMakeWriter::new(io::sink));
+    assert!(layer.is_err());
+}
@@ -44,3 +44,33 @@ fn modern_logger_debug_and_trace_markers_snapshot() {
 
     assert_snapshot!(out);
 }
+
+#[test]
+fn modern_logger_without_emoji_uses_plain_markers() {
+    let logger = ModernLogger;
+    let is_emoji = |c: char| ('\u{1F300}'..='\u{1FAFF}').contains(&c);
+
+    let (debug, trace) = with_emoji(false, || (logger.debug_raw("d"), logger.trace_raw("t")));
+
+    assert_eq!(debug, "[debug] d");
+    assert_eq!(trace, "[trace] t");
+    assert!(!debug.chars().chain(trace.chars()).any(is_emoji));
+}
+
+#[test]
+fn printer_set_emoji_applies_to_its_formatter() {
+    let printer = Printer::new(
+        ModernLogger,
+        SimpleBackend,
+        LogFormat::Json,
+        Verbosity::Verbose,
+    )
+    .with_timestamp_mode(TimestampMode::Disabled);
+    printer.set_emoji(false);
+
+    let out =
+        crate::logging::tests::common::capture_stderr(|| ScreenLogger::debug(&printer, "probe"));
+
+    assert!(out.contains("[debug] probe"), "{out:?}");
+    assert!(emoji_enabled(), "override must not leak past the call");
+}
@@ -33,3 +33,84 @@ fn simple_logger_step_contains_message() {
     let step = logger.step_raw("Processing item");
     assert!(step.contains("Processing item"));
 }
+
+mod glyph_mode_tests {
+    use super::*;
+    use crate::config;
+    use pretty_assertions::assert_eq;
+    use serial_test::serial;
+
+    #[test]
+    #[serial]
+    fn ascii_glyphs_are_independent_of_color() {
+        set_glyph_mode(GlyphMode::Ascii);
+        config::setnocolor(false);
+        let colored_ascii = SimpleLogger.ok_raw("saved");
+
+        set_glyph_mode(GlyphMode::Unicode);
+        config::setnocolor(true);
+        let plain_unicode = SimpleLogger.ok_raw("saved");
+        let modern = ModernLogger.step_raw("building");
+
+        config::setnocolor(false);
+        set_glyph_mode(GlyphMode::Auto);
+
+        assert_eq!(colored_ascii, "\x1b[32m+\x1b[0m saved");
+        assert_eq!(plain_unicode, "✔ saved");
+        assert_eq!(modern, "⠿ building");
+    }
+
+    #[test]
+    fn auto_mode_probes_locale_for_utf8() {
+        assert!(locale_supports_unicode(Some("en_US.UTF-8")));
+        assert!(locale_supports_unicode(Some("de_DE.utf8")));
+        assert!(locale_supports_unicode(None));
+        assert!(!locale_supports_unicode(Some("C")));
+        assert!(!locale_supports_unicode(Some("en_US.ISO-8859-1")));
+    }
+}
+
+mod color_scheme_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn custom_ok_color_is_used_by_ok_raw() {
+        let scheme = ColorScheme {
+            ok: "38;5;208",
+            ..ColorScheme::default()
+        };
+
+        let out = with_ansi(Some(true), || {
+            with_color_scheme(Some(scheme), || SimpleLogger.ok_raw("saved"))
+        });
+
+        assert!(out.starts_with("\x1b[38;5;208m"), "{out:?}");
+    }
+
+    #[test]
+    fn default_scheme_matches_stock_colors() {
+        assert_eq!(ColorScheme::default(), ColorScheme::STANDARD);
+        assert_eq!(ColorScheme::default().ok, "32");
+        assert_eq!(ColorScheme::default().info, "");
+        assert_eq!(ColorScheme::default().field, "2");
+    }
+
+    #[test]
+    fn printer_scheme_colors_info() {
+        let printer = Printer::new(
+            SimpleLogger,
+            SimpleBackend,
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_ansi(true);
+        printer.set_color_scheme(ColorScheme::high_contrast());
+
+        let out = crate::logging::tests::common::capture_stdout(|| {
+            ScreenLogger::info(&printer, "hello");
+        });
+
+        assert_eq!(out, "  \x1b[97mhello\x1b[0m\n");
+    }
+}
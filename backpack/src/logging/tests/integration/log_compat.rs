@@ -0,0 +1,24 @@
+use crate::logging::tests::common::{MockLogger, capture_stdout};
+use crate::logging::*;
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn log_crate_macros_route_through_printer() {
+    crate::logging::internal::globals::reset_logger();
+    set_logger(Printer::new(
+        MockLogger::new(Verbosity::Normal),
+        SimpleBackend,
+        LogFormat::Text,
+        Verbosity::Normal,
+    ));
+    init_log_compat().unwrap();
+
+    let out = capture_stdout(|| {
+        ::log::info!("hello from log");
+        ::log::debug!("hidden at normal verbosity");
+    });
+
+    assert!(out.contains("INFO: hello from log"), "got: {out}");
+    assert!(!out.contains("hidden at normal verbosity"));
+}
@@ -1 +1,3 @@
 pub mod integration;
+#[cfg(feature = "log-compat")]
+pub mod log_compat;
@@ -1,7 +1,13 @@
 use crate::logging::{tests::common::capture_stderr, *};
 use insta::assert_snapshot;
 use serial_test::serial;
+use std::sync::Arc;
 
+/// Install a Trace-level text logger on a frozen clock, so no ETA appears.
+///
+/// The clock never advances, so the default throttle would hide every frame
+/// after the first; snapshot tests that record each tick use
+/// `.throttle(Duration::ZERO)`.
 fn ensure_global_logger() {
     crate::logging::internal::globals::reset_logger();
     let printer = Printer::new(
@@ -10,11 +16,13 @@ fn ensure_global_logger() {
         LogFormat::Text,
         Verbosity::Trace,
     );
+    printer.set_clock(Arc::new(MockClock::new()));
     set_logger(printer);
 }
 
 mod progress_behavior_tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     #[serial]
@@ -59,7 +67,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::with_total("Processing", 10);
+            let mut p = Progress::with_total("Processing", 10).throttle(Duration::ZERO);
             p.tick();
             p.tick();
         });
@@ -73,7 +81,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::new("Loading");
+            let mut p = Progress::new("Loading").throttle(Duration::ZERO);
             p.tick();
             p.tick();
             p.tick();
@@ -101,7 +109,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::with_total("Downloading", 5);
+            let mut p = Progress::with_total("Downloading", 5).throttle(Duration::ZERO);
             p.tick();
             p.tick();
             p.tick();
@@ -118,7 +126,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::new("Processing");
+            let mut p = Progress::new("Processing").throttle(Duration::ZERO);
             p.tick();
             p.tick();
             p.tick();
@@ -134,7 +142,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::new("Uploading");
+            let mut p = Progress::new("Uploading").throttle(Duration::ZERO);
             p.update(3, 10);
             p.update(5, 10);
             p.update(10, 10);
@@ -149,7 +157,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::with_total("Syncing", 10);
+            let mut p = Progress::with_total("Syncing", 10).throttle(Duration::ZERO);
             p.update(5, 10);
             p.tick();
             p.tick();
@@ -164,8 +172,8 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p1 = Progress::new("Task A");
-            let mut p2 = Progress::new("Task B");
+            let mut p1 = Progress::new("Task A").throttle(Duration::ZERO);
+            let mut p2 = Progress::new("Task B").throttle(Duration::ZERO);
 
             p1.tick();
             p2.tick();
@@ -210,7 +218,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::with_total("Variable", 10);
+            let mut p = Progress::with_total("Variable", 10).throttle(Duration::ZERO);
             p.tick();
             p.total = None;
             p.tick();
@@ -271,7 +279,7 @@ mod progress_behavior_tests {
         ensure_global_logger();
 
         let out = capture_stderr(|| {
-            let mut p = Progress::with_total("Dynamic", 100);
+            let mut p = Progress::with_total("Dynamic", 100).throttle(Duration::ZERO);
             p.tick();
             p.update(50, 200);
             p.tick();
@@ -280,3 +288,1008 @@ mod progress_behavior_tests {
         assert_snapshot!(out);
     }
 }
+
+mod finished_guard_tests {
+    use super::*;
+    use crate::logging::tests::common::capture_stdout;
+    use std::time::Duration;
+
+    #[test]
+    #[serial]
+    fn updates_after_a_terminal_state_are_ignored() {
+        ensure_global_logger();
+        let mut p = Progress::with_total("Sync", 10).throttle(Duration::ZERO);
+        p.update(4, 10);
+        // What `finish`/`abort` leave behind before consuming the handle.
+        p.finished = true;
+
+        let mut out = String::new();
+        let err = capture_stderr(|| {
+            out = capture_stdout(|| {
+                p.tick();
+                p.update(9, 12);
+            });
+        });
+
+        assert_eq!(out, "");
+        assert_eq!(err, "");
+        assert_eq!(p.current, 4);
+        assert_eq!(p.total, Some(10));
+    }
+}
+
+mod progress_throttle_tests {
+    use super::*;
+    use crate::logging::tests::common::CountingBackend;
+    use std::time::Duration;
+
+    fn counting_global_logger() -> CountingBackend {
+        counting_global_logger_on(Arc::new(SystemClock))
+    }
+
+    fn counting_global_logger_on(clock: Arc<dyn Clock>) -> CountingBackend {
+        crate::logging::internal::globals::reset_logger();
+        let backend = CountingBackend::default();
+        let printer = Printer::new(
+            SimpleLogger,
+            backend.clone(),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_clock(clock);
+        set_logger(printer);
+        backend
+    }
+
+    #[test]
+    #[serial]
+    fn progress_throttle_skips_renders_within_window() {
+        let backend = counting_global_logger();
+
+        let mut p = Progress::with_total("Hot loop", 1000).throttle(Duration::from_mins(1));
+        for _ in 0..1000 {
+            p.tick();
+        }
+        assert_eq!(p.current, 1000);
+
+        p.finish("Hot loop done");
+
+        // First tick renders, the rest fall inside the window; finish always renders.
+        assert_eq!(backend.progress_count(), 2);
+    }
+
+    #[test]
+    #[serial]
+    fn progress_zero_throttle_renders_every_tick() {
+        let backend = counting_global_logger();
+
+        let mut p = Progress::with_total("Unthrottled", 10).throttle(Duration::ZERO);
+        for _ in 0..10 {
+            p.tick();
+        }
+
+        assert_eq!(backend.progress_count(), 10);
+    }
+
+    #[test]
+    #[serial]
+    fn progress_is_throttled_by_default() {
+        let clock = Arc::new(MockClock::new());
+        let backend = counting_global_logger_on(clock.clone());
+
+        let mut p = Progress::new("Default");
+        for _ in 0..5 {
+            p.update(p.current + 1, 10);
+        }
+        assert_eq!(backend.progress_count(), 1);
+
+        clock.advance(Progress::DEFAULT_THROTTLE);
+        p.update(6, 10);
+
+        assert_eq!(backend.progress_count(), 2);
+    }
+}
+
+mod progress_iter_tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    #[serial]
+    fn wrap_iter_ticks_each_item_and_finishes() {
+        ensure_global_logger();
+
+        let mut seen = Vec::new();
+        let out = capture_stderr(|| {
+            for item in Progress::wrap_iter("Items", vec![10, 20, 30, 40, 50].into_iter())
+                .throttle(Duration::ZERO)
+            {
+                seen.push(item);
+            }
+        });
+
+        assert_eq!(seen, vec![10, 20, 30, 40, 50]);
+        for i in 1..=5 {
+            assert!(
+                out.contains(&format!("Items ({i}/5, {}%)", i * 20)),
+                "missing {i}/5 in {out}"
+            );
+        }
+        assert_eq!(out.matches("Items — done").count(), 1);
+    }
+
+    #[test]
+    #[serial]
+    fn wrap_iter_finishes_once_when_dropped_early() {
+        ensure_global_logger();
+
+        let out = capture_stderr(|| {
+            let firsts: Vec<_> = Progress::wrap_iter("Partial", 0..10).take(2).collect();
+            assert_eq!(firsts, vec![0, 1]);
+        });
+
+        assert_eq!(out.matches("Partial — done").count(), 1);
+        assert!(out.contains("Partial (2/10, 20%)"));
+    }
+}
+
+mod progress_nesting_tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    #[serial]
+    fn progress_indents_under_open_task() {
+        ensure_global_logger();
+
+        let out = capture_stderr(|| {
+            logger().intro("Build");
+            let mut p = Progress::with_total("Compiling", 2).throttle(Duration::ZERO);
+            p.tick();
+            p.tick();
+        });
+
+        let line = out
+            .lines()
+            .find(|l| l.contains("Compiling (1/2, 50%)"))
+            .expect("missing progress line");
+        assert!(line.starts_with("  Compiling"), "not indented: {line:?}");
+    }
+
+    #[test]
+    #[serial]
+    fn top_level_progress_is_not_indented() {
+        ensure_global_logger();
+
+        let out = capture_stderr(|| {
+            let mut p = Progress::with_total("Top", 1).throttle(Duration::ZERO);
+            p.tick();
+        });
+
+        let line = out
+            .lines()
+            .find(|l| l.contains("Top (1/1, 100%)"))
+            .expect("missing progress line");
+        assert!(line.starts_with("Top"), "unexpected indent: {line:?}");
+    }
+}
+
+mod progress_abort_tests {
+    use super::*;
+    use crate::logging::tests::common::capture_stdout;
+    use std::time::Duration;
+
+    #[test]
+    #[serial]
+    fn abort_reports_failure_instead_of_done() {
+        ensure_global_logger();
+
+        let mut out = String::new();
+        let err = capture_stderr(|| {
+            out = capture_stdout(|| {
+                let mut p = Progress::with_total("Fetch", 3).throttle(Duration::ZERO);
+                p.tick();
+                p.abort("network error");
+            });
+        });
+
+        assert!(
+            err.contains(&SimpleLogger.err_raw("Fetch: network error")),
+            "missing error line in {err}"
+        );
+        assert!(err.contains("Fetch — aborted"));
+        assert!(!err.contains("— done"), "unexpected success output: {err}");
+        assert!(!out.contains("done"), "unexpected success output: {out}");
+        assert_eq!(L.task_depth(), 0);
+    }
+
+    #[test]
+    #[serial]
+    fn abort_sets_aborted_status_in_json() {
+        crate::logging::internal::globals::reset_logger();
+        let printer = Printer::new(
+            SimpleLogger,
+            SimpleBackend,
+            LogFormat::Json,
+            Verbosity::Normal,
+        );
+        printer.set_timestamp_mode(TimestampMode::Disabled);
+        set_logger(printer);
+
+        let out = capture_stdout(|| {
+            let _ = capture_stderr(|| {
+                Progress::new("Upload").abort("disk full");
+            });
+        });
+
+        let event: serde_json::Value = out
+            .lines()
+            .filter_map(|l| serde_json::from_str(l).ok())
+            .find(|v: &serde_json::Value| v["level"] == "progress")
+            .expect("missing progress event");
+        assert_eq!(event["fields"]["finished"], "true");
+        assert_eq!(event["fields"]["status"], "aborted");
+    }
+}
+
+mod progress_render_tests {
+    use super::*;
+    use crate::logging::tests::common::make_printer;
+    use pretty_assertions::assert_eq;
+
+    fn run_job(printer: &Printer<SimpleLogger, SimpleBackend>) {
+        for current in 1..=5 {
+            ScreenLogger::progress(
+                printer,
+                &ProgressUpdate {
+                    label: "Job",
+                    current,
+                    total: Some(5),
+                    finished: current == 5,
+                    aborted: false,
+                    depth: 0,
+                    group_digits: false,
+                    eta: None,
+                    elapsed: None,
+                    bar_width: None,
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn final_mode_emits_only_terminal_state() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_progress_render(ProgressRender::Final);
+
+        let out = capture_stderr(|| run_job(&printer));
+
+        assert_eq!(
+            out.lines().collect::<Vec<_>>(),
+            vec!["Job (5/5, 100%)", "Job — done"]
+        );
+    }
+
+    #[test]
+    fn lines_mode_is_the_default() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+
+        let out = capture_stderr(|| run_job(&printer));
+
+        assert_eq!(out.lines().count(), 6);
+        assert!(!out.contains('\r'));
+    }
+
+    #[test]
+    fn inline_mode_redraws_in_place() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_progress_render(ProgressRender::Inline);
+
+        let out = capture_stderr(|| run_job(&printer));
+
+        assert_eq!(out.matches('\r').count(), 5);
+        assert!(out.ends_with("Job (5/5, 100%)\nJob — done\n"));
+    }
+}
+
+mod digit_grouping_tests {
+    use super::*;
+    use crate::logging::tests::common::make_printer;
+
+    fn render(printer: &Printer<SimpleLogger, SimpleBackend>) -> String {
+        capture_stderr(|| {
+            ScreenLogger::progress(
+                printer,
+                &ProgressUpdate {
+                    label: "Rows",
+                    current: 1250,
+                    total: Some(50000),
+                    finished: false,
+                    aborted: false,
+                    depth: 0,
+                    group_digits: false,
+                    eta: None,
+                    elapsed: None,
+                    bar_width: None,
+                },
+            );
+        })
+    }
+
+    #[test]
+    fn progress_counts_are_grouped_when_enabled() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_group_digits(true);
+
+        assert_eq!(render(&printer).trim_end(), "Rows (1,250/50,000, 2%)");
+    }
+
+    #[test]
+    fn progress_counts_are_bare_by_default() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+
+        assert_eq!(render(&printer).trim_end(), "Rows (1250/50000, 2%)");
+    }
+
+    #[test]
+    fn group_digits_handles_signs_and_decimals() {
+        assert_eq!(group_digits("999"), "999");
+        assert_eq!(group_digits("1000"), "1,000");
+        assert_eq!(group_digits("-1234567.25"), "-1,234,567.25");
+        assert_eq!(group_digits("12ab"), "12ab");
+    }
+}
+
+mod eta_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::time::{Duration, Instant};
+
+    fn job(total: u64) -> Progress {
+        ensure_global_logger();
+        Progress::with_total("Eta", total)
+    }
+
+    /// Feed ticks at the given millisecond gaps and collect the ETA after each.
+    fn etas(progress: &mut Progress, gaps_ms: &[u64]) -> Vec<f64> {
+        let mut now = Instant::now();
+        progress.observe(now);
+
+        gaps_ms
+            .iter()
+            .map(|gap| {
+                now += Duration::from_millis(*gap);
+                progress.current += 1;
+                progress.observe(now);
+                progress.eta().unwrap().as_secs_f64()
+            })
+            .collect()
+    }
+
+    /// Sum of jumps between consecutive estimates.
+    fn jitter(etas: &[f64]) -> f64 {
+        etas.windows(2).map(|w| (w[1] - w[0]).abs()).sum()
+    }
+
+    #[test]
+    #[serial]
+    fn smoothed_eta_is_steadier_than_per_tick_estimate() {
+        let gaps = [100, 10, 300, 20, 250, 5, 180, 40, 220, 15];
+
+        let mut smoothed = job(100);
+        let smooth = etas(&mut smoothed, &gaps);
+
+        // With a smoothing factor of 1 the ETA follows only the last interval.
+        let mut naive = job(100);
+        naive.set_eta_smoothing(1.0);
+        let raw = etas(&mut naive, &gaps);
+
+        assert!(
+            jitter(&smooth) < jitter(&raw) / 2.0,
+            "smoothed {smooth:?} vs naive {raw:?}"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn zero_intervals_do_not_collapse_the_eta() {
+        let mut progress = job(10);
+        let now = Instant::now();
+        progress.observe(now);
+
+        progress.current = 1;
+        progress.observe(now + Duration::from_secs(1));
+        progress.current = 5;
+        progress.observe(now + Duration::from_secs(1));
+
+        assert_eq!(progress.eta(), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn running_line_shows_time_left() {
+        let update = ProgressUpdate {
+            label: "Upload",
+            current: 3,
+            total: Some(10),
+            finished: false,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: Some(Duration::from_millis(4200)),
+            elapsed: None,
+            bar_width: None,
+        };
+
+        assert_eq!(update.line(), "Upload (3/10, 30%) ~5s left");
+        assert_eq!(
+            ProgressUpdate {
+                finished: true,
+                ..update
+            }
+            .line(),
+            "Upload (3/10, 30%)"
+        );
+    }
+}
+
+mod percent_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn line(current: u64, total: Option<u64>) -> String {
+        ProgressUpdate {
+            label: "Copy",
+            current,
+            total,
+            finished: false,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: None,
+            elapsed: None,
+            bar_width: None,
+        }
+        .line()
+    }
+
+    #[test]
+    fn overshoot_is_capped_at_100_percent() {
+        assert_eq!(line(10, Some(5)), "Copy (10/5, 100%)");
+    }
+
+    #[test]
+    fn zero_total_omits_the_percentage() {
+        assert_eq!(line(1, Some(0)), "Copy (1/0)");
+    }
+
+    #[test]
+    fn unknown_total_shows_only_the_count() {
+        assert_eq!(line(7, None), "Copy (7)");
+    }
+}
+
+mod json_rate_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+    use std::time::Duration;
+
+    fn tick(printer: &Printer<SimpleLogger, CaptureBackend>, current: u64, elapsed: Duration) {
+        ScreenLogger::progress(
+            printer,
+            &ProgressUpdate {
+                label: "Upload",
+                current,
+                total: Some(10),
+                finished: false,
+                aborted: false,
+                depth: 0,
+                group_digits: false,
+                eta: None,
+                elapsed: Some(elapsed),
+                bar_width: None,
+            },
+        );
+    }
+
+    #[test]
+    fn json_progress_reports_rate_and_eta() {
+        let backend = CaptureBackend::new();
+        let printer = Printer::new(
+            SimpleLogger,
+            backend.clone(),
+            LogFormat::Json,
+            Verbosity::Normal,
+        )
+        .with_timestamp_mode(TimestampMode::Disabled);
+
+        tick(&printer, 1, Duration::from_millis(20));
+        tick(&printer, 2, Duration::from_millis(40));
+
+        let events: Vec<Value> = backend
+            .messages()
+            .iter()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2);
+
+        let last = &events[1]["fields"];
+        assert_eq!(last["current"], "2");
+        assert_eq!(last["total"], "10");
+
+        // 2 items in 40ms, then 8 left at that rate.
+        assert_eq!(last["rate"], "50.00");
+        assert_eq!(last["eta_seconds"], "0.2");
+    }
+
+    #[test]
+    fn eta_needs_a_total() {
+        let update = ProgressUpdate {
+            label: "Scan",
+            current: 5,
+            total: None,
+            finished: false,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: None,
+            elapsed: Some(Duration::from_secs(1)),
+            bar_width: None,
+        };
+
+        assert!(update.rate().is_some());
+        assert_eq!(update.eta_seconds(), None);
+    }
+}
+
+mod progress_bar_tests {
+    use super::*;
+    use crate::logging::tests::common::make_printer;
+
+    fn render(tty: bool, total: Option<u64>) -> String {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_progress_render(ProgressRender::Final);
+        printer.set_progress_bar(true);
+        printer.set_terminal_width(Some(80));
+        printer.set_tty(Some(tty));
+
+        let update = ProgressUpdate {
+            label: "Copy",
+            current: 5,
+            total,
+            finished: true,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: None,
+            elapsed: None,
+            bar_width: None,
+        };
+        capture_stderr(|| ScreenLogger::progress(&printer, &update))
+    }
+
+    #[test]
+    #[serial]
+    fn tty_draws_a_half_filled_gauge() {
+        set_glyph_mode(GlyphMode::Unicode);
+        let out = render(true, Some(10));
+        set_glyph_mode(GlyphMode::Auto);
+
+        // 80 columns leave more than the 40-cell cap.
+        assert!(
+            out.contains("[████████████████████░░░░░░░░░░░░░░░░░░░░] 50%"),
+            "{out:?}"
+        );
+        assert_eq!(out.matches('█').count(), 20);
+        assert_eq!(out.matches('░').count(), 20);
+    }
+
+    #[test]
+    #[serial]
+    fn ascii_mode_draws_the_gauge_in_ascii() {
+        set_glyph_mode(GlyphMode::Ascii);
+        let out = render(true, Some(10));
+        set_glyph_mode(GlyphMode::Auto);
+
+        assert!(
+            out.contains("[####################--------------------] 50%"),
+            "{out:?}"
+        );
+    }
+
+    #[test]
+    fn non_tty_falls_back_to_counts() {
+        let out = render(false, Some(10));
+        assert!(out.contains("Copy (5/10, 50%)"), "{out:?}");
+        assert!(!out.contains('█'));
+    }
+
+    #[test]
+    fn unknown_total_keeps_the_count() {
+        let out = render(true, None);
+        assert!(out.contains("Copy (5)"), "{out:?}");
+        assert!(!out.contains('['));
+    }
+}
+
+mod progress_format_tests {
+    use super::*;
+    use crate::logging::tests::common::make_printer;
+
+    /// Plain formatter that only marks progress labels.
+    struct Marked;
+
+    impl FormatLogger for Marked {
+        fn ok_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn warn_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn err_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn info_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn dim_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn intro_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn outro_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn done_raw(&self) -> String {
+            "done".to_string()
+        }
+        fn step_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn debug_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+        fn trace_raw(&self, m: &str) -> String {
+            m.to_string()
+        }
+
+        fn progress_raw(&self, label: &str, _: u64, _: Option<u64>, finished: bool) -> String {
+            let mark = if finished { "<<fin>>" } else { "<<run>>" };
+            format!("{mark} {label}")
+        }
+    }
+
+    #[test]
+    fn custom_formatter_styles_the_progress_label() {
+        let printer = make_printer(Marked, LogFormat::Text, Verbosity::Normal);
+        printer.set_progress_render(ProgressRender::Lines);
+
+        let update = ProgressUpdate {
+            label: "Copy",
+            current: 3,
+            total: Some(4),
+            finished: false,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: None,
+            elapsed: None,
+            bar_width: None,
+        };
+        let out = capture_stderr(|| {
+            ScreenLogger::progress(&printer, &update);
+            ScreenLogger::progress(
+                &printer,
+                &ProgressUpdate {
+                    current: 4,
+                    finished: true,
+                    ..update
+                },
+            );
+        });
+
+        assert!(out.contains("<<run>> Copy (3/4, 75%)"), "{out:?}");
+        assert!(out.contains("<<fin>> Copy (4/4, 100%)"), "{out:?}");
+    }
+}
+
+mod progress_dual_tests {
+    use super::*;
+    use crate::logging::tests::common::{capture_stderr, capture_stdout, make_printer};
+
+    use serde_json::Value;
+    use serial_test::serial;
+
+    fn update(current: u64, finished: bool) -> ProgressUpdate<'static> {
+        ProgressUpdate {
+            label: "Copy",
+            current,
+            total: Some(10),
+            finished,
+            aborted: false,
+            depth: 0,
+            group_digits: false,
+            eta: None,
+            elapsed: None,
+            bar_width: None,
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn json_goes_to_stdout_and_the_bar_to_stderr() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+        printer.set_progress_dual(true);
+        printer.set_progress_bar(true);
+        printer.set_terminal_width(Some(80));
+        printer.set_tty(Some(true));
+        printer.set_ansi(true);
+
+        let mut out = String::new();
+        let err = capture_stderr(|| {
+            out = capture_stdout(|| {
+                ScreenLogger::progress(&printer, &update(5, false));
+                ScreenLogger::progress(&printer, &update(10, true));
+            });
+        });
+
+        let events: Vec<Value> = out
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(events.len(), 2, "{out:?}");
+        assert!(events.iter().all(|e| e["level"] == "progress"));
+        assert_eq!(events[0]["fields"]["current"], "5");
+        assert!(!out.contains('█'));
+
+        assert!(err.contains("Copy ["), "{err:?}");
+        assert!(err.contains("] 50%"), "{err:?}");
+        assert!(err.ends_with("] 100%\n"), "{err:?}");
+    }
+
+    #[test]
+    #[serial]
+    fn live_bar_without_a_terminal_or_ansi_prints_only_the_final_line() {
+        for (tty, ansi) in [(false, true), (true, false)] {
+            let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+            printer.set_progress_dual(true);
+            printer.set_tty(Some(tty));
+            printer.set_ansi(ansi);
+
+            let err = capture_stderr(|| {
+                let _ = capture_stdout(|| {
+                    ScreenLogger::progress(&printer, &update(5, false));
+                    ScreenLogger::progress(&printer, &update(10, true));
+                });
+            });
+
+            assert!(!err.contains('\x1b') && !err.contains('\r'), "{err:?}");
+            assert_eq!(err.lines().count(), 1, "{err:?}");
+            assert!(err.contains("10/10"), "{err:?}");
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn json_alone_leaves_stderr_quiet() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let err = capture_stderr(|| {
+            let _ = capture_stdout(|| ScreenLogger::progress(&printer, &update(5, false)));
+        });
+
+        assert!(err.is_empty(), "{err:?}");
+    }
+}
+
+mod progress_stream_tests {
+    use super::*;
+    use crate::logging::tests::common::{capture_stdout, make_printer};
+
+    fn run(printer: &Printer<SimpleLogger, SimpleBackend>) -> (String, String) {
+        let update = ProgressUpdate::new("Copy", 2, Some(4));
+
+        let mut out = String::new();
+        let err = capture_stderr(|| {
+            out = capture_stdout(|| {
+                ScreenLogger::progress(printer, &update);
+                ScreenLogger::info(printer, "copied config");
+            });
+        });
+        (out, err)
+    }
+
+    #[test]
+    #[serial]
+    fn progress_goes_to_stderr_and_info_to_stdout() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+
+        let (out, err) = run(&printer);
+
+        assert!(err.contains("Copy (2/4, 50%)"), "{err:?}");
+        assert!(!out.contains("Copy"), "{out:?}");
+        assert!(out.contains("copied config"), "{out:?}");
+    }
+
+    #[test]
+    #[serial]
+    fn progress_can_be_sent_back_to_stdout() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_progress_to(Stream::Stdout);
+
+        let (out, err) = run(&printer);
+
+        assert!(out.contains("Copy (2/4, 50%)"), "{out:?}");
+        assert!(!err.contains("Copy"), "{err:?}");
+    }
+
+    #[test]
+    #[serial]
+    fn progress_override_ignores_the_stream_policy() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        set_stream_policy(StreamPolicy::AllToStdout);
+
+        let (out, err) = run(&printer);
+        set_stream_policy(StreamPolicy::default());
+
+        assert!(err.contains("Copy (2/4, 50%)"), "{err:?}");
+        assert!(out.contains("copied config"), "{out:?}");
+    }
+}
+
+mod progress_callback_tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    #[serial]
+    fn on_update_sees_every_tick_and_update() {
+        ensure_global_logger();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let mut p = Progress::new("Upload");
+        let sink = Arc::clone(&seen);
+        p.on_update(Box::new(move |current, total| {
+            sink.lock().unwrap().push((current, total));
+        }));
+
+        let _ = capture_stderr(|| {
+            p.tick();
+            p.tick();
+            p.update(5, 10);
+            p.tick();
+            p.finish("Uploaded");
+        });
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [(1, None), (2, None), (5, Some(10)), (6, Some(10))]
+        );
+    }
+}
+
+mod multi_progress_tests {
+    use super::*;
+    use crate::logging::tests::common::make_printer;
+    use std::time::Duration;
+
+    fn multi(tty: bool) -> MultiProgress {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_tty(Some(tty));
+        printer.set_ansi(true);
+        printer.multi_progress()
+    }
+
+    fn download(multi: &MultiProgress, name: &str) -> Progress {
+        multi.add(Progress::with_total(&format!("Download {name}"), 2).throttle(Duration::ZERO))
+    }
+
+    #[test]
+    #[serial]
+    fn without_a_tty_each_progress_prints_its_own_lines() {
+        ensure_global_logger();
+        let multi = multi(false);
+        assert!(!multi.is_live());
+
+        let err = capture_stderr(|| {
+            let (mut a, mut b) = (download(&multi, "a"), download(&multi, "b"));
+            a.tick();
+            b.tick();
+            b.tick();
+            a.finish("a done");
+            b.finish("b done");
+        });
+
+        assert!(!err.contains("\x1b[2K"), "{err:?}");
+        for line in [
+            "Download a (1/2, 50%)",
+            "Download b (1/2, 50%)",
+            "Download b (2/2, 100%)",
+        ] {
+            assert!(
+                err.lines().any(|l| l.contains(line)),
+                "missing {line:?} in {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn without_ansi_the_block_is_not_redrawn() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Normal);
+        printer.set_tty(Some(true));
+        printer.set_ansi(false);
+
+        assert!(!printer.multi_progress().is_live());
+    }
+
+    #[test]
+    #[serial]
+    fn on_a_tty_each_progress_owns_a_row() {
+        ensure_global_logger();
+        let multi = multi(true);
+        assert!(multi.is_live());
+
+        let err = capture_stderr(|| {
+            let (mut a, mut b) = (download(&multi, "a"), download(&multi, "b"));
+            a.tick();
+            b.tick();
+            b.tick();
+        });
+
+        let rows = multi.rows();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].contains("Download a (1/2, 50%)"), "{rows:?}");
+        assert!(rows[1].contains("Download b (2/2, 100%)"), "{rows:?}");
+
+        // Every redraw repaints the whole block, one cleared row per handle.
+        assert_eq!(err.matches("\r\x1b[2K").count(), 3 * 2, "{err:?}");
+    }
+}
+
+mod progress_retry_tests {
+    use super::*;
+    use crate::logging::tests::common::capture_stdout;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    #[serial]
+    fn retry_warns_without_advancing_and_is_counted_at_finish() {
+        ensure_global_logger();
+
+        let out = capture_stdout(|| {
+            let _ = capture_stderr(|| {
+                let mut p = Progress::with_total("Sending emails", 3);
+                p.tick();
+                p.retry("smtp down");
+                assert_eq!((p.current, p.retries()), (1, 1));
+                p.tick();
+                p.tick();
+                p.finish("Emails sent");
+            });
+        });
+
+        assert!(
+            out.contains("Sending emails: retrying (smtp down)"),
+            "{out:?}"
+        );
+        assert!(
+            out.contains("Emails sent (finished with 1 retry)"),
+            "{out:?}"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn finish_without_retries_keeps_the_message() {
+        ensure_global_logger();
+
+        let out = capture_stdout(|| {
+            let _ = capture_stderr(|| Progress::with_total("Upload", 1).finish("Uploaded"));
+        });
+
+        assert!(out.contains("Uploaded"), "{out:?}");
+        assert!(!out.contains("retr"), "{out:?}");
+    }
+}
@@ -1,2 +1,5 @@
+pub mod reruns;
 pub mod spans_and_tasks;
+pub mod task_fields;
+pub mod task_guard;
 pub mod timing;
@@ -0,0 +1,91 @@
+mod collapse_reruns_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn printer(format: LogFormat) -> Printer<MockLogger, SimpleBackend> {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            format,
+            Verbosity::Normal,
+        );
+        printer.set_collapse_reruns(true);
+        printer
+    }
+
+    fn intros(collapse: bool, labels: &[&str]) -> Vec<String> {
+        let printer = printer(LogFormat::Text);
+        printer.set_collapse_reruns(collapse);
+
+        let out = capture_stdout(|| {
+            for label in labels {
+                ScreenLogger::intro(&printer, label);
+                ScreenLogger::outro(&printer, "ok");
+            }
+        });
+
+        out.lines()
+            .filter_map(|l| l.strip_prefix("INTRO: "))
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn repeated_task_gets_a_run_counter() {
+        assert_eq!(
+            intros(true, &["build", "build", "build"]),
+            ["build", "build (run 2)", "build (run 3)"]
+        );
+    }
+
+    #[test]
+    fn a_different_task_resets_the_counter() {
+        assert_eq!(
+            intros(true, &["build", "test", "build"]),
+            ["build", "test", "build"]
+        );
+    }
+
+    #[test]
+    fn reruns_are_not_collapsed_by_default() {
+        assert_eq!(intros(false, &["build", "build"]), ["build", "build"]);
+    }
+
+    #[test]
+    fn json_reruns_keep_the_label_and_add_a_run_field() {
+        let printer = printer(LogFormat::Json);
+
+        let out = capture_stdout(|| {
+            for _ in 0..2 {
+                ScreenLogger::intro(&printer, "build");
+                ScreenLogger::outro(&printer, "ok");
+            }
+        });
+
+        let intros: Vec<serde_json::Value> = out
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .filter(|v: &serde_json::Value| v["message"] == "INTRO: build")
+            .collect();
+        assert_eq!(intros.len(), 2, "{out}");
+        assert_eq!(intros[0]["fields"].get("run"), None);
+        assert_eq!(intros[1]["fields"]["run"], 2);
+    }
+
+    #[test]
+    fn intros_over_the_depth_cap_do_not_count_as_runs() {
+        let printer = printer(LogFormat::Text);
+        printer.set_max_task_depth(1);
+
+        let out = capture_stdout(|| {
+            ScreenLogger::intro(&printer, "build");
+            ScreenLogger::outro(&printer, "ok");
+            ScreenLogger::intro(&printer, "watch");
+            ScreenLogger::intro(&printer, "build");
+            ScreenLogger::outro(&printer, "ok");
+        });
+
+        assert!(!out.contains("(run"), "{out}");
+    }
+}
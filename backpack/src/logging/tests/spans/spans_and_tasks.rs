@@ -49,4 +49,167 @@ mod nested_span_tests {
 
         assert_snapshot!(out);
     }
+
+    #[test]
+    fn dump_tree_aligns_timing_by_display_width() {
+        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Verbose);
+
+        let out = capture_stdout(|| {
+            printer.intro("db");
+            printer.intro("migrate schema");
+            printer.intro("日本語");
+            printer.dump_tree();
+        });
+
+        let offsets: Vec<usize> = out
+            .lines()
+            .skip_while(|l| *l != "Active tasks:")
+            .skip(1)
+            .map(|l| display_width(&l[..l.find("(started").unwrap()]))
+            .collect();
+
+        assert_eq!(offsets.len(), 3, "{out}");
+        assert!(offsets.iter().all(|&o| o == offsets[0]), "{out}");
+    }
+}
+
+mod indentation_tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn steps_indent_under_task_and_restore_after_outro() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_indent_width(4);
+
+        let out = capture_stdout(|| {
+            printer.intro("deploy");
+            printer.step("build");
+            printer.step("upload");
+            printer.outro("deployed");
+            printer.step("after");
+        });
+
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "INTRO: deploy",
+                "    STEP: build",
+                "    STEP: upload",
+                "OUTRO: deployed",
+                "STEP: after",
+            ]
+        );
+    }
+
+    #[test]
+    fn zero_indent_width_keeps_flat_output() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_indent_width(0);
+
+        let out = capture_stdout(|| {
+            printer.intro("deploy");
+            printer.step("build");
+            printer.outro("deployed");
+        });
+
+        assert!(out.lines().any(|l| l == "STEP: build"));
+    }
+}
+
+mod task_depth_cap_tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn intros_past_the_cap_warn_once_and_stay_untracked() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_max_task_depth(2);
+
+        let mut out = String::new();
+        let err = capture_stderr(|| {
+            out = capture_stdout(|| {
+                for i in 0..5 {
+                    ScreenLogger::intro(&printer, &format!("leak {i}"));
+                }
+            });
+        });
+
+        assert_eq!(printer.tasks.lock().unwrap().len(), 2);
+        assert_eq!(err.matches("task stack reached 2 open tasks").count(), 1);
+        // Intro text still renders for the untracked tasks.
+        assert!(out.contains("INTRO: leak 4"), "{out:?}");
+    }
+}
+
+mod leaked_task_tests {
+    use super::*;
+
+    fn leak_and_drop(verbosity: Verbosity) -> String {
+        let printer = make_printer(MockLogger::new(verbosity), LogFormat::Text, verbosity);
+
+        let _ = capture_stderr(|| {
+            let _ = capture_stdout(|| {
+                ScreenLogger::intro(&printer, "closed");
+                ScreenLogger::outro(&printer, "closed");
+                ScreenLogger::intro(&printer, "migrate");
+                ScreenLogger::intro(&printer, "copy rows");
+            });
+        });
+
+        // The diagnostic is a warning, so it follows the stream policy.
+        capture_stdout(|| drop(printer))
+    }
+
+    #[test]
+    fn verbose_drop_lists_unfinished_tasks() {
+        let out = leak_and_drop(Verbosity::Verbose);
+        let (_, diag) = out
+            .split_once("warning: printer dropped with 2 unfinished tasks:")
+            .expect("missing leak diagnostic");
+
+        let labels: Vec<_> = diag
+            .lines()
+            .filter_map(|l| l.trim().strip_prefix("- "))
+            .map(|l| l.split(" (open for ").next().unwrap())
+            .collect();
+        assert_eq!(labels, ["migrate", "copy rows"]);
+    }
+
+    #[test]
+    fn drop_survives_a_poisoned_lock() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Verbose),
+            LogFormat::Text,
+            Verbosity::Verbose,
+        );
+        let _ = capture_stdout(|| ScreenLogger::intro(&printer, "migrate"));
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _tasks = printer.tasks.lock().unwrap();
+            panic!("poison");
+        }));
+
+        let out = capture_stdout(|| drop(printer));
+        assert!(out.contains("- migrate (open for "), "{out}");
+    }
+
+    #[test]
+    fn normal_drop_stays_silent() {
+        assert_eq!(leak_and_drop(Verbosity::Normal), "");
+    }
 }
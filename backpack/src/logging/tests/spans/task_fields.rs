@@ -0,0 +1,83 @@
+mod task_fields_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+
+    fn json_lines(out: &str) -> Vec<Value> {
+        out.lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| serde_json::from_str(l).expect("Expected valid JSON"))
+            .collect()
+    }
+
+    #[test]
+    fn task_fields_apply_until_outro() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            printer.intro_with_fields("Build", Fields::from([("stage".into(), "build".into())]));
+            printer.info("compiling").field("crate", "log-rs").emit();
+            ScreenLogger::outro(&printer, "Built");
+            printer.info("after").emit();
+        });
+
+        let lines = json_lines(&out);
+        assert_eq!(lines[0]["fields"]["stage"], "build");
+        assert_eq!(lines[1]["message"], "compiling");
+        assert_eq!(lines[1]["fields"]["stage"], "build");
+        assert_eq!(lines[1]["fields"]["crate"], "log-rs");
+        assert_eq!(lines[3]["message"], "after");
+        assert!(lines[3].get("fields").is_none());
+    }
+
+    #[test]
+    fn event_fields_override_task_fields() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            printer.intro_with_fields("Build", Fields::from([("stage".into(), "build".into())]));
+            printer.info("linking").field("stage", "link").emit();
+        });
+
+        assert_eq!(json_lines(&out)[1]["fields"]["stage"], "link");
+    }
+
+    #[test]
+    fn info_with_fields_merges_task_fields() {
+        let printer = make_printer(SimpleLogger, LogFormat::Json, Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            printer.intro_with_fields("Build", Fields::from([("stage".into(), "build".into())]));
+            printer.info_with_fields(
+                "compiling",
+                &Fields::from([("crate".into(), "log-rs".into())]),
+            );
+        });
+
+        let lines = json_lines(&out);
+        assert_eq!(lines[1]["message"], "compiling");
+        assert_eq!(lines[1]["fields"]["stage"], "build");
+        assert_eq!(lines[1]["fields"]["crate"], "log-rs");
+    }
+
+    #[test]
+    fn text_mode_appends_task_fields() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+
+        let out = capture_stdout(|| {
+            printer.intro_with_fields("Build", Fields::from([("stage".into(), "build".into())]));
+            printer.info("compiling").emit();
+        });
+
+        assert!(
+            out.contains("compiling \x1b[2mstage=build\x1b[0m"),
+            "{out:?}"
+        );
+    }
+}
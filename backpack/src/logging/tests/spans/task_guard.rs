@@ -0,0 +1,66 @@
+mod task_guard_tests {
+    use crate::logging::tests::common::*;
+    use crate::logging::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn printer() -> Printer<MockLogger, SimpleBackend> {
+        make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        )
+    }
+
+    fn migrate(printer: &Printer<MockLogger, SimpleBackend>, fail: bool) -> anyhow::Result<()> {
+        let _task = printer.task("migrate");
+        ScreenLogger::step(printer, "copy rows");
+        if fail {
+            anyhow::bail!("disk full");
+        }
+        ScreenLogger::step(printer, "swap tables");
+        Ok(())
+    }
+
+    #[test]
+    fn early_return_still_closes_the_task() {
+        let printer = printer();
+
+        let out = capture_stdout(|| {
+            assert!(migrate(&printer, true).is_err());
+        });
+
+        assert_eq!(out, "INTRO: migrate\n  STEP: copy rows\nOUTRO: migrate\n");
+        assert_eq!(ScreenLogger::task_depth(&printer), 0);
+    }
+
+    #[test]
+    fn explicit_done_replaces_the_outro() {
+        let printer = printer();
+
+        let out = capture_stdout(|| {
+            let task = printer.task("deploy");
+            assert_eq!(task.label(), "deploy");
+            task.done();
+        });
+
+        assert_eq!(out, "INTRO: deploy\nDONE!\n");
+        assert_eq!(ScreenLogger::task_depth(&printer), 0);
+    }
+
+    #[test]
+    fn panics_unwind_through_the_guard() {
+        let printer = printer();
+
+        let out = capture_stdout(|| {
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                let _task = printer.task("risky");
+                panic!("boom");
+            }));
+            assert!(result.is_err());
+        });
+
+        assert!(out.ends_with("OUTRO: risky\n"), "{out:?}");
+        assert_eq!(ScreenLogger::task_depth(&printer), 0);
+    }
+}
@@ -3,18 +3,19 @@ use crate::logging::tests::common::*;
 use crate::logging::*;
 
 use insta::assert_snapshot;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 mod timing_tests {
     use super::*;
 
     #[test]
     fn outro_includes_timing_in_verbose_mode_snapshot() {
-        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Verbose);
+        let (printer, clock) =
+            make_clocked_printer(SimpleLogger, LogFormat::Text, Verbosity::Verbose);
 
         let out = capture_stdout(|| {
             printer.intro("timed-task");
-            std::thread::sleep(Duration::from_millis(20));
+            clock.advance(Duration::from_millis(20));
             printer.outro("finished");
         });
 
@@ -23,14 +24,15 @@ mod timing_tests {
 
     #[test]
     fn nested_timing_tracks_independently_snapshot() {
-        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Verbose);
+        let (printer, clock) =
+            make_clocked_printer(SimpleLogger, LogFormat::Text, Verbosity::Verbose);
 
         let out = capture_stdout(|| {
             printer.intro("outer");
-            std::thread::sleep(Duration::from_millis(10));
+            clock.advance(Duration::from_millis(10));
 
             printer.intro("inner");
-            std::thread::sleep(Duration::from_millis(10));
+            clock.advance(Duration::from_millis(10));
             printer.outro("inner-done");
 
             printer.outro("outer-done");
@@ -42,18 +44,356 @@ mod timing_tests {
     #[test]
     fn quiet_mode_preserves_timing_summaries_snapshot() {
         config::setquiet(true);
-        let printer = make_printer(SimpleLogger, LogFormat::Text, Verbosity::Quiet);
+        let (printer, clock) =
+            make_clocked_printer(SimpleLogger, LogFormat::Text, Verbosity::Quiet);
 
         let out = capture_stdout(|| {
             printer.intro("quiet-task");
-            std::thread::sleep(Duration::from_millis(20));
+            clock.advance(Duration::from_millis(20));
             printer.outro("quiet-outro");
 
             printer.intro("another-task");
-            std::thread::sleep(Duration::from_millis(20));
+            clock.advance(Duration::from_millis(20));
             printer.done();
         });
 
         assert_snapshot!(out);
     }
 }
+
+mod task_summary_tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn trace_outro_reports_step_count_and_peak_depth() {
+        let (printer, clock) = make_clocked_printer(
+            MockLogger::new(Verbosity::Trace),
+            LogFormat::Text,
+            Verbosity::Trace,
+        );
+        let tick = || clock.advance(Duration::from_millis(10));
+
+        let out = capture_stdout(|| {
+            printer.intro("deploy");
+            printer.step("build");
+            printer.intro("upload");
+            printer.step("assets");
+            printer.intro("cdn");
+            printer.step("purge");
+            tick();
+            printer.outro("cdn done");
+            tick();
+            printer.outro("upload done");
+            printer.step("verify");
+            tick();
+            printer.outro("deploy done");
+        });
+
+        let outros: Vec<&str> = out.lines().filter(|l| l.starts_with("OUTRO")).collect();
+        assert_eq!(
+            outros,
+            vec![
+                "OUTRO: cdn done (took 10ms, 1 step, depth 1)",
+                "OUTRO: upload done (took 20ms, 2 steps, depth 2)",
+                "OUTRO: deploy done (took 30ms, 4 steps, depth 3)",
+            ]
+        );
+    }
+
+    #[test]
+    fn verbose_outro_omits_step_count_and_depth() {
+        let (printer, clock) = make_clocked_printer(
+            MockLogger::new(Verbosity::Verbose),
+            LogFormat::Text,
+            Verbosity::Verbose,
+        );
+
+        let out = capture_stdout(|| {
+            printer.intro("deploy");
+            printer.step("build");
+            clock.advance(Duration::from_millis(10));
+            printer.outro("deploy done");
+        });
+
+        assert!(out.contains("deploy done (took 10ms)\n"));
+    }
+}
+
+mod completed_summary_tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn run_tasks(printer: &Printer<MockLogger, SimpleBackend>, clock: &MockClock) {
+        for (label, ms) in [("fetch", 5), ("compile", 60), ("link", 30)] {
+            printer.intro(label);
+            clock.advance(Duration::from_millis(ms));
+            printer.outro(&format!("{label} done"));
+        }
+    }
+
+    fn timed_printer(format: LogFormat) -> Printer<MockLogger, SimpleBackend> {
+        let (printer, clock) = make_clocked_printer(
+            MockLogger::new(Verbosity::Normal),
+            format,
+            Verbosity::Normal,
+        );
+        run_tasks(&printer, &clock);
+        printer
+    }
+
+    #[test]
+    fn summary_lists_tasks_slowest_first() {
+        let printer = timed_printer(LogFormat::Text);
+
+        let out = capture_stdout(|| printer.summary());
+        let names: Vec<&str> = out
+            .lines()
+            .skip_while(|l| *l != "Task summary:")
+            .skip(1)
+            .filter_map(|l| l.split_whitespace().next())
+            .collect();
+
+        assert_eq!(names, vec!["compile", "link", "fetch"]);
+    }
+
+    #[test]
+    fn summary_follows_the_stream_policy() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Normal),
+            LogFormat::Text,
+            Verbosity::Normal,
+        );
+        printer.set_stream_policy(StreamPolicy::AllToStderr);
+
+        let mut err = String::new();
+        let out = capture_stdout(|| err = capture_stderr(|| printer.summary()));
+
+        assert!(out.is_empty(), "{out:?}");
+        assert_eq!(err, "(no completed tasks)\n");
+    }
+
+    #[test]
+    fn summary_emits_one_json_event_in_json_mode() {
+        let printer = timed_printer(LogFormat::Json);
+
+        let out = capture_stdout(|| printer.summary());
+        let line = out.lines().last().expect("no summary output");
+        let event: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(event["level"], "info");
+        assert_eq!(event["message"], "summary");
+        let rows = &event["fields"]["tasks"];
+        let names: Vec<&str> = rows
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|r| r["task"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(names, vec!["compile", "link", "fetch"]);
+        assert_eq!(rows[0]["duration_ms"], 60);
+    }
+}
+
+mod step_delta_tests {
+    use super::*;
+
+    /// Milliseconds from a trailing `(+Nms)` marker.
+    fn delta_ms(line: &str) -> u128 {
+        let start = line.rfind("(+").expect("missing step delta") + 2;
+        line[start..]
+            .trim_end_matches(')')
+            .trim_end_matches("ms")
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn trace_steps_show_time_since_previous_step() {
+        let (printer, clock) = make_clocked_printer(
+            MockLogger::new(Verbosity::Trace),
+            LogFormat::Text,
+            Verbosity::Trace,
+        );
+
+        let out = capture_stdout(|| {
+            printer.intro("deploy");
+            clock.advance(Duration::from_millis(30));
+            printer.step("build");
+            clock.advance(Duration::from_millis(60));
+            printer.step("upload");
+            printer.outro("deployed");
+        });
+
+        let steps: Vec<&str> = out.lines().filter(|l| l.contains("STEP:")).collect();
+        assert_eq!(steps.len(), 2);
+        assert_eq!((delta_ms(steps[0]), delta_ms(steps[1])), (30, 60));
+    }
+
+    #[test]
+    fn verbose_steps_have_no_delta() {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Verbose),
+            LogFormat::Text,
+            Verbosity::Verbose,
+        );
+
+        let out = capture_stdout(|| {
+            printer.intro("deploy");
+            printer.step("build");
+            printer.outro("deployed");
+        });
+
+        assert!(!out.contains("(+"));
+    }
+}
+
+mod quiet_profile_tests {
+    use super::*;
+
+    fn quiet_run(profile: QuietProfile) -> String {
+        let printer = make_printer(
+            MockLogger::new(Verbosity::Quiet),
+            LogFormat::Text,
+            Verbosity::Quiet,
+        );
+        printer.set_quiet_profile(profile);
+
+        capture_stdout(|| {
+            ScreenLogger::intro(&printer, "build");
+            ScreenLogger::outro(&printer, "built");
+            ScreenLogger::intro(&printer, "test");
+            ScreenLogger::done(&printer);
+            printer.summary();
+        })
+    }
+
+    #[test]
+    fn default_profile_keeps_outros() {
+        let out = quiet_run(QuietProfile::default());
+
+        assert!(out.contains("OUTRO: built"), "{out:?}");
+        assert!(out.contains("Task summary:"));
+    }
+
+    #[test]
+    fn errors_only_hides_outros_and_summary() {
+        let out = quiet_run(QuietProfile::ErrorsOnly);
+
+        assert!(out.trim().is_empty(), "{out:?}");
+    }
+
+    #[test]
+    fn errors_and_summary_shows_only_the_summary() {
+        let out = quiet_run(QuietProfile::ErrorsAndSummary);
+
+        assert!(!out.contains("OUTRO"), "{out:?}");
+        assert!(!out.contains("DONE"), "{out:?}");
+        assert!(out.starts_with("Task summary:"), "{out:?}");
+        assert!(out.contains("build") && out.contains("test"));
+    }
+}
+
+mod outro_timing_tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn verbose_run(printer: &(Printer<MockLogger, SimpleBackend>, Arc<MockClock>)) -> String {
+        let (printer, clock) = printer;
+        capture_stdout(|| {
+            ScreenLogger::intro(printer, "build");
+            clock.advance(Duration::from_millis(10));
+            ScreenLogger::outro(printer, "built");
+            ScreenLogger::intro(printer, "test");
+            clock.advance(Duration::from_millis(10));
+            ScreenLogger::done(printer);
+        })
+    }
+
+    fn verbose_printer(format: LogFormat) -> (Printer<MockLogger, SimpleBackend>, Arc<MockClock>) {
+        make_clocked_printer(
+            MockLogger::new(Verbosity::Verbose),
+            format,
+            Verbosity::Verbose,
+        )
+    }
+
+    #[test]
+    fn timing_suffix_is_shown_by_default() {
+        let out = verbose_run(&verbose_printer(LogFormat::Text));
+
+        assert_eq!(
+            out,
+            "INTRO: build\nOUTRO: built (took 10ms)\nINTRO: test\nDONE! (took 10ms)\n"
+        );
+    }
+
+    #[test]
+    fn disabling_timing_drops_the_suffix() {
+        let printer = verbose_printer(LogFormat::Text);
+        printer.0.set_outro_timing(false);
+
+        assert_eq!(
+            verbose_run(&printer),
+            "INTRO: build\nOUTRO: built\nINTRO: test\nDONE!\n"
+        );
+    }
+
+    #[test]
+    fn json_keeps_duration_when_timing_is_off() {
+        let printer = verbose_printer(LogFormat::Json);
+        printer.0.set_outro_timing(false);
+
+        let outros: Vec<serde_json::Value> = verbose_run(&printer)
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .filter(|e: &serde_json::Value| e["fields"].get("duration_ms").is_some())
+            .collect();
+
+        assert_eq!(outros.len(), 2);
+        assert_eq!(outros[0]["fields"]["duration_ms"], "10");
+        assert!(!outros[0]["message"].as_str().unwrap().contains("took"));
+    }
+}
+
+mod mock_clock_tests {
+    use super::*;
+
+    use pretty_assertions::assert_eq;
+
+    fn clocked(verbosity: Verbosity) -> (Printer<MockLogger, SimpleBackend>, Arc<MockClock>) {
+        make_clocked_printer(MockLogger::new(verbosity), LogFormat::Text, verbosity)
+    }
+
+    #[test]
+    fn outro_reports_mock_clock_time() {
+        let (printer, clock) = clocked(Verbosity::Normal);
+
+        let out = capture_stdout(|| {
+            ScreenLogger::intro(&printer, "build");
+            clock.advance(Duration::from_millis(20));
+            ScreenLogger::outro(&printer, "built");
+        });
+
+        assert_eq!(out, "INTRO: build\nOUTRO: built (took 20ms)\n");
+    }
+
+    #[test]
+    fn step_deltas_follow_the_clock() {
+        let (printer, clock) = clocked(Verbosity::Trace);
+
+        let out = capture_stdout(|| {
+            printer.intro("deploy");
+            clock.advance(Duration::from_millis(30));
+            printer.step("build");
+            clock.advance(Duration::from_millis(45));
+            printer.step("upload");
+        });
+
+        let steps: Vec<&str> = out.lines().filter(|l| l.contains("STEP:")).collect();
+        assert_eq!(steps, ["  STEP: build (+30ms)", "  STEP: upload (+45ms)"]);
+    }
+}